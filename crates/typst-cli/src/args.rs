@@ -170,6 +170,16 @@ pub struct FontsCommand {
     /// Also lists style variants of each font family.
     #[arg(long)]
     pub variants: bool,
+
+    /// Lists each font's path, supported OpenType scripts, languages, and
+    /// features, variation axes, Unicode coverage, and whether it has a MATH
+    /// table.
+    #[arg(long)]
+    pub details: bool,
+
+    /// Prints all details about the fonts as JSON.
+    #[arg(long)]
+    pub json: bool,
 }
 
 /// Update the CLI using a pre-compiled binary from a Typst GitHub release.
@@ -264,6 +274,12 @@ pub struct CompileArgs {
     #[arg(long = "open", value_name = "VIEWER")]
     pub open: Option<Option<String>>,
 
+    /// Prints which fonts were used for which characters and warns about
+    /// characters that no font could display. Not available for HTML
+    /// output.
+    #[arg(long = "font-report")]
+    pub font_report: bool,
+
     /// Produces performance timings of the compilation process. (experimental)
     ///
     /// The resulting JSON file can be loaded into a tracing tool such as
@@ -17,6 +17,7 @@ use typst::foundations::{Datetime, Smart};
 use typst::html::HtmlDocument;
 use typst::layout::{Frame, Page, PageRanges, PagedDocument};
 use typst::syntax::{FileId, Source, Span};
+use typst::text::FontReport;
 use typst::WorldExt;
 use typst_pdf::{PdfOptions, PdfStandards, Timestamp};
 
@@ -28,6 +29,7 @@ use crate::args::{
 use crate::server::HtmlServer;
 use crate::timings::Timer;
 
+use crate::fonts::codepoint_range;
 use crate::watch::Status;
 use crate::world::SystemWorld;
 use crate::{set_failed, terminal};
@@ -68,6 +70,8 @@ pub struct CompileConfig {
     pub pdf_standards: PdfStandards,
     /// A path to write a Makefile rule describing the current compilation.
     pub make_deps: Option<PathBuf>,
+    /// Whether to print a report of the used and missing fonts.
+    pub font_report: bool,
     /// The PPI (pixels per inch) to use for PNG export.
     pub ppi: f32,
     /// The export cache for images, used for caching output files in `typst
@@ -126,6 +130,10 @@ impl CompileConfig {
             ))
         });
 
+        if args.font_report && output_format == OutputFormat::Html {
+            bail!("the font report is not available for HTML output");
+        }
+
         let pages = args.pages.as_ref().map(|export_ranges| {
             PageRanges::new(export_ranges.iter().map(|r| r.0.clone()).collect())
         });
@@ -162,6 +170,7 @@ impl CompileConfig {
             pdf_standards,
             creation_timestamp: args.world.creation_timestamp,
             make_deps: args.make_deps.clone(),
+            font_report: args.font_report,
             ppi: args.ppi,
             diagnostic_format: args.process.diagnostic_format,
             open: args.open.clone(),
@@ -189,7 +198,7 @@ pub fn compile_once(
 
     match output {
         // Export the PDF / PNG.
-        Ok(outputs) => {
+        Ok((outputs, report)) => {
             let duration = start.elapsed();
 
             if config.watching {
@@ -200,6 +209,11 @@ pub fn compile_once(
                 }
             }
 
+            if let Some(report) = &report {
+                print_font_report(&mut terminal::out(), report)
+                    .map_err(|err| eco_format!("failed to print font report ({err})"))?;
+            }
+
             print_diagnostics(world, &[], &warnings, config.diagnostic_format)
                 .map_err(|err| eco_format!("failed to print diagnostics ({err})"))?;
 
@@ -224,27 +238,62 @@ pub fn compile_once(
 }
 
 /// Compile and then export the document.
+///
+/// Also returns the font report if one was requested.
 fn compile_and_export(
     world: &mut SystemWorld,
     config: &mut CompileConfig,
-) -> Warned<SourceResult<Vec<Output>>> {
+) -> Warned<SourceResult<(Vec<Output>, Option<FontReport>)>> {
     match config.output_format {
         OutputFormat::Html => {
             let Warned { output, warnings } = typst::compile::<HtmlDocument>(world);
             let result = output.and_then(|document| export_html(&document, config));
             Warned {
-                output: result.map(|()| vec![config.output.clone()]),
+                output: result.map(|()| (vec![config.output.clone()], None)),
                 warnings,
             }
         }
         _ => {
-            let Warned { output, warnings } = typst::compile::<PagedDocument>(world);
-            let result = output.and_then(|document| export_paged(&document, config));
+            let (Warned { output, mut warnings }, families) =
+                typst::compile_with_unknown_families::<PagedDocument>(world);
+            let result = output.and_then(|document| {
+                let report = config.font_report.then(|| {
+                    let Warned { output, warnings: missing } =
+                        FontReport::new(&document, families);
+                    warnings.extend(missing);
+                    output
+                });
+                export_paged(&document, config).map(|outputs| (outputs, report))
+            });
             Warned { output: result, warnings }
         }
     }
 }
 
+/// Print which fonts were used for which characters and which requested
+/// families were missing.
+fn print_font_report(out: &mut impl Write, report: &FontReport) -> io::Result<()> {
+    writeln!(out, "fonts used:")?;
+    for (font, ranges) in &report.fonts {
+        let info = font.info();
+        let ranges: Vec<_> = ranges
+            .iter()
+            .map(|range| codepoint_range(*range.start() as u32, *range.end() as u32))
+            .collect();
+        writeln!(out, "- {} ({:?}): {}", info.family, info.variant, ranges.join(", "))?;
+    }
+
+    if !report.missing_families.is_empty() {
+        writeln!(out, "missing font families:")?;
+        for family in &report.missing_families {
+            writeln!(out, "- {}", family.v)?;
+        }
+    }
+
+    writeln!(out)?;
+    Ok(())
+}
+
 /// Export to HTML.
 fn export_html(document: &HtmlDocument, config: &CompileConfig) -> SourceResult<()> {
     let html = typst_html::html(document)?;
@@ -765,3 +814,84 @@ impl<'a> codespan_reporting::files::Files<'a> for SystemWorld {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+    use crate::args::{CliArguments, Command};
+
+    #[test]
+    fn test_font_report() {
+        let dir = std::env::temp_dir()
+            .join(format!("typst-font-report-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("main.typ");
+        let output = dir.join("main.pdf");
+        fs::write(
+            &input,
+            "#set text(font: (\"nonexistent\", \"Libertinus Serif\"))\nAB\u{10FFFD}",
+        )
+        .unwrap();
+
+        let args = CliArguments::try_parse_from([
+            OsStr::new("typst"),
+            OsStr::new("compile"),
+            OsStr::new("--ignore-system-fonts"),
+            OsStr::new("--font-report"),
+            input.as_os_str(),
+            output.as_os_str(),
+        ])
+        .unwrap();
+        let Command::Compile(command) = &args.command else { unreachable!() };
+        let mut config = CompileConfig::new(command).unwrap();
+        let mut world = SystemWorld::new(
+            &command.args.input,
+            &command.args.world,
+            &command.args.process,
+        )
+        .unwrap();
+
+        let Warned { output, warnings } = compile_and_export(&mut world, &mut config);
+        let (_, report) = output.unwrap();
+        let report = report.unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let families: Vec<_> = report
+            .missing_families
+            .iter()
+            .map(|family| family.v.as_str())
+            .collect();
+        assert_eq!(families, ["nonexistent"]);
+        assert_eq!(report.missing_glyphs.len(), 1);
+        assert_eq!(report.missing_glyphs[0].v, "\u{10FFFD}");
+        assert!(warnings.iter().any(|warning| {
+            warning.message.starts_with("no font contains a glyph for")
+                && warning.message.ends_with("(U+10FFFD)")
+        }));
+
+        let mut out = vec![];
+        print_font_report(&mut out, &report).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("fonts used:\n- Libertinus Serif ("));
+        assert!(out.contains("): U+0041-U+0042\n"));
+        assert!(out.ends_with("missing font families:\n- nonexistent\n\n"));
+    }
+
+    #[test]
+    fn test_font_report_html() {
+        let args = CliArguments::try_parse_from([
+            "typst",
+            "compile",
+            "--format",
+            "html",
+            "--font-report",
+            "main.typ",
+        ])
+        .unwrap();
+        let Command::Compile(command) = &args.command else { unreachable!() };
+        let Err(err) = CompileConfig::new(command) else { panic!() };
+        assert_eq!(err, "the font report is not available for HTML output");
+    }
+}
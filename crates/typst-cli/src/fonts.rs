@@ -1,21 +1,342 @@
-use typst::text::FontVariant;
-use typst_kit::fonts::Fonts;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use ecow::eco_format;
+use serde::Serialize;
+use typst::diag::StrResult;
+use typst::text::{Font, FontFlags, FontInfo, FontVariant};
+use typst_kit::fonts::{FontSlot, Fonts};
 
 use crate::args::FontsCommand;
 
 /// Execute a font listing command.
-pub fn fonts(command: &FontsCommand) {
+pub fn fonts(command: &FontsCommand) -> StrResult<()> {
     let fonts = Fonts::searcher()
         .include_system_fonts(!command.font.ignore_system_fonts)
         .search_with(&command.font.font_paths);
 
+    if command.json {
+        let families: Vec<_> = fonts
+            .book
+            .families()
+            .map(|(name, _)| Family { name, fonts: details(&fonts, name) })
+            .collect();
+        let json = serde_json::to_string_pretty(&families)
+            .map_err(|err| eco_format!("failed to serialize fonts ({err})"))?;
+        println!("{json}");
+        return Ok(());
+    }
+
+    print_fonts(&mut io::stdout(), &fonts, command)
+        .map_err(|err| eco_format!("failed to print fonts ({err})"))
+}
+
+/// Print the font families, optionally with their variants or details.
+fn print_fonts(
+    out: &mut impl Write,
+    fonts: &Fonts,
+    command: &FontsCommand,
+) -> io::Result<()> {
     for (name, infos) in fonts.book.families() {
-        println!("{name}");
-        if command.variants {
+        writeln!(out, "{name}")?;
+        if command.details {
+            for details in details(fonts, name) {
+                print_details(out, &details)?;
+            }
+        } else if command.variants {
             for info in infos {
                 let FontVariant { style, weight, stretch } = info.variant;
-                println!("- Style: {style:?}, Weight: {weight:?}, Stretch: {stretch:?}");
+                writeln!(
+                    out,
+                    "- Style: {style:?}, Weight: {weight:?}, Stretch: {stretch:?}"
+                )?;
             }
         }
     }
+
+    Ok(())
+}
+
+/// A font family with details about its fonts.
+#[derive(Serialize)]
+struct Family<'a> {
+    /// The family's name.
+    name: &'a str,
+    /// The fonts in the family.
+    fonts: Vec<Details>,
+}
+
+/// Details about a single font, as found in its metadata and OpenType tables.
+#[derive(Serialize)]
+struct Details {
+    /// The style, weight, and stretch of the font.
+    variant: FontVariant,
+    /// Whether all glyphs have the same width.
+    monospace: bool,
+    /// Whether the font has serifs.
+    serif: bool,
+    /// The path of the font file or `None` for embedded fonts.
+    path: Option<PathBuf>,
+    /// The index of the font in its collection.
+    index: u32,
+    /// The number of glyphs in the font.
+    glyphs: u16,
+    /// Whether the font has an OpenType MATH table.
+    math: bool,
+    /// The OpenType scripts with their language systems.
+    scripts: BTreeMap<String, BTreeSet<String>>,
+    /// The OpenType feature tags.
+    features: BTreeSet<String>,
+    /// The variation axes, if this is a variable font.
+    axes: Vec<Axis>,
+    /// Ranges of covered Unicode codepoints.
+    coverage: Vec<(u32, u32)>,
+}
+
+/// A variation axis of a variable font.
+#[derive(Serialize)]
+struct Axis {
+    /// The axis' OpenType tag, e.g. `wght`.
+    tag: String,
+    /// The minimum value of the axis.
+    min: f32,
+    /// The default value of the axis.
+    default: f32,
+    /// The maximum value of the axis.
+    max: f32,
+}
+
+/// Collect details about all fonts of a family.
+fn details(fonts: &Fonts, family: &str) -> Vec<Details> {
+    fonts
+        .book
+        .select_family(&family.to_lowercase())
+        .filter_map(|id| {
+            let info = fonts.book.info(id)?;
+            let slot = fonts.fonts.get(id)?;
+            Some(font_details(info, slot, &slot.get()?))
+        })
+        .collect()
+}
+
+/// Collect details about a single font.
+fn font_details(info: &FontInfo, slot: &FontSlot, font: &Font) -> Details {
+    let ttf = font.ttf();
+    let mut scripts = BTreeMap::<String, BTreeSet<String>>::new();
+    let mut features = BTreeSet::new();
+    for table in [ttf.tables().gsub, ttf.tables().gpos].into_iter().flatten() {
+        for script in table.scripts {
+            scripts.entry(tag(script.tag.to_bytes())).or_default().extend(
+                script.languages.into_iter().map(|lang| tag(lang.tag.to_bytes())),
+            );
+        }
+        features.extend(
+            table.features.into_iter().map(|feature| tag(feature.tag.to_bytes())),
+        );
+    }
+
+    let axes = ttf
+        .variation_axes()
+        .into_iter()
+        .map(|axis| Axis {
+            tag: tag(axis.tag.to_bytes()),
+            min: axis.min_value,
+            default: axis.def_value,
+            max: axis.max_value,
+        })
+        .collect();
+
+    let mut coverage: Vec<(u32, u32)> = vec![];
+    for c in info.coverage.iter() {
+        match coverage.last_mut() {
+            Some((_, end)) if *end + 1 == c => *end = c,
+            _ => coverage.push((c, c)),
+        }
+    }
+
+    Details {
+        variant: info.variant,
+        monospace: info.flags.contains(FontFlags::MONOSPACE),
+        serif: info.flags.contains(FontFlags::SERIF),
+        path: slot.path().map(PathBuf::from),
+        index: slot.index(),
+        glyphs: ttf.number_of_glyphs(),
+        math: ttf.tables().math.is_some(),
+        scripts,
+        features,
+        axes,
+        coverage,
+    }
+}
+
+/// Turn the bytes of an OpenType tag into a string, trimming the padding.
+fn tag(bytes: [u8; 4]) -> String {
+    String::from_utf8_lossy(&bytes).trim_end().to_string()
+}
+
+/// Print details about a font in a human-readable form.
+fn print_details(out: &mut impl Write, details: &Details) -> io::Result<()> {
+    let FontVariant { style, weight, stretch } = details.variant;
+    writeln!(out, "- Style: {style:?}, Weight: {weight:?}, Stretch: {stretch:?}")?;
+
+    let path = match &details.path {
+        Some(path) => path.display().to_string(),
+        None => "<embedded>".into(),
+    };
+    writeln!(out, "  Path: {path} (index {})", details.index)?;
+
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    writeln!(
+        out,
+        "  Glyphs: {}, Monospace: {}, Serif: {}, Math: {}",
+        details.glyphs,
+        yes_no(details.monospace),
+        yes_no(details.serif),
+        yes_no(details.math),
+    )?;
+
+    let scripts: Vec<_> = details
+        .scripts
+        .iter()
+        .map(|(script, langs)| {
+            if langs.is_empty() {
+                script.clone()
+            } else {
+                let langs: Vec<_> = langs.iter().map(String::as_str).collect();
+                format!("{script} ({})", langs.join(", "))
+            }
+        })
+        .collect();
+    writeln!(out, "  Scripts: {}", list(&scripts))?;
+
+    let features: Vec<_> = details.features.iter().cloned().collect();
+    writeln!(out, "  Features: {}", list(&features))?;
+
+    if !details.axes.is_empty() {
+        let axes: Vec<_> = details
+            .axes
+            .iter()
+            .map(|axis| {
+                format!(
+                    "{} {}..{} (default {})",
+                    axis.tag, axis.min, axis.max, axis.default
+                )
+            })
+            .collect();
+        writeln!(out, "  Axes: {}", list(&axes))?;
+    }
+
+    let coverage: Vec<_> = details
+        .coverage
+        .iter()
+        .map(|&(start, end)| codepoint_range(start, end))
+        .collect();
+    writeln!(out, "  Coverage: {}", list(&coverage))
+}
+
+/// Format an inclusive range of Unicode codepoints, e.g. `U+0041-U+005A`.
+pub fn codepoint_range(start: u32, end: u32) -> String {
+    if start == end {
+        format!("U+{start:04X}")
+    } else {
+        format!("U+{start:04X}-U+{end:04X}")
+    }
+}
+
+/// Join a list for printing, marking empty lists.
+fn list(items: &[String]) -> String {
+    if items.is_empty() {
+        "-".into()
+    } else {
+        items.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use serde_json::json;
+
+    use super::*;
+    use crate::args::{CliArguments, Command};
+
+    /// Parse a `typst fonts` command line.
+    fn command(args: &[&str]) -> FontsCommand {
+        let args = CliArguments::try_parse_from(
+            ["typst", "fonts", "--ignore-system-fonts"].iter().chain(args),
+        )
+        .unwrap();
+        let Command::Fonts(command) = args.command else { unreachable!() };
+        command
+    }
+
+    /// Search for the embedded fonts only.
+    fn embedded() -> Fonts {
+        Fonts::searcher().include_system_fonts(false).search()
+    }
+
+    #[test]
+    fn test_fonts_json() {
+        let fonts = embedded();
+        let name = "DejaVu Sans Mono";
+        let family = Family { name, fonts: details(&fonts, name) };
+        let mut json = serde_json::to_value(&family).unwrap();
+        assert_eq!(json["name"], name);
+        assert_eq!(json["fonts"].as_array().unwrap().len(), 4);
+
+        let font = json["fonts"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|font| {
+                font["variant"]["weight"] == 400 && font["variant"]["style"] == "normal"
+            })
+            .unwrap();
+
+        // The glyph count, coverage, scripts, and features depend on the exact
+        // font version, so we only check their shape.
+        assert!(font["glyphs"].take().as_u64().unwrap() > 0);
+        assert!(font["scripts"].take().is_object());
+        assert!(font["features"].take().is_array());
+        let coverage = font["coverage"].take();
+        assert!(coverage.as_array().unwrap().iter().any(|range| {
+            range[0].as_u64() <= Some(0x41) && range[1].as_u64() >= Some(0x5A)
+        }));
+
+        let expected = json!({
+            "variant": { "style": "normal", "weight": 400, "stretch": 1000 },
+            "monospace": true,
+            "serif": false,
+            "path": null,
+            "index": 0,
+            "glyphs": null,
+            "math": false,
+            "scripts": null,
+            "features": null,
+            "axes": [],
+            "coverage": null,
+        });
+        assert_eq!(*font, expected);
+    }
+
+    #[test]
+    fn test_fonts_details() {
+        let mut out = vec![];
+        print_fonts(&mut out, &embedded(), &command(&["--details"])).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let (_, math) = out.split_once("New Computer Modern Math\n").unwrap();
+        assert!(math.starts_with("- Style: Normal, Weight: 400, Stretch: "));
+        assert!(math.contains("  Path: <embedded> (index 0)\n"));
+        assert!(math.contains(", Math: yes\n"));
+        assert!(math.contains("  Features: "));
+        assert!(math.contains("  Coverage: U+"));
+    }
+
+    #[test]
+    fn test_codepoint_range() {
+        assert_eq!(codepoint_range(0x41, 0x41), "U+0041");
+        assert_eq!(codepoint_range(0x41, 0x5A), "U+0041-U+005A");
+        assert_eq!(codepoint_range(0x1F600, 0x1F64F), "U+1F600-U+1F64F");
+    }
 }
@@ -69,7 +69,7 @@ fn dispatch() -> HintedStrResult<()> {
         Command::Watch(command) => crate::watch::watch(&mut timer, command)?,
         Command::Init(command) => crate::init::init(command)?,
         Command::Query(command) => crate::query::query(command)?,
-        Command::Fonts(command) => crate::fonts::fonts(command),
+        Command::Fonts(command) => crate::fonts::fonts(command)?,
         Command::Update(command) => crate::update::update(command)?,
     }
 
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use ecow::eco_format;
 use typst_library::diag::{bail, error, At, SourceDiagnostic, SourceResult};
-use typst_library::foundations::{Array, Dict, Value};
+use typst_library::foundations::{ops, Array, Dict, Value};
 use typst_syntax::ast::{self, AstNode};
 
 use crate::{Access, Eval, Vm};
@@ -78,6 +78,9 @@ where
             Value::Dict(value) => destructure_dict(vm, destruct, value, f)?,
             _ => bail!(pattern.span(), "cannot destructure {}", value.ty()),
         },
+        ast::Pattern::Typed(typed) => {
+            bail!(typed.span(), "typed patterns are only allowed in match arms")
+        }
     }
     Ok(())
 }
@@ -208,3 +211,150 @@ fn wrong_number_of_elements(
                but the pattern expects {expected}",
     )
 }
+
+/// Checks whether a value matches the pattern of a match arm.
+///
+/// Binds the pattern's identifiers in the current scope along the way. As
+/// bindings may already have been made when a later part of the pattern
+/// doesn't match, each arm should be tried in its own scope.
+pub(crate) fn matches(
+    vm: &mut Vm,
+    pattern: ast::Pattern,
+    value: Value,
+) -> SourceResult<bool> {
+    Ok(match pattern {
+        ast::Pattern::Normal(ast::Expr::Ident(ident)) => {
+            vm.define(ident, value);
+            true
+        }
+        ast::Pattern::Normal(expr) => ops::equal(&value, &expr.eval(vm)?),
+        ast::Pattern::Placeholder(_) => true,
+        ast::Pattern::Parenthesized(parenthesized) => {
+            matches(vm, parenthesized.pattern(), value)?
+        }
+        ast::Pattern::Destructuring(destruct) => match value {
+            Value::Array(array) => matches_array(vm, destruct, array)?,
+            Value::Dict(dict) => matches_dict(vm, destruct, dict)?,
+            _ => false,
+        },
+        ast::Pattern::Typed(typed) => matches_typed(vm, typed, value)?,
+    })
+}
+
+/// Checks whether an array matches a destructuring pattern.
+fn matches_array(
+    vm: &mut Vm,
+    destruct: ast::Destructuring,
+    array: Array,
+) -> SourceResult<bool> {
+    let len = array.as_slice().len();
+    let mut count = 0;
+    let mut spread = false;
+
+    for p in destruct.items() {
+        match p {
+            ast::DestructuringItem::Pattern(_) => count += 1,
+            ast::DestructuringItem::Spread(_) => spread = true,
+            ast::DestructuringItem::Named(_) => return Ok(false),
+        }
+    }
+
+    if len < count || (!spread && len > count) {
+        return Ok(false);
+    }
+
+    let mut i = 0;
+    for p in destruct.items() {
+        match p {
+            ast::DestructuringItem::Pattern(pattern) => {
+                if !matches(vm, pattern, array.as_slice()[i].clone())? {
+                    return Ok(false);
+                }
+                i += 1;
+            }
+            ast::DestructuringItem::Spread(spread) => {
+                let sink_size = len - count;
+                if let Some(ident) = spread.sink_ident() {
+                    let sink = &array.as_slice()[i..i + sink_size];
+                    vm.define(ident, Value::Array(sink.into()));
+                }
+                i += sink_size;
+            }
+            ast::DestructuringItem::Named(_) => {}
+        }
+    }
+
+    Ok(true)
+}
+
+/// Checks whether a dictionary matches a destructuring pattern.
+fn matches_dict(
+    vm: &mut Vm,
+    destruct: ast::Destructuring,
+    dict: Dict,
+) -> SourceResult<bool> {
+    // An empty pattern only matches an empty dictionary.
+    if destruct.items().next().is_none() {
+        return Ok(dict.is_empty());
+    }
+
+    let mut sink = None;
+    let mut used = HashSet::new();
+
+    for p in destruct.items() {
+        match p {
+            // Shorthand for a direct identifier.
+            ast::DestructuringItem::Pattern(ast::Pattern::Normal(ast::Expr::Ident(
+                ident,
+            ))) => {
+                let Ok(v) = dict.get(&ident) else { return Ok(false) };
+                vm.define(ident, v.clone());
+                used.insert(ident.get().clone());
+            }
+            ast::DestructuringItem::Named(named) => {
+                let name = named.name();
+                let Ok(v) = dict.get(&name) else { return Ok(false) };
+                if !matches(vm, named.pattern(), v.clone())? {
+                    return Ok(false);
+                }
+                used.insert(name.get().clone());
+            }
+            ast::DestructuringItem::Spread(spread) => sink = spread.sink_ident(),
+            ast::DestructuringItem::Pattern(_) => return Ok(false),
+        }
+    }
+
+    if let Some(ident) = sink {
+        let mut sink = Dict::new();
+        for (key, value) in dict {
+            if !used.contains(key.as_str()) {
+                sink.insert(key, value);
+            }
+        }
+        vm.define(ident, Value::Dict(sink));
+    }
+
+    Ok(true)
+}
+
+/// Checks whether a value matches a typed pattern.
+fn matches_typed(
+    vm: &mut Vm,
+    typed: ast::TypedPattern,
+    value: Value,
+) -> SourceResult<bool> {
+    let ty = typed.ty();
+    let fits = match ty.eval(vm)? {
+        Value::Type(ty) => value.ty() == ty,
+        Value::Func(func) if func.element().is_some() => matches!(
+            &value,
+            Value::Content(content) if Some(content.elem()) == func.element()
+        ),
+        v => bail!(ty.span(), "expected type or element function, found {}", v.ty()),
+    };
+
+    match typed.pattern() {
+        Some(pattern) if fits => matches(vm, pattern, value),
+        _ => Ok(fits),
+    }
+}
@@ -542,6 +542,33 @@ impl<'a> CapturesVisitor<'a> {
                 self.internal.exit();
             }
 
+            // A match arm contains bindings in its pattern. These are active in
+            // the arm's guard and body.
+            Some(ast::Expr::MatchExpr(expr)) => {
+                self.visit(expr.value().to_untyped());
+                for arm in expr.arms() {
+                    self.internal.enter();
+                    self.visit_match_pattern(arm.pattern());
+                    if let Some(guard) = arm.guard() {
+                        self.visit(guard.to_untyped());
+                    }
+                    self.visit(arm.body().to_untyped());
+                    self.internal.exit();
+                }
+            }
+
+            // A try-catch expression may bind the caught error, which is only
+            // active in the handler.
+            Some(ast::Expr::TryCatch(expr)) => {
+                self.visit(expr.body().to_untyped());
+                self.internal.enter();
+                if let Some(ident) = expr.binding() {
+                    self.bind(ident);
+                }
+                self.visit(expr.handler().to_untyped());
+                self.internal.exit();
+            }
+
             // An import contains items, but these are active only after the
             // path is evaluated.
             Some(ast::Expr::ModuleImport(expr)) => {
@@ -568,6 +595,41 @@ impl<'a> CapturesVisitor<'a> {
         }
     }
 
+    /// Visit the expressions in a match pattern and bind its identifiers.
+    fn visit_match_pattern(&mut self, pattern: ast::Pattern) {
+        match pattern {
+            ast::Pattern::Normal(ast::Expr::Ident(ident)) => self.bind(ident),
+            ast::Pattern::Normal(expr) => self.visit(expr.to_untyped()),
+            ast::Pattern::Placeholder(_) => {}
+            ast::Pattern::Parenthesized(parenthesized) => {
+                self.visit_match_pattern(parenthesized.pattern())
+            }
+            ast::Pattern::Destructuring(destruct) => {
+                for item in destruct.items() {
+                    match item {
+                        ast::DestructuringItem::Pattern(pattern) => {
+                            self.visit_match_pattern(pattern)
+                        }
+                        ast::DestructuringItem::Named(named) => {
+                            self.visit_match_pattern(named.pattern())
+                        }
+                        ast::DestructuringItem::Spread(spread) => {
+                            if let Some(ident) = spread.sink_ident() {
+                                self.bind(ident);
+                            }
+                        }
+                    }
+                }
+            }
+            ast::Pattern::Typed(typed) => {
+                self.visit(typed.ty().to_untyped());
+                if let Some(pattern) = typed.pattern() {
+                    self.visit_match_pattern(pattern);
+                }
+            }
+        }
+    }
+
     /// Bind a new internal variable.
     fn bind(&mut self, ident: ast::Ident) {
         // The concrete value does not matter as we only use the scoping
@@ -653,6 +715,15 @@ mod tests {
         test(s, "#for (x, y) in y { x + y }", &["y"]);
         test(s, "#for x in y {} #x", &["x", "y"]);
 
+        // Match expression.
+        test(s, "#match x { y => y + z }", &["x", "z"]);
+        test(s, "#match x { (y, ..z) if y > 0 => z, _ => y }", &["x", "y"]);
+        test(s, "#match x { f(y) => y, (x: z) => z }", &["f", "x"]);
+
+        // Try-catch expression.
+        test(s, "#try { x } catch err { err + y }", &["x", "y"]);
+        test(s, "#try { let z = 1 } catch { z }", &["z"]);
+
         // Import.
         test(s, "#import z: x, y", &["z"]);
         test(s, "#import x + y: x, y, z", &["x", "y"]);
@@ -134,6 +134,8 @@ impl Eval for ast::Expr<'_> {
             Self::Conditional(v) => v.eval(vm),
             Self::WhileLoop(v) => v.eval(vm),
             Self::ForLoop(v) => v.eval(vm),
+            Self::MatchExpr(v) => v.eval(vm),
+            Self::TryCatch(v) => v.eval(vm),
             Self::ModuleImport(v) => v.eval(vm),
             Self::ModuleInclude(v) => v.eval(vm).map(Value::Content),
             Self::LoopBreak(v) => v.eval(vm),
@@ -1,10 +1,10 @@
-use typst_library::diag::{bail, error, At, SourceDiagnostic, SourceResult};
-use typst_library::foundations::{ops, IntoValue, Value};
+use typst_library::diag::{bail, error, warning, At, SourceDiagnostic, SourceResult};
+use typst_library::foundations::{dict, ops, Array, Dict, IntoValue, Repr, Value};
 use typst_syntax::ast::{self, AstNode};
 use typst_syntax::{Span, SyntaxKind, SyntaxNode};
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::{destructure, Eval, Vm};
+use crate::{destructure, matches, Eval, Vm};
 
 /// The maximum number of loop iterations.
 const MAX_ITERATIONS: usize = 10_000;
@@ -189,6 +189,99 @@ impl Eval for ast::ForLoop<'_> {
     }
 }
 
+impl Eval for ast::MatchExpr<'_> {
+    type Output = Value;
+
+    fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        warn_for_unreachable_arms(vm, self);
+
+        let value = self.value().eval(vm)?;
+        for arm in self.arms() {
+            vm.scopes.enter();
+            let selected = matches(vm, arm.pattern(), value.clone())?
+                && match arm.guard() {
+                    Some(guard) => guard.eval(vm)?.cast::<bool>().at(guard.span())?,
+                    None => true,
+                };
+            let output = if selected { Some(arm.body().eval(vm)?) } else { None };
+            vm.scopes.exit();
+
+            if let Some(output) = output {
+                // Mark the return as conditional.
+                if let Some(FlowEvent::Return(_, _, conditional)) = &mut vm.flow {
+                    *conditional = true;
+                }
+
+                return Ok(output);
+            }
+        }
+
+        bail!(
+            self.value().span(), "no match arm matches {}", value.repr();
+            hint: "add a final `_` arm to handle all remaining values"
+        )
+    }
+}
+
+/// Warns about the first arm following an arm that matches any value.
+fn warn_for_unreachable_arms(vm: &mut Vm, expr: ast::MatchExpr) {
+    let mut arms = expr.arms().skip_while(|arm| {
+        arm.guard().is_some()
+            || !matches!(
+                arm.pattern(),
+                ast::Pattern::Placeholder(_) | ast::Pattern::Normal(ast::Expr::Ident(_))
+            )
+    });
+
+    if let (Some(_), Some(unreachable)) = (arms.next(), arms.next()) {
+        vm.engine.sink.warn(warning!(
+            unreachable.span(),
+            "unreachable match arm";
+            hint: "the previous arm already matches all values"
+        ));
+    }
+}
+
+impl Eval for ast::TryCatch<'_> {
+    type Output = Value;
+
+    fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        let depth = vm.scopes.scopes.len();
+        let errors = match self.body().eval(vm) {
+            Ok(value) => return Ok(value),
+            Err(errors) => errors,
+        };
+
+        // Leave the scopes the failed body didn't get to exit.
+        while vm.scopes.scopes.len() > depth {
+            vm.scopes.exit();
+        }
+
+        vm.scopes.enter();
+        if let Some(ident) = self.binding() {
+            vm.define(ident, caught(&errors));
+        }
+        let output = self.handler().eval(vm)?;
+        vm.scopes.exit();
+        Ok(output)
+    }
+}
+
+/// Describes caught errors. The message and hints of the first error are
+/// available directly, while `errors` lists all of them in the same form.
+fn caught(errors: &[SourceDiagnostic]) -> Dict {
+    let describe = |error: &SourceDiagnostic| {
+        let hints: Array =
+            error.hints.iter().cloned().map(IntoValue::into_value).collect();
+        dict! { "message" => error.message.clone(), "hints" => hints }
+    };
+
+    let mut dict = errors.first().map(describe).unwrap_or_default();
+    let all: Array = errors.iter().map(|error| describe(error).into_value()).collect();
+    dict.insert("errors".into(), all.into_value());
+    dict
+}
+
 impl Eval for ast::LoopBreak<'_> {
     type Output = Value;
 
@@ -17,7 +17,7 @@ use typst_library::introspection::{
 use typst_library::layout::{Abs, Axes, BlockBody, BlockElem, BoxElem, Region, Size};
 use typst_library::model::{DocumentInfo, ParElem};
 use typst_library::routines::{Arenas, FragmentKind, Pair, RealizationKind, Routines};
-use typst_library::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TextElem};
+use typst_library::text::{LinebreakElem, RubyElem, SmartQuoteElem, SpaceElem, TextElem};
 use typst_library::World;
 use typst_syntax::Span;
 
@@ -239,6 +239,34 @@ fn handle(
             if elem.double(styles) { '"' } else { '\'' },
             child.span(),
         ));
+    } else if let Some(elem) = child.to_packed::<RubyElem>() {
+        let mut children = vec![];
+        for (base, annotation) in elem.units(styles).at(elem.span())? {
+            children.extend(html_fragment(
+                engine,
+                &base,
+                locator.next(&base.span()),
+                styles,
+            )?);
+            let annotation = html_fragment(
+                engine,
+                &annotation,
+                locator.next(&annotation.span()),
+                styles,
+            )?;
+            children.push(
+                HtmlElement::new(tag::rt)
+                    .with_children(annotation)
+                    .spanned(elem.span())
+                    .into(),
+            );
+        }
+        output.push(
+            HtmlElement::new(tag::ruby)
+                .with_children(children)
+                .spanned(elem.span())
+                .into(),
+        );
     } else if let Some(elem) = child.to_packed::<FrameElem>() {
         let locator = locator.next(&elem.span());
         let style = TargetElem::set_target(Target::Paged).wrap();
@@ -1006,6 +1006,18 @@ fn code_completions(ctx: &mut CompletionContext, hash: bool) {
         "Computes or inserts different things based on a condition.",
     );
 
+    ctx.snippet_completion(
+        "match expression",
+        "match ${value} {\n\t${pattern} => ${},\n\t_ => ${},\n}",
+        "Computes or inserts different things based on the shape of a value.",
+    );
+
+    ctx.snippet_completion(
+        "try-catch expression",
+        "try {\n\t${}\n} catch ${err} {\n\t${}\n}",
+        "Recovers from errors that occur while evaluating code.",
+    );
+
     ctx.snippet_completion(
         "while loop",
         "while ${1 < 2} {\n\t${}\n}",
@@ -1,6 +1,8 @@
 use std::cell::{LazyCell, RefCell};
 use std::fmt::{self, Debug, Formatter};
 use std::hash::Hash;
+use std::num::NonZeroUsize;
+use std::ops::Range;
 
 use bumpalo::boxed::Box as BumpBox;
 use bumpalo::Bump;
@@ -12,9 +14,9 @@ use typst_library::introspection::{
     Introspector, Location, Locator, LocatorLink, SplitLocator, Tag, TagElem,
 };
 use typst_library::layout::{
-    Abs, AlignElem, Alignment, Axes, BlockElem, ColbreakElem, FixedAlignment, FlushElem,
-    Fr, Fragment, Frame, PagebreakElem, PlaceElem, PlacementScope, Ratio, Region,
-    Regions, Rel, Size, Sizing, Spacing, VElem,
+    Abs, AlignElem, Alignment, Axes, Binding, BlockElem, ColbreakElem, Dir,
+    FixedAlignment, FloatSide, FlushElem, Fr, Fragment, Frame, PageElem, PagebreakElem,
+    PlaceElem, PlacementScope, Ratio, Region, Regions, Rel, Size, Sizing, Spacing, VElem,
 };
 use typst_library::model::ParElem;
 use typst_library::routines::{Pair, Routines};
@@ -23,7 +25,7 @@ use typst_library::World;
 use typst_utils::SliceExt;
 
 use super::{layout_multi_block, layout_single_block, FlowMode};
-use crate::inline::ParSituation;
+use crate::inline::{ParSituation, ParSpace};
 use crate::modifiers::layout_and_modify;
 
 /// Collects all elements of the flow into prepared children. These are much
@@ -48,6 +50,7 @@ pub fn collect<'a>(
         expand,
         output: Vec::with_capacity(children.len()),
         par_situation: ParSituation::First,
+        pending: vec![],
     }
     .run(mode)
 }
@@ -62,6 +65,9 @@ struct Collector<'a, 'x, 'y> {
     locator: SplitLocator<'a>,
     output: Vec<Child<'a>>,
     par_situation: ParSituation,
+    /// Paragraphs whose lines are yet to be laid out, along with the index in
+    /// the output at which their lines belong.
+    pending: Vec<(usize, &'a mut ParChild<'a>)>,
 }
 
 impl<'a> Collector<'a, '_, '_> {
@@ -83,7 +89,7 @@ impl<'a> Collector<'a, '_, '_> {
             } else if let Some(elem) = child.to_packed::<ParElem>() {
                 self.par(elem, styles)?;
             } else if let Some(elem) = child.to_packed::<BlockElem>() {
-                self.block(elem, styles);
+                self.block(elem, styles)?;
             } else if let Some(elem) = child.to_packed::<PlaceElem>() {
                 self.place(elem, styles)?;
             } else if child.is::<FlushElem>() {
@@ -104,6 +110,7 @@ impl<'a> Collector<'a, '_, '_> {
             }
         }
 
+        self.flush_pars()?;
         Ok(self.output)
     }
 
@@ -152,88 +159,131 @@ impl<'a> Collector<'a, '_, '_> {
     }
 
     /// Collect a paragraph into [`LineChild`]ren. This already performs line
-    /// layout since it is not dependent on the concrete regions.
+    /// layout since it is not dependent on the concrete regions. The layout
+    /// itself is deferred until [`Self::flush_pars`] so that consecutive
+    /// paragraphs can be laid out in parallel.
     fn par(
         &mut self,
         elem: &'a Packed<ParElem>,
         styles: StyleChain<'a>,
     ) -> SourceResult<()> {
-        let lines = crate::inline::layout_par(
-            elem,
-            self.engine,
-            self.locator.next(&elem.span()),
-            styles,
-            self.base,
-            self.expand,
-            self.par_situation,
-        )?
-        .into_frames();
-
         let spacing = elem.spacing(styles);
         let leading = elem.leading(styles);
 
-        self.output.push(Child::Rel(spacing.into(), 4));
-
-        self.lines(lines, leading, styles);
+        let par = self.bump.alloc(ParChild {
+            leading,
+            children: 0,
+            elem,
+            styles,
+            locator: self.locator.next(&elem.span()),
+            situation: self.par_situation,
+            base: self.base,
+            expand: self.expand,
+        });
 
+        self.output.push(Child::Rel(spacing.into(), 4));
+        self.pending.push((self.output.len(), par));
         self.output.push(Child::Rel(spacing.into(), 4));
         self.par_situation = ParSituation::Consecutive;
 
         Ok(())
     }
 
+    /// Lays out the pending paragraphs in parallel and inserts their lines
+    /// into the output.
+    fn flush_pars(&mut self) -> SourceResult<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        let pending = std::mem::take(&mut self.pending);
+        let work = pending.iter().map(|(_, par)| {
+            (
+                par.elem,
+                par.styles,
+                par.locator.relayout(),
+                par.situation,
+                par.base,
+                par.expand,
+            )
+        });
+
+        let results = self
+            .engine
+            .parallelize(
+                work,
+                |engine, (elem, styles, locator, situation, base, expand)| {
+                    crate::inline::layout_par(
+                        elem,
+                        engine,
+                        locator,
+                        styles,
+                        ParSpace { region: base, expand, exclusions: &[] },
+                        situation,
+                    )
+                    .map(Fragment::into_frames)
+                },
+            )
+            .collect::<SourceResult<Vec<_>>>()?;
+
+        let mut rest = std::mem::take(&mut self.output).into_iter();
+        let mut cursor = 0;
+        for ((at, par), lines) in pending.into_iter().zip(results) {
+            self.output.extend(rest.by_ref().take(at - cursor));
+            cursor = at;
+
+            // The paragraph is attached to its first line so that it can be
+            // laid out again if needed.
+            let start = self.output.len();
+            self.lines(lines, par.leading, par.styles);
+            par.children = self.output.len() - start;
+            let par: &'a ParChild<'a> = par;
+            if let Some(Child::Line(line)) = self.output.get_mut(start) {
+                line.par = Some(par);
+            }
+        }
+
+        self.output.extend(rest);
+        Ok(())
+    }
+
     /// Collect laid-out lines.
     fn lines(&mut self, lines: Vec<Frame>, leading: Abs, styles: StyleChain<'a>) {
         let align = AlignElem::alignment_in(styles).resolve(styles);
-        let costs = TextElem::costs_in(styles);
-
-        // Determine whether to prevent widow and orphans.
-        let len = lines.len();
-        let prevent_orphans =
-            costs.orphan() > Ratio::zero() && len >= 2 && !lines[1].is_empty();
-        let prevent_widows =
-            costs.widow() > Ratio::zero() && len >= 2 && !lines[len - 2].is_empty();
-        let prevent_all = len == 3 && prevent_orphans && prevent_widows;
-
-        // Store the heights of lines at the edges because we'll potentially
-        // need these later when `lines` is already moved.
-        let height_at = |i| lines.get(i).map(Frame::height).unwrap_or_default();
-        let front_1 = height_at(0);
-        let front_2 = height_at(1);
-        let back_2 = height_at(len.saturating_sub(2));
-        let back_1 = height_at(len.saturating_sub(1));
-
-        for (i, frame) in lines.into_iter().enumerate() {
+        let needs = line_needs(&lines, leading, styles);
+
+        for (i, (frame, need)) in lines.into_iter().zip(needs).enumerate() {
             if i > 0 {
                 self.output.push(Child::Rel(leading.into(), 5));
             }
 
-            // To prevent widows and orphans, we require enough space for
-            // - all lines if it's just three
-            // - the first two lines if we're at the first line
-            // - the last two lines if we're at the second to last line
-            let need = if prevent_all && i == 0 {
-                front_1 + leading + front_2 + leading + back_1
-            } else if prevent_orphans && i == 0 {
-                front_1 + leading + front_2
-            } else if prevent_widows && i >= 2 && i + 2 == len {
-                back_2 + leading + back_1
-            } else {
-                frame.height()
-            };
-
-            self.output
-                .push(Child::Line(self.boxed(LineChild { frame, align, need })));
+            self.output.push(Child::Line(self.boxed(LineChild {
+                frame,
+                align,
+                need,
+                sticky: false,
+                par: None,
+            })));
         }
     }
 
     /// Collect a block into a [`SingleChild`] or [`MultiChild`] depending on
     /// whether it is breakable.
-    fn block(&mut self, elem: &'a Packed<BlockElem>, styles: StyleChain<'a>) {
+    fn block(
+        &mut self,
+        elem: &'a Packed<BlockElem>,
+        styles: StyleChain<'a>,
+    ) -> SourceResult<()> {
         let locator = self.locator.next(&elem.span());
         let align = AlignElem::alignment_in(styles).resolve(styles);
         let alone = self.children.len() == 1;
         let sticky = elem.sticky(styles);
+        if elem.attached(styles) {
+            // The block attaches to the lines of preceding paragraphs, so they
+            // must be laid out first.
+            self.flush_pars()?;
+            self.attach();
+        }
         let breakable = elem.breakable(styles);
         let fr = match elem.height(styles) {
             Sizing::Fr(fr) => Some(fr),
@@ -274,6 +324,22 @@ impl<'a> Collector<'a, '_, '_> {
 
         self.output.push(spacing(elem.below(styles)));
         self.par_situation = ParSituation::Other;
+        Ok(())
+    }
+
+    /// Makes the last in-flow line or block sticky so that it stays together
+    /// with the block that follows it.
+    fn attach(&mut self) {
+        for child in self.output.iter_mut().rev() {
+            match child {
+                Child::Line(line) => line.sticky = true,
+                Child::Single(single) => single.sticky = true,
+                Child::Multi(multi) => multi.sticky = true,
+                Child::Tag(_) | Child::Rel(..) | Child::Placed(_) => continue,
+                Child::Fr(_) | Child::Flush | Child::Break(_) => {}
+            }
+            break;
+        }
     }
 
     /// Collects a placed element into a [`PlacedChild`].
@@ -290,11 +356,33 @@ impl<'a> Collector<'a, '_, '_> {
         let scope = elem.scope(styles);
         let float = elem.float(styles);
 
+        let side = elem.side(styles);
+        if side.is_some() {
+            if !float {
+                bail!(
+                    elem.span(),
+                    "side placement is only available for floating placement";
+                    hint: "you can enable floating placement with `place(float: true, ..)`"
+                );
+            } else if scope == PlacementScope::Parent {
+                bail!(elem.span(), "side floats cannot be parent-scoped");
+            }
+        }
+
+        let float_page = float && elem.float_page(styles);
+        if float_page && scope == PlacementScope::Parent {
+            bail!(elem.span(), "float pages cannot be parent-scoped");
+        }
+
         match (float, align_y) {
-            (true, Smart::Custom(None | Some(FixedAlignment::Center))) => bail!(
-                elem.span(),
-                "vertical floating placement must be `auto`, `top`, or `bottom`"
-            ),
+            (true, Smart::Custom(None | Some(FixedAlignment::Center)))
+                if side.is_none() =>
+            {
+                bail!(
+                    elem.span(),
+                    "vertical floating placement must be `auto`, `top`, or `bottom`"
+                )
+            }
             (false, Smart::Auto) => bail!(
                 elem.span(),
                 "automatic positioning is only available for floating placement";
@@ -319,7 +407,12 @@ impl<'a> Collector<'a, '_, '_> {
             align_y,
             scope,
             float,
+            side,
             clearance,
+            defer: elem.defer(styles),
+            float_page,
+            max_count: elem.max_count(styles),
+            max_fraction: elem.max_fraction(styles),
             delta,
             elem,
             styles,
@@ -350,7 +443,7 @@ pub enum Child<'a> {
     /// Fractional spacing.
     Fr(Fr),
     /// An already layouted line of a paragraph.
-    Line(BumpBox<'a, LineChild>),
+    Line(BumpBox<'a, LineChild<'a>>),
     /// An unbreakable block.
     Single(BumpBox<'a, SingleChild<'a>>),
     /// A breakable block.
@@ -364,11 +457,117 @@ pub enum Child<'a> {
 }
 
 /// A child that encapsulates a layouted line of a paragraph.
-#[derive(Debug)]
-pub struct LineChild {
+#[derive(Debug, Clone)]
+pub struct LineChild<'a> {
     pub frame: Frame,
     pub align: Axes<FixedAlignment>,
     pub need: Abs,
+    /// Whether the line must stay together with the following block.
+    pub sticky: bool,
+    /// The paragraph, if this is its first line.
+    pub par: Option<&'a ParChild<'a>>,
+}
+
+/// Determines how much space each line needs, including that of the following
+/// lines it is grouped with by widow and orphan prevention.
+fn line_needs(lines: &[Frame], leading: Abs, styles: StyleChain) -> Vec<Abs> {
+    // Determine how many lines to keep together at the start and end of the
+    // paragraph to prevent orphans and widows. A count of one disables the
+    // prevention.
+    let len = lines.len();
+    let costs = TextElem::costs_in(styles);
+    let orphans = if costs.orphan() > Ratio::zero() && len >= 2 && !lines[1].is_empty() {
+        ParElem::orphans_in(styles).get().min(len)
+    } else {
+        1
+    };
+    let widows =
+        if costs.widow() > Ratio::zero() && len >= 2 && !lines[len - 2].is_empty() {
+            ParElem::widows_in(styles).get().min(len)
+        } else {
+            1
+        };
+    let prevent_all = orphans > 1 && widows > 1 && orphans + widows > len;
+
+    // The height of the given lines including the leading between them.
+    let span = |range: Range<usize>| {
+        let count = range.len();
+        lines[range].iter().map(Frame::height).sum::<Abs>()
+            + leading * count.saturating_sub(1) as f64
+    };
+
+    (0..len)
+        .map(|i| {
+            // To prevent widows and orphans, we require enough space for
+            // - all lines if they can't be split without leaving too few
+            //   lines on either side
+            // - the first `orphans` lines if we're at the first line
+            // - the last `widows` lines if we're at the first of them
+            if prevent_all && i == 0 {
+                span(0..len)
+            } else if orphans > 1 && i == 0 {
+                span(0..orphans)
+            } else if widows > 1 && i >= orphans.max(2) && i + widows == len {
+                span(i..len)
+            } else {
+                lines[i].height()
+            }
+        })
+        .collect()
+}
+
+/// A paragraph whose lines are already laid out, but which can be laid out
+/// again to wrap around side floats.
+#[derive(Debug)]
+pub struct ParChild<'a> {
+    /// The spacing between the paragraph's lines.
+    pub leading: Abs,
+    /// The number of children that the lines of the full-width layout and
+    /// the leading between them take up in the flow.
+    pub children: usize,
+    elem: &'a Packed<ParElem>,
+    styles: StyleChain<'a>,
+    locator: Locator<'a>,
+    situation: ParSituation,
+    base: Size,
+    expand: bool,
+}
+
+impl<'a> ParChild<'a> {
+    /// Lays out the paragraph's lines, reducing the width available to each
+    /// line by its entry in `exclusions`.
+    pub fn layout(
+        &self,
+        engine: &mut Engine,
+        exclusions: &[Abs],
+    ) -> SourceResult<Vec<Frame>> {
+        crate::inline::layout_par(
+            self.elem,
+            engine,
+            self.locator.relayout(),
+            self.styles,
+            ParSpace { region: self.base, expand: self.expand, exclusions },
+            self.situation,
+        )
+        .map(Fragment::into_frames)
+    }
+
+    /// Turns lines laid out with [`Self::layout`] into [`LineChild`]ren.
+    pub fn children(&self, lines: Vec<Frame>) -> Vec<LineChild<'a>> {
+        let align = AlignElem::alignment_in(self.styles).resolve(self.styles);
+        let needs = line_needs(&lines, self.leading, self.styles);
+        lines
+            .into_iter()
+            .zip(needs)
+            .map(|(frame, need)| LineChild {
+                frame,
+                align,
+                need,
+                sticky: false,
+                par: None,
+            })
+            .collect()
+    }
 }
 
 /// A child that encapsulates a prepared unbreakable block.
@@ -617,7 +816,16 @@ pub struct PlacedChild<'a> {
     pub align_y: Smart<Option<FixedAlignment>>,
     pub scope: PlacementScope,
     pub float: bool,
+    pub side: Option<FloatSide>,
     pub clearance: Abs,
+    /// Whether the float must not appear above its position in the flow.
+    pub defer: bool,
+    /// Whether the float goes onto a page or column of its own.
+    pub float_page: bool,
+    /// How many floats may be in the float's insertion area at most.
+    pub max_count: Option<NonZeroUsize>,
+    /// How much of its insertion area's height floats may take up at most.
+    pub max_fraction: Option<Ratio>,
     pub delta: Axes<Rel<Abs>>,
     elem: &'a Packed<PlaceElem>,
     styles: StyleChain<'a>,
@@ -656,6 +864,36 @@ impl PlacedChild<'_> {
     pub fn location(&self) -> Location {
         self.elem.location().unwrap()
     }
+
+    /// Resolves the side of a side float to `Start` (left) or `End` (right).
+    pub fn resolve_side(&self, engine: &Engine, side: FloatSide) -> FixedAlignment {
+        let dir = TextElem::dir_in(self.styles);
+        let left = match side {
+            FloatSide::Left => true,
+            FloatSide::Right => false,
+            FloatSide::Start => dir.is_positive(),
+            FloatSide::End => !dir.is_positive(),
+            FloatSide::Inside | FloatSide::Outside => {
+                let binding =
+                    PageElem::binding_in(self.styles).unwrap_or_else(|| match dir {
+                        Dir::LTR => Binding::Left,
+                        _ => Binding::Right,
+                    });
+
+                // The inside is on the left unless the page's margins are
+                // swapped.
+                let page = engine.introspector.page(self.location());
+                let inside_left = !binding.swap(page);
+                (side == FloatSide::Inside) == inside_left
+            }
+        };
+
+        if left {
+            FixedAlignment::Start
+        } else {
+            FixedAlignment::End
+        }
+    }
 }
 
 /// Wraps a parameterized computation and caches its latest output.
@@ -1,15 +1,16 @@
 use std::num::NonZeroUsize;
 
+use comemo::Track;
 use typst_library::diag::SourceResult;
-use typst_library::engine::Engine;
+use typst_library::engine::{Engine, Sink};
 use typst_library::foundations::{Content, NativeElement, Packed, Resolve, Smart};
 use typst_library::introspection::{
     Counter, CounterDisplayElem, CounterState, CounterUpdate, Location, Locator,
     SplitLocator, Tag,
 };
 use typst_library::layout::{
-    Abs, Axes, Dir, FixedAlignment, Fragment, Frame, FrameItem, OuterHAlignment,
-    PlacementScope, Point, Region, Regions, Rel, Size,
+    Abs, Axes, ColumnBalance, Dir, FixedAlignment, Fragment, Frame, FrameItem,
+    OuterHAlignment, PlacementScope, Point, Region, Regions, Rel, Size,
 };
 use typst_library::model::{
     FootnoteElem, FootnoteEntry, LineNumberingScope, Numbering, ParLineMarker,
@@ -111,8 +112,140 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
             return self.column(locator, regions);
         }
 
+        if self.config.columns.balance == ColumnBalance::Off {
+            return self.columns(locator, regions, regions.size.y);
+        }
+
+        // Lay out the columns at full height first to find out how much
+        // content fits into this region.
+        let checkpoint = self.work.clone();
+        let insertions = self.page_insertions.clone();
+        let footnote_spill = self.footnote_spill.clone();
+        let footnote_queue = self.footnote_queue.clone();
+        let full = self.columns(locator.relayout(), regions, regions.size.y)?;
+
+        // Only balance if the region was filled without anything breaking
+        // across into the next one. If something breaks across, the columns
+        // up to the break are full and shortening them would move content.
+        // With `{true}`, we additionally only balance the region in which the
+        // flow ends.
+        let settled = !self.work.spilled()
+            && self.footnote_spill.is_none()
+            && self.footnote_queue.is_empty();
+        let last = self.work.done();
+        if !settled || (self.config.columns.balance == ColumnBalance::Last && !last) {
+            return Ok(full);
+        }
+
+        // Search for the smallest column height with which the same content
+        // still fits into the region.
+        let target = self.work.left();
+        let mut lo = Abs::zero();
+        let mut hi = if regions.expand.y { regions.size.y } else { full.height() };
+        let mut best = None;
+
+        // When probing, there must always be a next region so that content
+        // which doesn't fit is deferred instead of overflowing the last column.
+        let probe = Regions { backlog: &[Abs::inf()], ..regions };
+        let full_state = (self.work.clone(), self.page_insertions.clone());
+        while hi - lo > Abs::pt(1.0) {
+            let mid = (lo + hi) / 2.0;
+            *self.work = checkpoint.clone();
+            self.page_insertions = insertions.clone();
+            self.footnote_spill = footnote_spill.clone();
+            self.footnote_queue = footnote_queue.clone();
+
+            let fits = match self.probe(locator.relayout(), probe, mid) {
+                Ok(_) => {
+                    self.work.left() == target
+                        && !self.work.spilled()
+                        && self.footnote_spill.is_none()
+                        && self.footnote_queue.is_empty()
+                }
+                // A float requested a relayout of the page. Propagate it so
+                // that the whole region is laid out again with the float.
+                Err(err) => return Err(err),
+            };
+
+            if fits {
+                best = Some(mid);
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+
+        // Lay out the columns at the best height once more, this time
+        // reporting diagnostics.
+        *self.work = checkpoint;
+        self.page_insertions = insertions;
+        self.footnote_spill = footnote_spill;
+        self.footnote_queue = footnote_queue;
+        match best {
+            Some(height) => self.columns(locator, probe, height),
+            None => {
+                (*self.work, self.page_insertions) = full_state;
+                self.footnote_spill = None;
+                self.footnote_queue.clear();
+                Ok(full)
+            }
+        }
+    }
+
+    /// Lay out columns of the given height like [`Self::columns`], but without
+    /// reporting diagnostics.
+    ///
+    /// Column balancing tries out many heights, most of which are discarded.
+    /// Warnings and delayed errors from those attempts could describe
+    /// problems that the final layout doesn't have, so they are collected in
+    /// a scratch sink and dropped.
+    fn probe(
+        &mut self,
+        locator: Locator,
+        regions: Regions,
+        column_height: Abs,
+    ) -> FlowResult<Frame> {
+        let Engine {
+            routines, world, introspector, traced, ref route, ..
+        } = *self.engine;
+
+        let mut sink = Sink::new();
+        let mut engine = Engine {
+            routines,
+            world,
+            introspector,
+            traced,
+            sink: sink.track_mut(),
+            route: route.clone(),
+        };
+
+        let mut composer = Composer {
+            engine: &mut engine,
+            work: &mut *self.work,
+            config: self.config,
+            column: 0,
+            page_base: self.page_base,
+            page_insertions: std::mem::take(&mut self.page_insertions),
+            column_insertions: Insertions::default(),
+            footnote_spill: self.footnote_spill.take(),
+            footnote_queue: std::mem::take(&mut self.footnote_queue),
+        };
+
+        let result = composer.columns(locator, regions, column_height);
+        self.page_insertions = composer.page_insertions;
+        self.footnote_spill = composer.footnote_spill;
+        self.footnote_queue = composer.footnote_queue;
+        result
+    }
+
+    /// Lay out multiple columns of the given height side by side.
+    fn columns(
+        &mut self,
+        locator: Locator,
+        regions: Regions,
+        column_height: Abs,
+    ) -> FlowResult<Frame> {
         // Create a backlog for multi-column layout.
-        let column_height = regions.size.y;
         let backlog: Vec<_> = std::iter::once(&column_height)
             .chain(regions.backlog)
             .flat_map(|&h| std::iter::repeat_n(h, self.config.columns.count))
@@ -229,6 +362,12 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
             self.footnote(note, &mut regions.clone(), Abs::zero(), false)?;
         }
 
+        // If the next pending float requests a float page, this column is
+        // reserved for floats.
+        if self.work.floats.first().is_some_and(|placed| placed.float_page) {
+            return self.float_page(regions);
+        }
+
         // Process pending floats.
         for placed in std::mem::take(&mut self.work.floats) {
             self.float(placed, &regions, false, false)?;
@@ -237,6 +376,55 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
         distribute(self, regions)
     }
 
+    /// Fills the current column with queued floats that request a float page
+    /// instead of flow content.
+    ///
+    /// The first float is always placed. Subsequent ones are placed as long as
+    /// they request a float page, too, and fit. All others remain queued.
+    fn float_page(&mut self, regions: Regions) -> FlowResult<Frame> {
+        let floats = std::mem::take(&mut self.work.floats);
+        let start = self.column_insertions.height();
+
+        for (i, &placed) in floats.iter().enumerate() {
+            // A float may already be placed if we're in a relayout.
+            let loc = placed.location();
+            if self.skipped(loc) {
+                continue;
+            }
+
+            let frame = placed.layout(self.engine, regions.base())?;
+            let need = frame.height() + placed.clearance;
+            let remaining = regions.size.y - (self.column_insertions.height() - start);
+            let (count, _) = self.column_insertions.floats();
+            if count > 0 && (!placed.float_page || !remaining.fits(need)) {
+                self.work.floats.extend_from_slice(&floats[i..]);
+                break;
+            }
+
+            self.footnotes(&regions, &frame, need, false, false)?;
+            self.column_insertions
+                .push_float(placed, frame, FixedAlignment::Start);
+            self.column_insertions.skips.push(loc);
+        }
+
+        // The flow content area stays empty.
+        let used = self.column_insertions.height() - start;
+        let height = if regions.expand.y {
+            (regions.size.y - used).max(Abs::zero())
+        } else {
+            Abs::zero()
+        };
+
+        Ok(Frame::soft(Size::new(regions.size.x, height)))
+    }
+
+    /// The distance from the top of the page/container region to the start of
+    /// the current column's distribution area, which is shifted by the
+    /// insertions at the top.
+    pub fn top_offset(&self) -> Abs {
+        self.page_insertions.top_size + self.column_insertions.top_size
+    }
+
     /// Lays out an item with floating placement.
     ///
     /// This is called from within [`distribute`]. When the float fits, this
@@ -274,6 +462,13 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
             return Ok(());
         }
 
+        // A float that requests a float page is queued for the next region,
+        // where it's handled by `Self::float_page`.
+        if placed.float_page && regions.may_progress() {
+            self.work.floats.push(placed);
+            return Ok(());
+        }
+
         // Determine the base size of the chosen scope.
         let base = match placed.scope {
             PlacementScope::Column => regions.base(),
@@ -297,6 +492,11 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
             }
         };
 
+        // Whether there is content before the float's position in the scope.
+        // A deferred float may not be put above it.
+        let preceded =
+            clearance || (placed.scope == PlacementScope::Parent && self.column > 0);
+
         // We only require clearance if there is other content.
         let clearance = if clearance { placed.clearance } else { Abs::zero() };
         let need = frame.height() + clearance;
@@ -307,24 +507,54 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
             return Ok(());
         }
 
-        // Handle footnotes in the float.
-        self.footnotes(regions, &frame, need, false, migratable)?;
+        // Select the insertion area where we'll put this float.
+        let area = match placed.scope {
+            PlacementScope::Column => &self.column_insertions,
+            PlacementScope::Parent => &self.page_insertions,
+        };
+
+        // If the area's float limits would be exceeded, queue the float for
+        // the next region. The first float in an area is always accepted.
+        let (count, height) = area.floats();
+        if count > 0
+            && regions.may_progress()
+            && (placed.max_count.is_some_and(|max| count >= max.get())
+                || placed.max_fraction.is_some_and(|max| height + need > max.of(base.y)))
+        {
+            self.work.floats.push(placed);
+            return Ok(());
+        }
 
         // Determine the float's vertical alignment. We can unwrap the inner
         // `Option` because `Custom(None)` is checked for during collection.
-        let align_y = placed.align_y.map(Option::unwrap).unwrap_or_else(|| {
-            // When the float's vertical midpoint would be above the middle of
-            // the page if it were layouted in-flow, we use top alignment.
-            // Otherwise, we use bottom alignment.
-            let used = base.y - remaining;
-            let half = need / 2.0;
-            let ratio = (used + half) / base.y;
-            if ratio <= 0.5 {
-                FixedAlignment::Start
-            } else {
-                FixedAlignment::End
+        let align_y = match placed.align_y.map(Option::unwrap) {
+            // A deferred top float that is preceded by content moves to the
+            // next region.
+            Smart::Custom(FixedAlignment::Start)
+                if placed.defer && preceded && regions.may_progress() =>
+            {
+                self.work.floats.push(placed);
+                return Ok(());
             }
-        });
+            Smart::Custom(align) => align,
+            Smart::Auto if placed.defer && preceded => FixedAlignment::End,
+            Smart::Auto => {
+                // When the float's vertical midpoint would be above the middle
+                // of the page if it were layouted in-flow, we use top
+                // alignment. Otherwise, we use bottom alignment.
+                let used = base.y - remaining;
+                let half = need / 2.0;
+                let ratio = (used + half) / base.y;
+                if ratio <= 0.5 {
+                    FixedAlignment::Start
+                } else {
+                    FixedAlignment::End
+                }
+            }
+        };
+
+        // Handle footnotes in the float.
+        self.footnotes(regions, &frame, need, false, migratable)?;
 
         // Select the insertion area where we'll put this float.
         let area = match placed.scope {
@@ -619,7 +849,7 @@ fn layout_footnote(
 }
 
 /// An additive list of insertions.
-#[derive(Default)]
+#[derive(Default, Clone)]
 struct Insertions<'a, 'b> {
     top_floats: Vec<(&'b PlacedChild<'a>, Frame)>,
     bottom_floats: Vec<(&'b PlacedChild<'a>, Frame)>,
@@ -667,6 +897,19 @@ impl<'a, 'b> Insertions<'a, 'b> {
         self.footnote_separator = Some(frame);
     }
 
+    /// The number of floats in the top and bottom area and their combined
+    /// height (including clearances).
+    fn floats(&self) -> (usize, Abs) {
+        let count = self.top_floats.len() + self.bottom_floats.len();
+        let height = self
+            .top_floats
+            .iter()
+            .chain(&self.bottom_floats)
+            .map(|(placed, frame)| frame.height() + placed.clearance)
+            .sum();
+        (count, height)
+    }
+
     /// The combined height of the top and bottom area (includings clearances).
     /// Subtracting this from the total region size yields the available space
     /// for distribution.
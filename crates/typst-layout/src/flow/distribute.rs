@@ -1,12 +1,15 @@
+use ecow::EcoVec;
 use typst_library::introspection::Tag;
 use typst_library::layout::{
-    Abs, Axes, FixedAlignment, Fr, Frame, FrameItem, Point, Region, Regions, Rel, Size,
+    Abs, Axes, FixedAlignment, FloatSide, Fr, Frame, FrameItem, Point, Region, Regions,
+    Rel, Size,
 };
+use typst_library::model::MarginNoteElem;
 use typst_utils::Numeric;
 
 use super::{
-    Child, Composer, FlowResult, LineChild, MultiChild, MultiSpill, PlacedChild,
-    SingleChild, Stop, Work,
+    Child, Composer, FlowResult, LineChild, MultiChild, MultiSpill, ParChild,
+    PlacedChild, SingleChild, Stop, Work,
 };
 
 /// Distributes as many children as fit from `composer.work` into the first
@@ -15,7 +18,10 @@ pub fn distribute(composer: &mut Composer, regions: Regions) -> FlowResult<Frame
     let mut distributor = Distributor {
         composer,
         regions,
+        start: regions.size.y,
+        origin: composer.top_offset(),
         items: vec![],
+        exclusions: vec![],
         sticky: None,
         stickable: None,
     };
@@ -37,8 +43,15 @@ struct Distributor<'a, 'b, 'x, 'y, 'z> {
     composer: &'z mut Composer<'a, 'b, 'x, 'y>,
     /// Regions which are continuously shrunk as new items are added.
     regions: Regions<'z>,
+    /// The available height at the start of distribution.
+    start: Abs,
+    /// The vertical position of the region within the page, used for
+    /// alignment to the baseline grid.
+    origin: Abs,
     /// Already laid out items, not yet aligned.
     items: Vec<Item<'a, 'b>>,
+    /// The areas next to side floats, which in-flow content avoids.
+    exclusions: Vec<Exclusion>,
     /// A snapshot which can be restored to migrate a suffix of sticky blocks to
     /// the next region.
     sticky: Option<DistributionSnapshot<'a, 'b>>,
@@ -70,6 +83,27 @@ struct DistributionSnapshot<'a, 'b> {
     items: usize,
 }
 
+/// The area next to a side float.
+struct Exclusion {
+    /// The side of the region the float is at.
+    side: FixedAlignment,
+    /// The vertical position at which the area starts.
+    top: Abs,
+    /// The vertical position at which the area ends, including clearance.
+    bottom: Abs,
+    /// The width of the area, including clearance.
+    width: Abs,
+}
+
+/// The remaining lines of a paragraph that was laid out again to wrap around
+/// side floats.
+#[derive(Debug, Clone)]
+pub struct WrapSpill<'a> {
+    lines: EcoVec<LineChild<'a>>,
+    leading: Abs,
+    index: usize,
+}
+
 /// A laid out item in a distribution.
 enum Item<'a, 'b> {
     /// An introspection tag.
@@ -82,6 +116,9 @@ enum Item<'a, 'b> {
     Frame(Frame, Axes<FixedAlignment>),
     /// A frame for an absolutely (not floatingly) placed child.
     Placed(Frame, &'b PlacedChild<'a>),
+    /// A frame for a side float, its side, and its distance from the current
+    /// position.
+    Side(Frame, &'b PlacedChild<'a>, FixedAlignment, Abs),
 }
 
 impl Item<'_, '_> {
@@ -105,10 +142,14 @@ impl Item<'_, '_> {
 impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
     /// Distributes content into the region.
     fn run(&mut self) -> FlowResult<()> {
-        // First, handle spill of a breakable block.
+        // First, handle spill of a breakable block or of a paragraph wrapping
+        // around side floats.
         if let Some(spill) = self.composer.work.spill.take() {
             self.multi_spill(spill)?;
         }
+        if let Some(spill) = self.composer.work.wrap_spill.take() {
+            self.wrap_spill(spill)?;
+        }
 
         // If spill are taken care of, process children until no space is left
         // or no children are left.
@@ -187,7 +228,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
                     }
                     return false;
                 }
-                Item::Tag(_) | Item::Abs(..) | Item::Placed(..) => {}
+                Item::Tag(_) | Item::Abs(..) | Item::Placed(..) | Item::Side(..) => {}
                 Item::Fr(.., None) => return false,
                 Item::Frame(..) | Item::Fr(.., Some(_)) => return true,
             }
@@ -204,7 +245,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
                     self.items.remove(i);
                     break;
                 }
-                Item::Tag(_) | Item::Abs(..) | Item::Placed(..) => {}
+                Item::Tag(_) | Item::Abs(..) | Item::Placed(..) | Item::Side(..) => {}
                 Item::Frame(..) | Item::Fr(..) => break,
             }
         }
@@ -215,7 +256,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         for item in self.items.iter().rev() {
             match *item {
                 Item::Abs(amount, 1..) => return amount,
-                Item::Tag(_) | Item::Abs(..) | Item::Placed(..) => {}
+                Item::Tag(_) | Item::Abs(..) | Item::Placed(..) | Item::Side(..) => {}
                 Item::Frame(..) | Item::Fr(..) => break,
             }
         }
@@ -223,10 +264,29 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
     }
 
     /// Processes a line of a paragraph.
-    fn line(&mut self, line: &'b LineChild) -> FlowResult<()> {
+    fn line(&mut self, line: &'b LineChild<'a>) -> FlowResult<()> {
+        // If the line starts a paragraph next to a side float, we lay out the
+        // paragraph again so that it wraps around the float.
+        if let Some(par) = line.par {
+            if let Some(lines) = self.wrap(par)? {
+                return self.wrapped(par, lines);
+            }
+        }
+
+        self.line_frame(line)
+    }
+
+    /// Processes an already laid out line, either from the collected children
+    /// or from a paragraph that wraps around side floats.
+    fn line_frame(&mut self, line: &LineChild) -> FlowResult<()> {
+        // Move the line down such that its baseline is on the baseline grid.
+        let shift = self.grid_shift(line.frame.baseline());
+
         // If the line doesn't fit and a followup region may improve things,
         // finish the region.
-        if !self.regions.size.y.fits(line.frame.height()) && self.regions.may_progress() {
+        if !self.regions.size.y.fits(shift + line.frame.height())
+            && self.regions.may_progress()
+        {
             return Err(Stop::Finish(false));
         }
 
@@ -234,7 +294,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         // following lines grouped by widow/orphan prevention, does not fit into
         // the current region, but does fit into the next region, finish the
         // region.
-        if !self.regions.size.y.fits(line.need)
+        if !self.regions.size.y.fits(shift + line.need)
             && self
                 .regions
                 .iter()
@@ -244,17 +304,162 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             return Err(Stop::Finish(false));
         }
 
-        self.frame(line.frame.clone(), line.align, false, false)
+        if !shift.is_zero() {
+            self.regions.size.y -= shift;
+            self.items.push(Item::Abs(shift, 0));
+        }
+
+        self.frame(line.frame.clone(), line.align, line.sticky, false)
+    }
+
+    /// Lays out a paragraph starting at the current position such that its
+    /// lines avoid side floats. Returns `None` if none of its lines are next to
+    /// a side float.
+    fn wrap(&mut self, par: &ParChild<'a>) -> FlowResult<Option<Vec<LineChild<'a>>>> {
+        let y = self.position();
+        if self.exclusions.iter().all(|exclusion| exclusion.bottom <= y) {
+            return Ok(None);
+        }
+
+        // The heights of the lines can change with their widths, which in turn
+        // affects which lines are next to a float. Thus, we repeat until the
+        // insets of the lines agree with their layout, but give up after a few
+        // attempts to guarantee termination.
+        let mut shape = vec![];
+        let mut lines = par.layout(self.composer.engine, &[])?;
+        for _ in 0..3 {
+            let next = self.shape(y, &lines, par.leading);
+            if next == shape {
+                break;
+            }
+
+            shape = next;
+            let exclusions: Vec<_> =
+                shape.iter().map(|&(left, right)| left + right).collect();
+            lines = par.layout(self.composer.engine, &exclusions)?;
+        }
+
+        if shape.is_empty() {
+            return Ok(None);
+        }
+
+        // Pad the shortened lines to the full width so that they don't overlap
+        // with the floats when aligned.
+        let lines = lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, frame)| match shape.get(i) {
+                Some(&(left, right)) => pad(frame, left, right),
+                None => frame,
+            })
+            .collect();
+
+        Ok(Some(par.children(lines)))
+    }
+
+    /// Determines the insets at the left and right of the given lines if the
+    /// first one were at `y`. Trailing lines without insets are omitted.
+    fn shape(&self, y: Abs, lines: &[Frame], leading: Abs) -> Vec<(Abs, Abs)> {
+        let mut top = y;
+        let mut shape: Vec<_> = lines
+            .iter()
+            .map(|frame| {
+                let insets = self.insets(top, top + frame.height());
+                top += frame.height() + leading;
+                insets
+            })
+            .collect();
+
+        while shape
+            .last()
+            .is_some_and(|(left, right)| left.is_zero() && right.is_zero())
+        {
+            shape.pop();
+        }
+
+        shape
+    }
+
+    /// Processes the lines of a paragraph that wraps around side floats in
+    /// place of its collected lines.
+    fn wrapped(
+        &mut self,
+        par: &ParChild<'a>,
+        lines: Vec<LineChild<'a>>,
+    ) -> FlowResult<()> {
+        // Skip the children of the collected lines, except for the last one,
+        // which is skipped by the caller.
+        let children = self.composer.work.children;
+        self.composer.work.children = &children[par.children - 1..];
+
+        let spill = WrapSpill {
+            lines: lines.into(),
+            leading: par.leading,
+            index: 0,
+        };
+        match self.wrap_spill(spill) {
+            Ok(()) => Ok(()),
+            // If not even the first line fits, the whole paragraph moves to the
+            // next region, where it doesn't need to wrap.
+            Err(Stop::Finish(forced))
+                if self
+                    .composer
+                    .work
+                    .wrap_spill
+                    .as_ref()
+                    .is_some_and(|spill| spill.index == 0) =>
+            {
+                self.composer.work.wrap_spill = None;
+                self.composer.work.children = children;
+                Err(Stop::Finish(forced))
+            }
+            Err(stop) => {
+                self.composer.work.advance();
+                Err(stop)
+            }
+        }
+    }
+
+    /// Processes the remaining lines of a paragraph that wraps around side
+    /// floats.
+    fn wrap_spill(&mut self, mut spill: WrapSpill<'a>) -> FlowResult<()> {
+        while let Some(line) = spill.lines.get(spill.index) {
+            if spill.index > 0 {
+                self.rel(spill.leading.into(), 5);
+            }
+
+            // If the line doesn't fit, save the rest into the spill.
+            if let Err(stop) = self.line_frame(line) {
+                self.composer.work.wrap_spill = Some(spill);
+                return Err(stop);
+            }
+
+            spill.index += 1;
+        }
+
+        Ok(())
     }
 
     /// Processes an unbreakable block.
     fn single(&mut self, single: &'b SingleChild<'a>) -> FlowResult<()> {
         // Lay out the block.
-        let frame = single.layout(
+        let mut frame = single.layout(
             self.composer.engine,
             Region::new(self.regions.base(), self.regions.expand),
         )?;
 
+        // If the block is next to a side float, lay it out again with the
+        // remaining width.
+        let y = self.position();
+        let (left, right) = self.insets(y, y + frame.height());
+        if single.fr.is_none() && !(left + right).is_zero() {
+            let mut base = self.regions.base();
+            base.x -= left + right;
+            let narrowed = single
+                .layout(self.composer.engine, Region::new(base, self.regions.expand))?;
+            frame = pad(narrowed, left, right);
+        }
+
         // Handle fractionally sized blocks.
         if let Some(fr) = single.fr {
             self.composer
@@ -270,11 +475,17 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             return Err(Stop::Finish(false));
         }
 
-        self.frame(frame, single.align, single.sticky, false)
+        self.frame(frame, single.align, single.sticky, false)?;
+        self.grid_pad();
+        Ok(())
     }
 
     /// Processes a breakable block.
     fn multi(&mut self, multi: &'b MultiChild<'a>) -> FlowResult<()> {
+        // Breakable blocks can't wrap around side floats, so they move below
+        // them.
+        self.clear();
+
         // Skip directly if the region is already (over)full. `line` and
         // `single` implicitly do this through their `fits` checks.
         if self.regions.is_full() {
@@ -293,6 +504,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             return Err(Stop::Finish(false));
         }
 
+        self.grid_pad();
         Ok(())
     }
 
@@ -316,6 +528,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             return Err(Stop::Finish(false));
         }
 
+        self.grid_pad();
         Ok(())
     }
 
@@ -376,7 +589,9 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
 
     /// Processes an absolutely or floatingly placed child.
     fn placed(&mut self, placed: &'b PlacedChild<'a>) -> FlowResult<()> {
-        if placed.float {
+        if let Some(side) = placed.side {
+            self.side_float(placed, side)?;
+        } else if placed.float {
             // If the element is floatingly placed, let the composer handle it.
             // It might require relayout because the area available for
             // distribution shrinks. We make the spacing occupied by weak
@@ -401,6 +616,55 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         Ok(())
     }
 
+    /// Processes a float that text wraps around.
+    fn side_float(
+        &mut self,
+        placed: &'b PlacedChild<'a>,
+        side: FloatSide,
+    ) -> FlowResult<()> {
+        let side = placed.resolve_side(self.composer.engine, side);
+        let frame = placed.layout(self.composer.engine, self.regions.base())?;
+
+        // Floats at the same side are stacked.
+        let y = self.position();
+        let top = self
+            .exclusions
+            .iter()
+            .filter(|exclusion| exclusion.side == side)
+            .map(|exclusion| exclusion.bottom)
+            .fold(y, Abs::max);
+
+        // If the float doesn't fit and a followup region may improve things,
+        // finish the region.
+        if !self.regions.size.y.fits(top - y + frame.height())
+            && self.regions.may_progress()
+        {
+            return Err(Stop::Finish(false));
+        }
+
+        self.composer
+            .footnotes(&self.regions, &frame, Abs::zero(), true, true)?;
+
+        // Text wraps around the float at its final position, i.e. including
+        // its offset.
+        let delta = placed.delta.zip_map(self.regions.base(), Rel::relative_to);
+        let dx = match side {
+            FixedAlignment::Start => delta.x,
+            _ => -delta.x,
+        };
+
+        self.exclusions.push(Exclusion {
+            side,
+            top: top + delta.y,
+            bottom: top + delta.y + frame.height() + placed.clearance,
+            width: (frame.width() + placed.clearance + dx).max(Abs::zero()),
+        });
+
+        self.flush_tags();
+        self.items.push(Item::Side(frame, placed, side, top - y));
+        Ok(())
+    }
+
     /// Processes a float flush.
     fn flush(&mut self) -> FlowResult<()> {
         // If there are still pending floats, finish the region instead of
@@ -408,9 +672,71 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         if !self.composer.work.floats.is_empty() {
             return Err(Stop::Finish(false));
         }
+
+        // Content after a flush doesn't wrap around earlier side floats.
+        self.clear();
         Ok(())
     }
 
+    /// The current vertical position in the region.
+    fn position(&self) -> Abs {
+        self.start - self.regions.size.y
+    }
+
+    /// Determines how far the current position must move down such that a
+    /// baseline `ascent` below it ends up on the next line of the baseline
+    /// grid.
+    fn grid_shift(&self, ascent: Abs) -> Abs {
+        let Some(grid) = &self.composer.config.grid else { return Abs::zero() };
+        let y = self.origin + self.position() + ascent - grid.offset;
+        let mut next = grid.step * (y / grid.step).ceil();
+        if (next - grid.step).approx_eq(y) {
+            next -= grid.step;
+        }
+        (next - y).max(Abs::zero())
+    }
+
+    /// Pads the current position down to the next line of the baseline grid
+    /// so that content after a block stays in rhythm.
+    fn grid_pad(&mut self) {
+        let shift = self.grid_shift(Abs::zero());
+        if !shift.is_zero() && self.regions.size.y.fits(shift) {
+            self.regions.size.y -= shift;
+            self.items.push(Item::Abs(shift, 0));
+        }
+    }
+
+    /// Determines the amount of space taken by side floats at the left and
+    /// right for content spanning from `top` to `bottom`.
+    fn insets(&self, top: Abs, bottom: Abs) -> (Abs, Abs) {
+        let mut insets = (Abs::zero(), Abs::zero());
+        for exclusion in &self.exclusions {
+            if exclusion.top < bottom && top < exclusion.bottom {
+                let inset = match exclusion.side {
+                    FixedAlignment::Start => &mut insets.0,
+                    _ => &mut insets.1,
+                };
+                inset.set_max(exclusion.width);
+            }
+        }
+        insets
+    }
+
+    /// Moves the current position below all side floats.
+    fn clear(&mut self) {
+        let y = self.position();
+        let bottom = self
+            .exclusions
+            .iter()
+            .map(|exclusion| exclusion.bottom)
+            .fold(y, Abs::max);
+
+        if bottom > y {
+            self.regions.size.y -= bottom - y;
+            self.items.push(Item::Abs(bottom - y, 0));
+        }
+    }
+
     /// Processes a column break.
     fn break_(&mut self, weak: bool) -> FlowResult<()> {
         // If there is a region to break into, break into it.
@@ -452,6 +778,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         let mut frs = Fr::zero();
         let mut used = Size::zero();
         let mut has_fr_child = false;
+        let mut floats = Abs::zero();
 
         // Determine the amount of used space and the sum of fractionals.
         for item in &self.items {
@@ -465,10 +792,17 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
                     used.y += frame.height();
                     used.x.set_max(frame.width());
                 }
+                Item::Side(frame, _, _, shift) => {
+                    floats.set_max(used.y + *shift + frame.height());
+                    used.x.set_max(frame.width());
+                }
                 Item::Tag(_) | Item::Placed(..) => {}
             }
         }
 
+        // Side floats may extend past the in-flow content.
+        used.y.set_max(floats);
+
         // When we have fractional spacing, occupy the remaining space with it.
         let mut fr_space = Abs::zero();
         if frs.get() > 0.0 && region.size.y.is_finite() {
@@ -496,18 +830,42 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
 
         // Determine the region's size.
         let size = region.expand.select(region.size, used.min(region.size));
-        let free = size.y - used.y;
+        let mut free = size.y - used.y;
+
+        // Stretch the spacing to fill the region, unless the region was ended
+        // explicitly or by the end of the flow.
+        if let Some(justify) = &self.composer.config.justify {
+            if !forced && frs.is_zero() && free > Abs::zero() {
+                free -= self.justify(free, justify.limit);
+            }
+        }
 
         let mut output = Frame::soft(size);
         let mut ruler = FixedAlignment::Start;
         let mut offset = Abs::zero();
         let mut fr_frames = fr_frames.into_iter();
 
+        // Tags are positioned at the top of the frame that follows them. Margin
+        // notes are anchored at the baseline of their line though, so for them,
+        // we additionally need the baseline of that frame.
+        let mut baselines = vec![Abs::zero(); self.items.len()];
+        let mut next = Abs::zero();
+        for (i, item) in self.items.iter().enumerate().rev() {
+            match item {
+                Item::Tag(_) => baselines[i] = next,
+                Item::Frame(frame, _) if frame.has_baseline() => next = frame.baseline(),
+                _ => next = Abs::zero(),
+            }
+        }
+
         // Position all items.
-        for item in self.items {
+        for (i, item) in self.items.into_iter().enumerate() {
             match item {
                 Item::Tag(tag) => {
-                    let y = offset + ruler.position(free);
+                    let mut y = offset + ruler.position(free);
+                    if matches!(tag, Tag::Start(elem) if elem.is::<MarginNoteElem>()) {
+                        y += baselines[i];
+                    }
                     let pos = Point::with_y(y);
                     output.push(pos, FrameItem::Tag(tag.clone()));
                 }
@@ -546,12 +904,68 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
 
                     output.push_frame(pos, frame);
                 }
+                Item::Side(frame, placed, side, shift) => {
+                    let x = side.position(size.x - frame.width());
+                    let y = offset + ruler.position(free) + shift;
+                    let pos = Point::new(x, y)
+                        + placed.delta.zip_map(size, Rel::relative_to).to_point();
+
+                    output.push_frame(pos, frame);
+                }
             }
         }
 
         Ok(output)
     }
 
+    /// Distributes up to `free` space across the paragraph and block spacing
+    /// in proportion to the spacing's natural size and returns how much of it
+    /// was used.
+    fn justify(&mut self, free: Abs, limit: Option<Rel<Abs>>) -> Abs {
+        // The position, natural size, weakness, and maximum stretch of each
+        // stretchable gap.
+        let gaps: Vec<_> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| match *item {
+                Item::Abs(amount, weakness @ 3..=4) if amount > Abs::zero() => {
+                    let max = limit.map_or(Abs::inf(), |limit| limit.relative_to(amount));
+                    Some((i, amount, weakness, max))
+                }
+                _ => None,
+            })
+            .collect();
+
+        // Gaps that reach their limit drop out and the rest is distributed
+        // among the others.
+        let mut stretch = vec![Abs::zero(); gaps.len()];
+        let mut remaining = free;
+        for _ in 0..=gaps.len() {
+            let open = || (0..gaps.len()).filter(|&k| stretch[k] < gaps[k].3);
+            let total: Abs = open().map(|k| gaps[k].1).sum();
+            if total <= Abs::zero() || remaining.approx_eq(Abs::zero()) {
+                break;
+            }
+
+            let shares: Vec<_> = open()
+                .map(|k| {
+                    (k, (remaining * (gaps[k].1 / total)).min(gaps[k].3 - stretch[k]))
+                })
+                .collect();
+            for (k, share) in shares {
+                stretch[k] += share;
+                remaining -= share;
+            }
+        }
+
+        for (&(i, amount, weakness, _), extra) in gaps.iter().zip(stretch) {
+            self.items[i] = Item::Abs(amount + extra, weakness);
+        }
+
+        free - remaining
+    }
+
     /// Create a snapshot of the work and items.
     fn snapshot(&self) -> DistributionSnapshot<'a, 'b> {
         DistributionSnapshot {
@@ -566,3 +980,11 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         self.items.truncate(snapshot.items);
     }
 }
+
+/// Pads a frame with empty space at the left and right.
+fn pad(frame: Frame, left: Abs, right: Abs) -> Frame {
+    let mut output = Frame::soft(Size::new(left + frame.width() + right, frame.height()));
+    output.set_baseline(frame.baseline());
+    output.push_frame(Point::with_x(left), frame);
+    output
+}
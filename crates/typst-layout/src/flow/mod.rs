@@ -21,8 +21,8 @@ use typst_library::introspection::{
     Introspector, Location, Locator, LocatorLink, SplitLocator, Tag,
 };
 use typst_library::layout::{
-    Abs, ColumnsElem, Dir, Em, Fragment, Frame, PageElem, PlacementScope, Region,
-    Regions, Rel, Size,
+    Abs, ColumnBalance, ColumnsElem, Dir, Em, Fragment, Frame, PageElem, PlacementScope,
+    Region, Regions, Rel, Size, VerticalJustify,
 };
 use typst_library::model::{FootnoteElem, FootnoteEntry, LineNumberingScope, ParLine};
 use typst_library::routines::{Arenas, FragmentKind, Pair, RealizationKind, Routines};
@@ -32,10 +32,10 @@ use typst_utils::{NonZeroExt, Numeric};
 
 use self::block::{layout_multi_block, layout_single_block};
 use self::collect::{
-    collect, Child, LineChild, MultiChild, MultiSpill, PlacedChild, SingleChild,
+    collect, Child, LineChild, MultiChild, MultiSpill, ParChild, PlacedChild, SingleChild,
 };
 use self::compose::{compose, Composer};
-use self::distribute::distribute;
+use self::distribute::{distribute, WrapSpill};
 
 /// Lays out content into a single region, producing a single frame.
 pub fn layout_frame(
@@ -70,8 +70,7 @@ pub fn layout_fragment(
         locator.track(),
         styles,
         regions,
-        NonZeroUsize::ONE,
-        Rel::zero(),
+        None,
     )
 }
 
@@ -98,12 +97,12 @@ pub fn layout_columns(
         locator.track(),
         styles,
         regions,
-        elem.count(styles),
-        elem.gutter(styles),
+        Some(elem),
     )
 }
 
-/// The cached, internal implementation of [`layout_fragment`].
+/// The cached, internal implementation of [`layout_fragment`] and
+/// [`layout_columns`].
 #[comemo::memoize]
 #[allow(clippy::too_many_arguments)]
 fn layout_fragment_impl(
@@ -117,8 +116,7 @@ fn layout_fragment_impl(
     locator: Tracked<Locator>,
     styles: StyleChain,
     regions: Regions,
-    columns: NonZeroUsize,
-    column_gutter: Rel<Abs>,
+    columns: Option<&Packed<ColumnsElem>>,
 ) -> SourceResult<Fragment> {
     if !regions.size.x.is_finite() && regions.expand.x {
         bail!(content.span(), "cannot expand into infinite width");
@@ -151,14 +149,20 @@ fn layout_fragment_impl(
         styles,
     )?;
 
+    let (count, gutter, balance) = match columns {
+        Some(elem) => (elem.count(styles), elem.gutter(styles), elem.balance(styles)),
+        None => (NonZeroUsize::ONE, Rel::zero(), ColumnBalance::Off),
+    };
+
     layout_flow(
         &mut engine,
         &children,
         &mut locator,
         styles,
         regions,
-        columns,
-        column_gutter,
+        count,
+        gutter,
+        balance,
         kind.into(),
     )
 }
@@ -194,10 +198,12 @@ pub fn layout_flow<'a>(
     mut regions: Regions,
     columns: NonZeroUsize,
     column_gutter: Rel<Abs>,
+    column_balance: ColumnBalance,
     mode: FlowMode,
 ) -> SourceResult<Fragment> {
     // Prepare configuration that is shared across the whole flow.
-    let config = configuration(shared, regions, columns, column_gutter, mode);
+    let config =
+        configuration(shared, regions, columns, column_gutter, column_balance, mode);
 
     // Collect the elements into pre-processed children. These are much easier
     // to handle than the raw elements.
@@ -238,6 +244,7 @@ fn configuration<'x>(
     regions: Regions,
     columns: NonZeroUsize,
     column_gutter: Rel<Abs>,
+    column_balance: ColumnBalance,
     mode: FlowMode,
 ) -> Config<'x> {
     Config {
@@ -252,7 +259,7 @@ fn configuration<'x>(
             let gutter = column_gutter.relative_to(regions.base().x);
             let width = (regions.size.x - gutter * (count - 1) as f64) / count as f64;
             let dir = TextElem::dir_in(shared);
-            ColumnConfig { count, width, gutter, dir }
+            ColumnConfig { count, width, gutter, dir, balance: column_balance }
         },
         footnote: FootnoteConfig {
             separator: FootnoteEntry::separator_in(shared),
@@ -260,6 +267,19 @@ fn configuration<'x>(
             gap: FootnoteEntry::gap_in(shared),
             expand: regions.expand.x,
         },
+        grid: PageElem::baseline_grid_in(shared)
+            .filter(|_| mode == FlowMode::Root)
+            .map(|grid| GridConfig {
+                step: grid.step.resolve(shared),
+                offset: grid.offset.resolve(shared),
+            })
+            .filter(|grid| grid.step > Abs::zero()),
+        justify: match PageElem::vertical_justify_in(shared) {
+            VerticalJustify::On(limit) if mode == FlowMode::Root => {
+                Some(JustifyConfig { limit: limit.map(|limit| limit.resolve(shared)) })
+            }
+            _ => None,
+        },
         line_numbers: (mode == FlowMode::Root).then(|| LineNumberConfig {
             scope: ParLine::numbering_scope_in(shared),
             default_clearance: {
@@ -292,6 +312,8 @@ struct Work<'a, 'b> {
     children: &'b [Child<'a>],
     /// Leftovers from a breakable block.
     spill: Option<MultiSpill<'a, 'b>>,
+    /// Leftover lines from a paragraph wrapping around side floats.
+    wrap_spill: Option<WrapSpill<'a>>,
     /// Queued floats that didn't fit in previous regions.
     floats: EcoVec<&'b PlacedChild<'a>>,
     /// Queued footnotes that didn't fit in previous regions.
@@ -312,6 +334,7 @@ impl<'a, 'b> Work<'a, 'b> {
         Self {
             children,
             spill: None,
+            wrap_spill: None,
             floats: EcoVec::new(),
             footnotes: EcoVec::new(),
             footnote_spill: None,
@@ -334,11 +357,24 @@ impl<'a, 'b> Work<'a, 'b> {
     fn done(&self) -> bool {
         self.children.is_empty()
             && self.spill.is_none()
+            && self.wrap_spill.is_none()
             && self.floats.is_empty()
             && self.footnote_spill.is_none()
             && self.footnotes.is_empty()
     }
 
+    /// Whether there are leftovers of a block, paragraph, or footnote that
+    /// broke across regions.
+    fn spilled(&self) -> bool {
+        self.spill.is_some() || self.wrap_spill.is_some() || self.footnote_spill.is_some()
+    }
+
+    /// The amount of unprocessed children, floats, and footnotes. This does
+    /// not account for spills.
+    fn left(&self) -> (usize, usize, usize) {
+        (self.children.len(), self.floats.len(), self.footnotes.len())
+    }
+
     /// Add skipped floats and footnotes from the insertion areas to the skip
     /// set.
     fn extend_skips(&mut self, skips: &[Location]) {
@@ -360,6 +396,10 @@ struct Config<'x> {
     columns: ColumnConfig,
     /// Settings for footnotes.
     footnote: FootnoteConfig,
+    /// Settings for the baseline grid.
+    grid: Option<GridConfig>,
+    /// Settings for vertical justification.
+    justify: Option<JustifyConfig>,
     /// Settings for line numbers.
     line_numbers: Option<LineNumberConfig>,
 }
@@ -387,6 +427,23 @@ struct ColumnConfig {
     /// The horizontal direction in which columns progress. Defined by
     /// `text.dir`.
     dir: Dir,
+    /// How to balance the heights of the columns.
+    balance: ColumnBalance,
+}
+
+/// Configuration of the baseline grid.
+struct GridConfig {
+    /// The distance between two grid lines.
+    step: Abs,
+    /// The position of the first grid line, measured from the top of the
+    /// region.
+    offset: Abs,
+}
+
+/// Configuration of vertical justification.
+struct JustifyConfig {
+    /// How much each gap may stretch at most, relative to its natural size.
+    limit: Option<Rel<Abs>>,
 }
 
 /// Configuration of line numbers.
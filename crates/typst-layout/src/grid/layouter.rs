@@ -2,11 +2,11 @@ use std::fmt::Debug;
 
 use typst_library::diag::{bail, SourceResult};
 use typst_library::engine::Engine;
-use typst_library::foundations::{Resolve, StyleChain};
+use typst_library::foundations::{Resolve, Smart, StyleChain};
 use typst_library::layout::grid::resolve::{Cell, CellGrid, LinePosition, Repeatable};
 use typst_library::layout::{
-    Abs, Axes, Dir, Fr, Fragment, Frame, FrameItem, Length, Point, Region, Regions, Rel,
-    Size, Sizing,
+    Abs, Axes, ColumnFit, Dir, Fr, Fragment, Frame, FrameItem, Length, Point, Region,
+    Regions, Rel, Size, Sizing,
 };
 use typst_library::text::TextElem;
 use typst_library::visualize::Geometry;
@@ -57,10 +57,23 @@ pub struct GridLayouter<'a> {
     /// The simulated footer height for this region.
     /// The simulation occurs before any rows are laid out for a region.
     pub(super) footer_height: Abs,
+    /// Where in the broken grid the header or footer that is currently being
+    /// laid out is. This is `None` while laying out other rows.
+    pub(super) continuation: Option<Continuation>,
     /// The span of the grid element.
     pub(super) span: Span,
 }
 
+/// Where a repeated header or footer is laid out within a grid that may be
+/// broken across multiple regions.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Continuation {
+    /// Whether the grid was broken before the current region.
+    pub continued: bool,
+    /// Whether the grid continues in the next region, if already known.
+    pub continues: Smart<bool>,
+}
+
 /// Details about a resulting row piece.
 #[derive(Debug)]
 pub struct RowPiece {
@@ -122,6 +135,7 @@ impl<'a> GridLayouter<'a> {
             is_rtl: TextElem::dir_in(styles) == Dir::RTL,
             header_height: Abs::zero(),
             footer_height: Abs::zero(),
+            continuation: None,
             span,
         }
     }
@@ -155,7 +169,7 @@ impl<'a> GridLayouter<'a> {
             if let Some(Repeatable::Repeated(footer)) = &self.grid.footer {
                 if y >= footer.start {
                     if y == footer.start {
-                        self.layout_footer(footer, engine, self.finished.len())?;
+                        self.layout_footer(footer, engine, self.finished.len(), true)?;
                     }
                     continue;
                 }
@@ -712,6 +726,8 @@ impl<'a> GridLayouter<'a> {
             let remaining = available - auto;
             if remaining >= Abs::zero() {
                 self.grow_fractional_columns(remaining, fr);
+            } else if self.grid.column_fit == ColumnFit::Content {
+                self.fit_auto_columns(engine, available)?;
             } else {
                 self.shrink_auto_columns(available, count);
             }
@@ -844,11 +860,13 @@ impl<'a> GridLayouter<'a> {
 
                 let size = Size::new(available, height);
                 let pod = Region::new(size, Axes::splat(false));
-                let frame =
-                    layout_cell(cell, engine, 0, self.styles, pod.into())?.into_frame();
+                let frame = layout_cell(cell, engine, 0, None, self.styles, pod.into())?
+                    .into_frame();
                 resolved.set_max(frame.width() - already_covered_width);
             }
 
+            let (min, max) = self.column_bounds(x);
+            let resolved = resolved.min(max).max(min);
             self.rcols[x] = resolved;
             auto += resolved;
             count += 1;
@@ -895,12 +913,87 @@ impl<'a> GridLayouter<'a> {
             }
         }
 
-        // Redistribute space fairly among overlarge columns.
-        for (&col, rcol) in self.grid.cols.iter().zip(&mut self.rcols) {
-            if col == Sizing::Auto && *rcol > fair {
-                *rcol = fair;
+        // Redistribute space fairly among overlarge columns, but don't go
+        // below a column's minimum width.
+        for x in 0..self.rcols.len() {
+            if self.grid.cols[x] == Sizing::Auto && self.rcols[x] > fair {
+                let (min, _) = self.column_bounds(x);
+                self.rcols[x] = fair.max(min);
+            }
+        }
+    }
+
+    /// Shrink auto columns to the available space based on their contents,
+    /// similar to automatic table layout in CSS.
+    ///
+    /// Each column keeps at least its min-content width, which is measured
+    /// here by laying out its cells with zero available width. The rest of the
+    /// space is distributed in proportion to how much wider than that the
+    /// column was measured before (its max-content width). If even the
+    /// min-content widths don't fit, the columns overflow.
+    fn fit_auto_columns(
+        &mut self,
+        engine: &mut Engine,
+        available: Abs,
+    ) -> SourceResult<()> {
+        // Reset the auto columns so that previous measurements don't count
+        // towards the width covered for colspans.
+        let max = std::mem::take(&mut self.rcols);
+        self.rcols = self
+            .grid
+            .cols
+            .iter()
+            .zip(&max)
+            .map(|(&col, &rcol)| if col == Sizing::Auto { Abs::zero() } else { rcol })
+            .collect();
+
+        let (min_total, _) = self.measure_auto_columns(engine, Abs::zero())?;
+
+        // The amount by which the auto columns would like to grow beyond
+        // their min-content width.
+        let slack: Abs = self
+            .grid
+            .cols
+            .iter()
+            .zip(self.rcols.iter().zip(&max))
+            .filter(|(col, _)| **col == Sizing::Auto)
+            .map(|(_, (&min, &max))| (max - min).max(Abs::zero()))
+            .sum();
+
+        let ratio = if min_total < available && slack > Abs::zero() {
+            ((available - min_total) / slack).min(1.0)
+        } else {
+            0.0
+        };
+
+        for (&col, (rcol, &max)) in
+            self.grid.cols.iter().zip(self.rcols.iter_mut().zip(&max))
+        {
+            if col == Sizing::Auto {
+                *rcol += (max - *rcol).max(Abs::zero()) * ratio;
             }
         }
+
+        Ok(())
+    }
+
+    /// The minimum and maximum width of the auto column `x` as configured by
+    /// `column-min` and `column-max`. The minimum takes precedence.
+    fn column_bounds(&self, x: usize) -> (Abs, Abs) {
+        // Gutter columns have no bounds.
+        if self.grid.has_gutter && x % 2 == 1 {
+            return (Abs::zero(), Abs::inf());
+        }
+
+        let c = if self.grid.has_gutter { x / 2 } else { x };
+        let base = self.regions.base().x;
+        let resolve = |bound: Smart<Rel<Length>>| {
+            bound.map(|bound| bound.resolve(self.styles).relative_to(base))
+        };
+
+        let min = resolve(self.grid.column_min.get(c)).unwrap_or(Abs::zero());
+        let max = resolve(self.grid.column_max.get(c)).unwrap_or(Abs::inf());
+        (min, max.max(min))
     }
 
     /// Layout a row with automatic height. Such a row may break across multiple
@@ -1087,8 +1180,15 @@ impl<'a> GridLayouter<'a> {
                 pod
             };
 
-            let frames =
-                layout_cell(cell, engine, disambiguator, self.styles, pod)?.into_frames();
+            let frames = layout_cell(
+                cell,
+                engine,
+                disambiguator,
+                self.continuation,
+                self.styles,
+                pod,
+            )?
+            .into_frames();
 
             // Skip the first region if one cell in it is empty. Then,
             // remeasure.
@@ -1254,9 +1354,15 @@ impl<'a> GridLayouter<'a> {
                         // rows.
                         pod.full = self.regions.full;
                     }
-                    let frame =
-                        layout_cell(cell, engine, disambiguator, self.styles, pod)?
-                            .into_frame();
+                    let frame = layout_cell(
+                        cell,
+                        engine,
+                        disambiguator,
+                        self.continuation,
+                        self.styles,
+                        pod,
+                    )?
+                    .into_frame();
                     let mut pos = pos;
                     if self.is_rtl {
                         // In the grid, cell colspans expand to the right,
@@ -1311,8 +1417,14 @@ impl<'a> GridLayouter<'a> {
                     pod.size.x = width;
 
                     // Push the layouted frames into the individual output frames.
-                    let fragment =
-                        layout_cell(cell, engine, disambiguator, self.styles, pod)?;
+                    let fragment = layout_cell(
+                        cell,
+                        engine,
+                        disambiguator,
+                        self.continuation,
+                        self.styles,
+                        pod,
+                    )?;
                     for (output, frame) in outputs.iter_mut().zip(fragment) {
                         let mut pos = pos;
                         if self.is_rtl {
@@ -1399,7 +1511,7 @@ impl<'a> GridLayouter<'a> {
                 && self.lrows.iter().all(|row| row.index() < footer.start)
             {
                 laid_out_footer_start = Some(footer.start);
-                self.layout_footer(footer, engine, self.finished.len())?;
+                self.layout_footer(footer, engine, self.finished.len(), last)?;
             }
         }
 
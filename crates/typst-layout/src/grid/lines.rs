@@ -577,6 +577,7 @@ mod test {
             stroke: Sides::splat(Some(Arc::new(Stroke::default()))),
             stroke_overridden: Sides::splat(false),
             breakable: true,
+            rotated: false,
         }
     }
 
@@ -590,6 +591,7 @@ mod test {
             stroke: Sides::splat(Some(Arc::new(Stroke::default()))),
             stroke_overridden: Sides::splat(false),
             breakable: true,
+            rotated: false,
         }
     }
 
@@ -10,10 +10,10 @@ use typst_library::engine::Engine;
 use typst_library::foundations::{Packed, StyleChain};
 use typst_library::introspection::Locator;
 use typst_library::layout::grid::resolve::{grid_to_cellgrid, table_to_cellgrid, Cell};
-use typst_library::layout::{Fragment, GridElem, Regions};
+use typst_library::layout::{Fragment, GridElem, Region, Regions, Size};
 use typst_library::model::TableElem;
 
-use self::layouter::RowPiece;
+use self::layouter::{Continuation, RowPiece};
 use self::lines::{
     generate_line_segments, hline_stroke_at_column, vline_stroke_at_row, LineSegment,
 };
@@ -25,10 +25,14 @@ use self::rowspans::{Rowspan, UnbreakableRowGroup};
 /// layouted as. For normal cells, it is always `0`, but for headers and
 /// footers, it indicates the index of the header/footer among all. See the
 /// [`Locator`] docs for more details on the concepts behind this.
+///
+/// The `continuation` is set for cells in repeated headers and footers and is
+/// made available to their contents through the styles.
 pub fn layout_cell(
     cell: &Cell,
     engine: &mut Engine,
     disambiguator: usize,
+    continuation: Option<Continuation>,
     styles: StyleChain,
     regions: Regions,
 ) -> SourceResult<Fragment> {
@@ -36,6 +40,29 @@ pub fn layout_cell(
     if disambiguator > 0 {
         locator = locator.split().next_inner(disambiguator as u128);
     }
+
+    let local;
+    let styles = match continuation {
+        Some(continuation) => {
+            local = [
+                GridElem::set_continued(continuation.continued),
+                GridElem::set_continues(continuation.continues),
+            ]
+            .map(|p| p.wrap());
+            styles.chain(&local)
+        }
+        None => styles,
+    };
+
+    // Rotated content is measured against the full height of the region
+    // instead of what is left of it. Otherwise, it would wrap differently
+    // depending on where its row ends up, e.g. in a repeated header.
+    if cell.rotated && !regions.expand.y {
+        let size = Size::new(regions.size.x, regions.base().y);
+        let pod = Region::new(size, regions.expand);
+        return crate::layout_fragment(engine, &cell.body, locator, styles, pod.into());
+    }
+
     crate::layout_fragment(engine, &cell.body, locator, styles, regions)
 }
 
@@ -1,9 +1,10 @@
 use typst_library::diag::SourceResult;
 use typst_library::engine::Engine;
+use typst_library::foundations::Smart;
 use typst_library::layout::grid::resolve::{Footer, Header, Repeatable};
 use typst_library::layout::{Abs, Axes, Frame, Regions};
 
-use super::layouter::GridLayouter;
+use super::layouter::{Continuation, GridLayouter};
 use super::rowspans::UnbreakableRowGroup;
 
 impl GridLayouter<'_> {
@@ -15,6 +16,12 @@ impl GridLayouter<'_> {
         engine: &mut Engine,
         disambiguator: usize,
     ) -> SourceResult<()> {
+        // It is not yet known whether the grid continues after this region.
+        self.continuation = Some(Continuation {
+            continued: self.is_continued(),
+            continues: Smart::Auto,
+        });
+
         let header_rows =
             self.simulate_header(header, &self.regions, engine, disambiguator)?;
         let mut skipped_region = false;
@@ -49,6 +56,8 @@ impl GridLayouter<'_> {
         for y in 0..header.end {
             self.layout_row(y, engine, disambiguator)?;
         }
+
+        self.continuation = None;
         Ok(())
     }
 
@@ -81,6 +90,12 @@ impl GridLayouter<'_> {
         engine: &mut Engine,
         disambiguator: usize,
     ) -> SourceResult<()> {
+        // Simulate the footer as it appears in all but the last region.
+        self.continuation = Some(Continuation {
+            continued: self.is_continued(),
+            continues: Smart::Custom(true),
+        });
+
         let footer_height = self
             .simulate_footer(footer, &self.regions, engine, disambiguator)?
             .height;
@@ -104,17 +119,26 @@ impl GridLayouter<'_> {
             footer_height
         };
 
+        self.continuation = None;
         Ok(())
     }
 
     /// Lays out all rows in the footer.
     /// They are unbreakable.
+    ///
+    /// The value of `last` indicates whether this is the grid's last region.
     pub fn layout_footer(
         &mut self,
         footer: &Footer,
         engine: &mut Engine,
         disambiguator: usize,
+        last: bool,
     ) -> SourceResult<()> {
+        self.continuation = Some(Continuation {
+            continued: self.is_continued(),
+            continues: Smart::Custom(!last),
+        });
+
         // Ensure footer rows have their own height available.
         // Won't change much as we're creating an unbreakable row group
         // anyway, so this is mostly for correctness.
@@ -126,9 +150,15 @@ impl GridLayouter<'_> {
             self.layout_row(y, engine, disambiguator)?;
         }
 
+        self.continuation = None;
         Ok(())
     }
 
+    /// Whether rows of the grid were laid out in a previous region.
+    pub fn is_continued(&self) -> bool {
+        self.rrows.iter().any(|rows| !rows.is_empty())
+    }
+
     // Simulate the footer's group of rows.
     pub fn simulate_footer(
         &self,
@@ -5,7 +5,7 @@ use typst_library::layout::grid::resolve::Repeatable;
 use typst_library::layout::{Abs, Axes, Frame, Point, Region, Regions, Size, Sizing};
 use typst_utils::MaybeReverseIter;
 
-use super::layouter::{in_last_with_offset, points, Row, RowPiece};
+use super::layouter::{in_last_with_offset, points, Continuation, Row, RowPiece};
 use super::{layout_cell, Cell, GridLayouter};
 
 /// All information needed to layout a single rowspan.
@@ -16,6 +16,9 @@ pub struct Rowspan {
     pub y: usize,
     /// The disambiguator for laying out the cells.
     pub disambiguator: usize,
+    /// Where in the broken grid the rowspan is laid out, if it is part of a
+    /// repeated header or footer.
+    pub continuation: Option<Continuation>,
     /// Amount of rows spanned by the cell at (x, y).
     pub rowspan: usize,
     /// Whether all rows of the rowspan are part of an unbreakable row group.
@@ -105,6 +108,7 @@ impl GridLayouter<'_> {
             x,
             y,
             disambiguator,
+            continuation,
             rowspan,
             is_effectively_unbreakable,
             dx,
@@ -141,7 +145,8 @@ impl GridLayouter<'_> {
         }
 
         // Push the layouted frames directly into the finished frames.
-        let fragment = layout_cell(cell, engine, disambiguator, self.styles, pod)?;
+        let fragment =
+            layout_cell(cell, engine, disambiguator, continuation, self.styles, pod)?;
         let (current_region, current_rrows) = current_region_data.unzip();
         for ((i, finished), frame) in self
             .finished
@@ -199,6 +204,7 @@ impl GridLayouter<'_> {
                     x,
                     y,
                     disambiguator,
+                    continuation: self.continuation,
                     rowspan,
                     // The field below will be updated in
                     // 'check_for_unbreakable_rows'.
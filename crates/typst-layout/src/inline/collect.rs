@@ -6,8 +6,8 @@ use typst_library::layout::{
 };
 use typst_library::routines::Pair;
 use typst_library::text::{
-    is_default_ignorable, LinebreakElem, SmartQuoteElem, SmartQuoter, SmartQuotes,
-    SpaceElem, TextElem,
+    is_default_ignorable, LinebreakElem, RubyElem, SmartQuoteElem, SmartQuoter,
+    SmartQuotes, SpaceElem, TextElem,
 };
 use typst_syntax::Span;
 use typst_utils::Numeric;
@@ -137,10 +137,33 @@ pub fn collect<'a>(
         collector.spans.push(1, Span::detached());
     }
 
-    for &(child, styles) in children {
+    let mut vertical_end = 0;
+    for (i, &(child, styles)) in children.iter().enumerate() {
+        if i < vertical_end {
+            continue;
+        }
+
         let prev_len = collector.full.len();
 
-        if child.is::<SpaceElem>() {
+        if TextElem::dir_in(styles) == Dir::TTB {
+            // Vertical content within horizontal lines is laid out on its own
+            // and stands on the baseline.
+            vertical_end = i + children[i..]
+                .iter()
+                .take_while(|&&(_, styles)| TextElem::dir_in(styles) == Dir::TTB)
+                .count();
+            let mut frame = layout_inline(
+                engine,
+                &children[i..vertical_end],
+                locator,
+                styles,
+                Size::splat(Abs::inf()),
+                false,
+            )?
+            .into_frame();
+            apply_baseline_shift(&mut frame, styles);
+            collector.push_item(Item::Frame(frame));
+        } else if child.is::<SpaceElem>() {
             collector.push_text(" ", styles);
         } else if let Some(elem) = child.to_packed::<TextElem>() {
             collector.build_text(styles, |full| {
@@ -212,6 +235,23 @@ pub fn collect<'a>(
                 }
             }
 
+            collector.push_item(Item::Skip(POP_ISOLATE));
+        } else if let Some(elem) = child.to_packed::<RubyElem>() {
+            let before = collector.full.chars().rev().find(|&c| !is_default_ignorable(c));
+            let after = children[i + 1..]
+                .iter()
+                .map(|&(child, _)| child)
+                .find(|child| !child.is::<TagElem>())
+                .and_then(|child| child.to_packed::<TextElem>())
+                .and_then(|elem| elem.text.chars().next());
+
+            collector.push_item(Item::Skip(LTR_ISOLATE));
+
+            for mut frame in layout_ruby(elem, engine, locator, styles, before, after)? {
+                apply_baseline_shift(&mut frame, styles);
+                collector.push_item(Item::Frame(frame));
+            }
+
             collector.push_item(Item::Skip(POP_ISOLATE));
         } else if let Some(elem) = child.to_packed::<BoxElem>() {
             let loc = locator.next(&elem.span());
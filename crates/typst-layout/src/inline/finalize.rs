@@ -11,6 +11,7 @@ pub fn finalize(
     lines: &[Line],
     region: Size,
     expand: bool,
+    exclusions: &[Abs],
     locator: &mut SplitLocator<'_>,
 ) -> SourceResult<Fragment> {
     // Determine the resulting width: Full width of the region if we should
@@ -26,10 +27,15 @@ pub fn finalize(
         region.x
     };
 
-    // Stack the lines into one frame per region.
+    // Stack the lines into one frame per region. Lines with a reduced
+    // available width are committed with that width.
     lines
         .iter()
-        .map(|line| commit(engine, p, line, width, region.y, locator))
+        .enumerate()
+        .map(|(i, line)| {
+            let width = width.min(region.x - exclusion(exclusions, i));
+            commit(engine, p, line, width, region.y, locator)
+        })
         .collect::<SourceResult<_>>()
         .map(Fragment::frames)
 }
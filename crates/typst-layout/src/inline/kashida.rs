@@ -0,0 +1,308 @@
+use std::borrow::Cow;
+
+use typst_library::layout::{Abs, Dir, Em};
+use unicode_script::Script;
+
+use super::shaping::{shape_arabic_word, tatweel};
+use super::{Item, Line, Range, ShapedGlyph, ShapedText};
+
+/// The Arabic tatweel, which elongates the connection between two letters.
+const TATWEEL: char = '\u{640}';
+
+/// The maximum number of kashidas at a single place, so that words aren't
+/// stretched excessively.
+const MAX_KASHIDAS: usize = 3;
+
+/// A place in a shaped text where kashidas can be inserted.
+#[derive(Debug, Copy, Clone)]
+struct Kashida {
+    /// The index of the glyph before which the kashidas are inserted.
+    index: usize,
+    /// The priority of the place. Lower is better.
+    priority: u8,
+    /// The width of a single kashida.
+    width: Abs,
+}
+
+/// How much a justified line can stretch through kashidas.
+pub fn kashida_capacity(line: &Line) -> Abs {
+    line.items
+        .iter()
+        .filter_map(Item::text)
+        .flat_map(kashidas)
+        .map(|kashida| kashida.width * MAX_KASHIDAS as f64)
+        .sum()
+}
+
+/// Distribute the remaining space of a justified line onto kashidas.
+///
+/// Each Arabic word gets at most one place for kashidas. The places are
+/// filled round-robin with whole kashidas, starting with the ones of the
+/// highest priority, until no further kashida fits into the remaining space
+/// or all places are full.
+///
+/// Returns the item index, glyph index, and number of kashidas to insert
+/// before that glyph.
+pub fn distribute_kashidas(
+    line: &Line,
+    mut remaining: Abs,
+) -> Vec<(usize, usize, usize)> {
+    let mut candidates: Vec<(usize, Kashida)> = line
+        .items
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| Some((i, item.text()?)))
+        .flat_map(|(i, text)| kashidas(text).into_iter().map(move |k| (i, k)))
+        .collect();
+    candidates.sort_by_key(|(_, kashida)| kashida.priority);
+
+    let mut counts = vec![0; candidates.len()];
+    loop {
+        let mut inserted = false;
+        for ((_, kashida), count) in candidates.iter().zip(&mut counts) {
+            if *count < MAX_KASHIDAS && kashida.width <= remaining {
+                *count += 1;
+                remaining -= kashida.width;
+                inserted = true;
+            }
+        }
+
+        if !inserted {
+            break;
+        }
+    }
+
+    candidates
+        .into_iter()
+        .zip(counts)
+        .filter(|&(_, count)| count > 0)
+        .map(|((i, kashida), count)| (i, kashida.index, count))
+        .collect()
+}
+
+/// Insert kashidas into shaped text, given as pairs of a glyph index and the
+/// number of kashidas to insert before that glyph.
+///
+/// The word around each place is shaped again with the tatweels in its text,
+/// so that the font can choose the letter forms and mark positions for the
+/// elongated connection. The kashidas become part of the cluster of the letter
+/// they precede, so they don't disturb the mapping from glyphs to text.
+pub fn insert_kashidas<'a>(
+    text: &ShapedText<'a>,
+    insertions: &[(usize, usize)],
+) -> ShapedText<'a> {
+    let mut width = text.width;
+    let mut glyphs = text.glyphs.to_vec();
+
+    // Replace the words starting from the visual end, so that the glyph
+    // indices of the other places stay valid.
+    let mut insertions = insertions.to_vec();
+    insertions.sort_by_key(|&(index, _)| std::cmp::Reverse(index));
+
+    for (index, count) in insertions {
+        let font = glyphs[index].font.clone();
+        let at = glyphs[index].range.end;
+        let word = word_range(text, at);
+
+        // In right-to-left text, the glyphs of a word are contiguous in
+        // visual order, too.
+        let in_word = |glyph: &ShapedGlyph| word.contains(&glyph.range.start);
+        let Some(first) = glyphs.iter().position(in_word) else { continue };
+        let last = glyphs.iter().rposition(in_word).unwrap_or(first);
+
+        let local = &text.text[word.start - text.base..word.end - text.base];
+        let split = at - word.start;
+        let inserted = count * TATWEEL.len_utf8();
+        let mut elongated = String::with_capacity(local.len() + inserted);
+        elongated.push_str(&local[..split]);
+        elongated.extend(std::iter::repeat_n(TATWEEL, count));
+        elongated.push_str(&local[split..]);
+
+        let reshaped = shape_arabic_word(&font, &elongated, text.styles, |offset| {
+            word.start
+                + if offset < split {
+                    offset
+                } else if offset < split + inserted {
+                    split
+                } else {
+                    offset - inserted
+                }
+        });
+
+        let before: Em = glyphs[first..=last].iter().map(|g| g.x_advance).sum();
+        let after: Em = reshaped.iter().map(|g| g.x_advance).sum();
+        width += (after - before).at(text.size);
+        glyphs.splice(first..=last, reshaped);
+    }
+
+    ShapedText { width, glyphs: Cow::Owned(glyphs), ..text.clone() }
+}
+
+/// The range of the word around the given offset in the paragraph's text.
+fn word_range(text: &ShapedText, at: usize) -> Range {
+    let local = at - text.base;
+    let start = text.text[..local]
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| c.is_whitespace())
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    let end = text.text[local..]
+        .find(char::is_whitespace)
+        .map_or(text.text.len(), |i| local + i);
+    text.base + start..text.base + end
+}
+
+/// Find the best place for kashidas in each Arabic word of the text.
+fn kashidas(text: &ShapedText) -> Vec<Kashida> {
+    // Arabic text is always shaped right-to-left. Such runs store their glyphs
+    // in visual order, that is, reversed.
+    if text.dir != Dir::RTL {
+        return vec![];
+    }
+
+    // The letters of the text, skipping over marks that don't affect joining.
+    let letters: Vec<(usize, char)> = text
+        .text
+        .char_indices()
+        .filter(|&(_, c)| !is_transparent(c))
+        .collect();
+
+    let mut kashidas = vec![];
+    let mut best: Option<Kashida> = None;
+    for (k, &(_, a)) in letters.iter().enumerate() {
+        let Some(&(offset, b)) = letters.get(k + 1) else { break };
+        if joining(b).is_none() {
+            kashidas.extend(best.take());
+            continue;
+        }
+
+        if !joins_left(a) || !joins_right(b) {
+            continue;
+        }
+
+        let next = letters.get(k + 2).map(|&(_, c)| c);
+        let is_final = !joins_left(b) || next.is_none_or(|c| !joins_right(c));
+        let Some(priority) = priority(a, b, next, is_final) else { continue };
+
+        // Later places win ties.
+        if best.is_some_and(|best| best.priority < priority) {
+            continue;
+        }
+
+        // The kashida is inserted visually to the left of the glyphs that
+        // end right before `b`. If there are none, `a` and `b` are part of
+        // the same cluster, e.g. a ligature.
+        let end = text.base + offset;
+        let Some(index) = text.glyphs.iter().position(|g| g.range.end == end) else {
+            continue;
+        };
+
+        let glyph = &text.glyphs[index];
+        if glyph.script != Script::Arabic || glyph.glyph_id == 0 {
+            continue;
+        }
+
+        // The word is shaped again with a single font when the kashidas are
+        // inserted.
+        let word = word_range(text, end);
+        if text
+            .glyphs
+            .iter()
+            .any(|g| word.contains(&g.range.start) && g.font != glyph.font)
+        {
+            continue;
+        }
+
+        let Some((_, advance)) = tatweel(&glyph.font, text.styles) else { continue };
+        let width = advance.at(text.size);
+        if width > Abs::zero() {
+            best = Some(Kashida { index, priority, width });
+        }
+    }
+
+    kashidas.extend(best);
+    kashidas
+}
+
+/// The priority of elongating the connection between the joined letters `a`
+/// and `b`, where `next` is the letter following `b`. Lower is better.
+///
+/// Follows the rules commonly used for Arabic justification: Connections
+/// after user-provided kashidas and after seen and sad come first, then those
+/// before the final forms of certain letters.
+fn priority(a: char, b: char, next: Option<char>, is_final: bool) -> Option<u8> {
+    let is_alef = |c| matches!(c, 'ا' | 'أ' | 'إ' | 'آ' | 'ٱ');
+    if b == TATWEEL || (a == 'ل' && is_alef(b)) {
+        return None;
+    }
+
+    let is_beh = |c| matches!(c, 'ب' | 'ت' | 'ث' | 'ن' | 'ي' | 'ئ' | 'پ' | 'ی');
+    Some(match b {
+        _ if a == TATWEEL => 0,
+        _ if matches!(a, 'س' | 'ش' | 'ص' | 'ض') => 1,
+        'ة' | 'ه' | 'د' | 'ذ' if is_final => 2,
+        'ط' | 'ظ' | 'ل' | 'ك' | 'ک' | 'گ' if is_final => 3,
+        _ if is_final && is_alef(b) => 3,
+        _ if is_beh(b) && matches!(next, Some('ر' | 'ز' | 'ى' | 'ي' | 'ی')) => 4,
+        'و' | 'ؤ' | 'ع' | 'ق' | 'ف' if is_final => 5,
+        _ if is_final => 6,
+        _ => 7,
+    })
+}
+
+/// Whether an Arabic letter connects to the letter preceding it.
+fn joins_right(c: char) -> bool {
+    joining(c).is_some_and(|(right, _)| right)
+}
+
+/// Whether an Arabic letter connects to the letter following it.
+fn joins_left(c: char) -> bool {
+    joining(c).is_some_and(|(_, left)| left)
+}
+
+/// Whether an Arabic letter connects to the preceding and to the following
+/// letter, respectively. Returns `None` for characters that aren't Arabic
+/// letters.
+fn joining(c: char) -> Option<(bool, bool)> {
+    match c {
+        // Hamza.
+        '\u{621}' => Some((false, false)),
+        // Right-joining letters like alef, dal, reh, and waw.
+        '\u{622}'..='\u{625}'
+        | '\u{627}'
+        | '\u{629}'
+        | '\u{62F}'..='\u{632}'
+        | '\u{648}'
+        | '\u{671}'..='\u{673}'
+        | '\u{675}'..='\u{677}'
+        | '\u{688}'..='\u{699}'
+        | '\u{6C0}'
+        | '\u{6C3}'..='\u{6CB}'
+        | '\u{6CD}'
+        | '\u{6CF}'
+        | '\u{6D2}'..='\u{6D3}'
+        | '\u{6D5}' => Some((true, false)),
+        // Dual-joining letters and the tatweel.
+        '\u{620}'..='\u{64A}'
+        | '\u{66E}'..='\u{66F}'
+        | '\u{678}'..='\u{6D3}'
+        | '\u{6FA}'..='\u{6FC}'
+        | '\u{6FF}' => Some((true, true)),
+        _ => None,
+    }
+}
+
+/// Whether a character is a mark that is skipped when determining how
+/// letters join.
+fn is_transparent(c: char) -> bool {
+    matches!(
+        c,
+        '\u{610}'..='\u{61A}'
+            | '\u{64B}'..='\u{65F}'
+            | '\u{670}'
+            | '\u{6D6}'..='\u{6DC}'
+            | '\u{6DF}'..='\u{6E4}'
+            | '\u{6E7}'..='\u{6E8}'
+            | '\u{6EA}'..='\u{6ED}'
+    )
+}
@@ -3,7 +3,9 @@ use std::ops::{Deref, DerefMut};
 
 use typst_library::engine::Engine;
 use typst_library::introspection::{SplitLocator, Tag};
-use typst_library::layout::{Abs, Dir, Em, Fr, Frame, FrameItem, Point};
+use typst_library::layout::{
+    Abs, Dir, Em, Fr, Frame, FrameItem, Point, Ratio, Transform,
+};
 use typst_library::model::ParLineMarker;
 use typst_library::text::{Lang, TextElem};
 use typst_utils::Numeric;
@@ -89,6 +91,57 @@ impl Line<'_> {
             .sum()
     }
 
+    /// How much the line can stretch or shrink through glyph expansion.
+    pub fn expandability(&self) -> Abs {
+        self.items
+            .iter()
+            .filter_map(Item::text)
+            .map(|s| s.expandability())
+            .sum()
+    }
+
+    /// How far the glyphs at the left and right edge of the line protrude
+    /// into the margins.
+    ///
+    /// If `builtin` is false, only custom protrusion factors are considered
+    /// and the built-in hanging punctuation is ignored.
+    pub fn protrusion(&self, builtin: bool) -> (Abs, Abs) {
+        // A line consisting of a single glyph doesn't protrude.
+        let single = |text: &ShapedText| self.items.len() == 1 && text.glyphs.len() == 1;
+
+        let mut left = Abs::zero();
+        if let Some(Item::Text(text)) = self.items.first() {
+            if let Some(glyph) = text.glyphs.first() {
+                if TextElem::overhang_in(text.styles) && !single(text) {
+                    let custom = TextElem::protrusion_in(text.styles).get(glyph.c).0;
+                    let factor = match custom {
+                        Some(ratio) => ratio.get(),
+                        None if builtin && !text.dir.is_positive() => overhang(glyph.c),
+                        None => 0.0,
+                    };
+                    left = factor * glyph.x_advance.at(text.size);
+                }
+            }
+        }
+
+        let mut right = Abs::zero();
+        if let Some(Item::Text(text)) = self.items.last() {
+            if let Some(glyph) = text.glyphs.last() {
+                if TextElem::overhang_in(text.styles) && !single(text) {
+                    let custom = TextElem::protrusion_in(text.styles).get(glyph.c).1;
+                    let factor = match custom {
+                        Some(ratio) => ratio.get(),
+                        None if builtin && text.dir.is_positive() => overhang(glyph.c),
+                        None => 0.0,
+                    };
+                    right = factor * glyph.x_advance.at(text.size);
+                }
+            }
+        }
+
+        (left, right)
+    }
+
     /// Whether the line has items with negative width.
     pub fn has_negative_width_items(&self) -> bool {
         self.items.iter().any(|item| match item {
@@ -437,32 +490,10 @@ pub fn commit(
         offset += p.config.hanging_indent;
     }
 
-    // Handle hanging punctuation to the left.
-    if let Some(Item::Text(text)) = line.items.first() {
-        if let Some(glyph) = text.glyphs.first() {
-            if !text.dir.is_positive()
-                && TextElem::overhang_in(text.styles)
-                && (line.items.len() > 1 || text.glyphs.len() > 1)
-            {
-                let amount = overhang(glyph.c) * glyph.x_advance.at(text.size);
-                offset -= amount;
-                remaining += amount;
-            }
-        }
-    }
-
-    // Handle hanging punctuation to the right.
-    if let Some(Item::Text(text)) = line.items.last() {
-        if let Some(glyph) = text.glyphs.last() {
-            if text.dir.is_positive()
-                && TextElem::overhang_in(text.styles)
-                && (line.items.len() > 1 || text.glyphs.len() > 1)
-            {
-                let amount = overhang(glyph.c) * glyph.x_advance.at(text.size);
-                remaining += amount;
-            }
-        }
-    }
+    // Handle hanging punctuation and protrusion into the margins.
+    let (left, right) = line.protrusion(true);
+    offset -= left;
+    remaining += left + right;
 
     // Determine how much additional space is needed. The justification_ratio is
     // for the first step justification, extra_justification is for the last
@@ -475,6 +506,43 @@ pub fn commit(
 
     let shrinkability = line.shrinkability();
     let stretchability = line.stretchability();
+
+    // Justify Arabic text by elongating the connections between letters with
+    // kashidas before resorting to the other means of adjustment. The words
+    // with kashidas are shaped again, so the space they take up is only known
+    // afterwards.
+    let mut elongated = vec![];
+    let mut expandability = line.expandability();
+    if p.config.kashida && line.justify && fr.is_zero() && remaining > Abs::zero() {
+        let kashidas = distribute_kashidas(line, remaining);
+        for (i, item) in line.items.iter().enumerate() {
+            let insertions: Vec<_> = kashidas
+                .iter()
+                .filter(|&&(item, ..)| item == i)
+                .map(|&(_, index, count)| (index, count))
+                .collect();
+            let Some(shaped) = item.text().filter(|_| !insertions.is_empty()) else {
+                continue;
+            };
+
+            let text = insert_kashidas(shaped, &insertions);
+            remaining -= text.width - shaped.width;
+            expandability += text.expandability() - shaped.expandability();
+            elongated.push((i, text));
+        }
+    }
+
+    // Let glyph expansion take its share of the adjustment, in proportion to
+    // its part of the line's total adjustability.
+    let mut expansion = 0.0;
+    let adjustable = remaining < Abs::zero() || (line.justify && fr.is_zero());
+    if adjustable && expandability > Abs::zero() {
+        let adjustability =
+            if remaining < Abs::zero() { shrinkability } else { stretchability };
+        let share = expandability / (expandability + adjustability);
+        expansion = (remaining * share / expandability).clamp(-1.0, 1.0);
+        remaining -= expansion * expandability;
+    }
     if remaining < Abs::zero() && shrinkability > Abs::zero() {
         // Attempt to reduce the length of the line, using shrinkability.
         justification_ratio = (remaining / shrinkability).max(-1.0);
@@ -499,7 +567,7 @@ pub fn commit(
 
     // Build the frames and determine the height and baseline.
     let mut frames = vec![];
-    for item in line.items.iter() {
+    for (i, item) in line.items.iter().enumerate() {
         let mut push = |offset: &mut Abs, frame: Frame| {
             let width = frame.width();
             top.set_max(frame.baseline());
@@ -526,12 +594,26 @@ pub fn commit(
                 }
             }
             Item::Text(shaped) => {
-                let frame = shaped.build(
+                let shaped = elongated
+                    .iter()
+                    .find(|&&(k, _)| k == i)
+                    .map_or(shaped, |(_, text)| text);
+
+                // The frame is scaled as a whole for glyph expansion. Since
+                // the justification was determined for the unscaled line, it
+                // is reduced accordingly so that it ends up at its intended
+                // size after scaling.
+                let scale = 1.0 + expansion * TextElem::expansion_in(shaped.styles).get();
+                let mut frame = shaped.build(
                     engine,
                     &p.spans,
-                    justification_ratio,
-                    extra_justification,
+                    justification_ratio / scale,
+                    extra_justification / scale,
                 );
+                if scale != 1.0 {
+                    frame.transform(Transform::scale(Ratio::new(scale), Ratio::one()));
+                    frame.size_mut().x *= scale;
+                }
                 push(&mut offset, frame);
             }
             Item::Frame(frame) => {
@@ -11,7 +11,7 @@ use icu_segmenter::LineSegmenter;
 use typst_library::engine::Engine;
 use typst_library::layout::{Abs, Em};
 use typst_library::model::Linebreaks;
-use typst_library::text::{is_default_ignorable, Lang, TextElem};
+use typst_library::text::{hyphenation_exception, is_default_ignorable, Lang, TextElem};
 use typst_syntax::link_prefix;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -34,6 +34,10 @@ const MIN_RATIO: f64 = -1.0;
 const MIN_APPROX_RATIO: f64 = -0.5;
 const BOUND_EPS: f64 = 1e-3;
 
+/// How many characters must remain before and after a hyphen when hyphenating
+/// with custom patterns, matching TeX's defaults.
+const DEFAULT_HYPHENATION_LIMITS: (usize, usize) = (2, 3);
+
 /// The ICU blob data.
 fn blob() -> BlobDataProvider {
     BlobDataProvider::try_new_from_static_blob(typst_assets::icu::ICU).unwrap()
@@ -105,14 +109,18 @@ impl Breakpoint {
 }
 
 /// Breaks the text into lines.
+///
+/// The available width of each line is `width` minus its entry in
+/// `exclusions`.
 pub fn linebreak<'a>(
     engine: &Engine,
     p: &'a Preparation<'a>,
     width: Abs,
+    exclusions: &[Abs],
 ) -> Vec<Line<'a>> {
     match p.config.linebreaks {
-        Linebreaks::Simple => linebreak_simple(engine, p, width),
-        Linebreaks::Optimized => linebreak_optimized(engine, p, width),
+        Linebreaks::Simple => linebreak_simple(engine, p, width, exclusions),
+        Linebreaks::Optimized => linebreak_optimized(engine, p, width, exclusions),
     }
 }
 
@@ -124,11 +132,15 @@ fn linebreak_simple<'a>(
     engine: &Engine,
     p: &'a Preparation<'a>,
     width: Abs,
+    exclusions: &[Abs],
 ) -> Vec<Line<'a>> {
     let mut lines = Vec::with_capacity(16);
     let mut start = 0;
     let mut last = None;
 
+    // The width available to the line with the given index.
+    let available = |line: usize| width - exclusion(exclusions, line);
+
     breakpoints(p, |end, breakpoint| {
         // Compute the line and its size.
         let mut attempt = line(engine, p, start..end, breakpoint, lines.last());
@@ -136,7 +148,7 @@ fn linebreak_simple<'a>(
         // If the line doesn't fit anymore, we push the last fitting attempt
         // into the stack and rebuild the line from the attempt's end. The
         // resulting line cannot be broken up further.
-        if !width.fits(attempt.width) {
+        if !available(lines.len()).fits(attempt.width) {
             if let Some((last_attempt, last_end)) = last.take() {
                 lines.push(last_attempt);
                 start = last_end;
@@ -147,7 +159,9 @@ fn linebreak_simple<'a>(
         // Finish the current line if there is a mandatory line break (i.e. due
         // to "\n") or if the line doesn't fit horizontally already since then
         // no shorter line will be possible.
-        if breakpoint == Breakpoint::Mandatory || !width.fits(attempt.width) {
+        if breakpoint == Breakpoint::Mandatory
+            || !available(lines.len()).fits(attempt.width)
+        {
             lines.push(attempt);
             start = end;
             last = None;
@@ -184,16 +198,18 @@ fn linebreak_optimized<'a>(
     engine: &Engine,
     p: &'a Preparation<'a>,
     width: Abs,
+    exclusions: &[Abs],
 ) -> Vec<Line<'a>> {
     let metrics = CostMetrics::compute(p);
 
     // Determines the exact costs of a likely good layout through Knuth-Plass
     // with approximate metrics. We can use this cost as an upper bound to prune
     // the search space in our proper optimization pass below.
-    let upper_bound = linebreak_optimized_approximate(engine, p, width, &metrics);
+    let upper_bound =
+        linebreak_optimized_approximate(engine, p, width, exclusions, &metrics);
 
     // Using the upper bound, perform exact optimized linebreaking.
-    linebreak_optimized_bounded(engine, p, width, &metrics, upper_bound)
+    linebreak_optimized_bounded(engine, p, width, exclusions, &metrics, upper_bound)
 }
 
 /// Performs line breaking in optimized Knuth-Plass style, but with an upper
@@ -203,6 +219,7 @@ fn linebreak_optimized_bounded<'a>(
     engine: &Engine,
     p: &'a Preparation<'a>,
     width: Abs,
+    exclusions: &[Abs],
     metrics: &CostMetrics,
     upper_bound: Cost,
 ) -> Vec<Line<'a>> {
@@ -212,30 +229,53 @@ fn linebreak_optimized_bounded<'a>(
         total: Cost,
         line: Line<'a>,
         end: usize,
+        /// The number of lines up to and including this one.
+        count: usize,
     }
 
+    // With exclusions, the width of a line depends on the number of lines
+    // before it. Then, the optimal layout up to a breakpoint is not
+    // necessarily part of the optimal layout overall, so we keep the optimal
+    // layout for each number of lines up to a breakpoint. Lines past the
+    // exclusions all have the same width, so layouts reaching past them are
+    // interchangeable.
+    let reduced = exclusions
+        .iter()
+        .rposition(|amount| !amount.is_zero())
+        .map_or(0, |i| i + 1);
+    let class = |count: usize| count.min(reduced);
+
     // Dynamic programming table.
-    let mut table = vec![Entry { pred: 0, total: 0.0, line: Line::empty(), end: 0 }];
+    let mut table = vec![Entry {
+        pred: 0,
+        total: 0.0,
+        line: Line::empty(),
+        end: 0,
+        count: 0,
+    }];
 
     let mut active = 0;
     let mut prev_end = 0;
+    let mut last = 0;
 
     breakpoints(p, |end, breakpoint| {
-        // Find the optimal predecessor.
-        let mut best: Option<Entry> = None;
+        // Find the optimal predecessor for each class of line counts.
+        let mut best: Vec<Entry> = vec![];
 
-        // A lower bound for the cost of all following line attempts.
-        let mut line_lower_bound = None;
+        // A lower bound for the cost of all following line attempts that have
+        // at least the given width available.
+        let mut line_lower_bound: Option<(Abs, Cost)> = None;
 
         for (pred_index, pred) in table.iter().enumerate().skip(active) {
             let start = pred.end;
             let unbreakable = prev_end == start;
+            let available = width - exclusion(exclusions, pred.count);
 
             // If the minimum cost we've established for the line is already
             // too much, skip this attempt.
-            if line_lower_bound
-                .is_some_and(|lower| pred.total + lower > upper_bound + BOUND_EPS)
-            {
+            if line_lower_bound.is_some_and(|(min_width, lower)| {
+                available >= min_width && pred.total + lower > upper_bound + BOUND_EPS
+            }) {
                 continue;
             }
 
@@ -246,7 +286,7 @@ fn linebreak_optimized_bounded<'a>(
             let (line_ratio, line_cost) = ratio_and_cost(
                 p,
                 metrics,
-                width,
+                available,
                 &pred.line,
                 &attempt,
                 breakpoint,
@@ -271,16 +311,16 @@ fn linebreak_optimized_bounded<'a>(
             let total = pred.total + line_cost;
 
             // If the line is already underfull (`line_ratio > 0`), any shorter
-            // slice of the line will be even more underfull. So it'll only get
-            // worse from here and further attempts would also have a cost
-            // exceeding `bound`. There is one exception: When the line has
-            // negative spacing, we can't know for sure, so we don't assign the
-            // lower bound in that case.
+            // slice of the line will be even more underfull if it has at least
+            // as much width available. So it'll only get worse from here and
+            // further attempts would also have a cost exceeding `bound`. There
+            // is one exception: When the line has negative spacing, we can't
+            // know for sure, so we don't assign the lower bound in that case.
             if line_ratio > 0.0
                 && line_lower_bound.is_none()
                 && !attempt.has_negative_width_items()
             {
-                line_lower_bound = Some(line_cost);
+                line_lower_bound = Some((available, line_cost));
             }
 
             // If the cost already exceeds the upper bound, we don't need to
@@ -289,9 +329,19 @@ fn linebreak_optimized_bounded<'a>(
                 continue;
             }
 
-            // If this attempt is better than what we had before, take it!
-            if best.as_ref().is_none_or(|best| best.total >= total) {
-                best = Some(Entry { pred: pred_index, total, line: attempt, end });
+            // If this attempt is better than what we had before for the same
+            // class of line counts, take it!
+            let entry = Entry {
+                pred: pred_index,
+                total,
+                line: attempt,
+                end,
+                count: pred.count + 1,
+            };
+            match best.iter_mut().find(|best| class(best.count) == class(entry.count)) {
+                Some(best) if best.total >= total => *best = entry,
+                Some(_) => {}
+                None => best.push(entry),
             }
         }
 
@@ -301,22 +351,35 @@ fn linebreak_optimized_bounded<'a>(
             active = table.len();
         }
 
+        last = table.len();
         table.extend(best);
         prev_end = end;
     });
 
-    // Retrace the best path.
+    // Retrace the best path, starting from the cheapest layout at the last
+    // breakpoint.
     let mut lines = Vec::with_capacity(16);
-    let mut idx = table.len() - 1;
+    let best = table[last..]
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total.total_cmp(&b.total))
+        .map(|(i, _)| last + i);
 
     // This should only happen if our bound was faulty. Which shouldn't happen!
-    if table[idx].end != p.text.len() {
+    let Some(mut idx) = best.filter(|&idx| table[idx].end == p.text.len()) else {
         #[cfg(debug_assertions)]
         panic!("bounded inline layout is incomplete");
 
         #[cfg(not(debug_assertions))]
-        return linebreak_optimized_bounded(engine, p, width, metrics, Cost::INFINITY);
-    }
+        return linebreak_optimized_bounded(
+            engine,
+            p,
+            width,
+            exclusions,
+            metrics,
+            Cost::INFINITY,
+        );
+    };
 
     while idx != 0 {
         table.truncate(idx + 1);
@@ -341,6 +404,7 @@ fn linebreak_optimized_approximate(
     engine: &Engine,
     p: &Preparation,
     width: Abs,
+    exclusions: &[Abs],
     metrics: &CostMetrics,
 ) -> Cost {
     // Determine the cumulative estimation metrics.
@@ -353,6 +417,8 @@ fn linebreak_optimized_approximate(
         end: usize,
         unbreakable: bool,
         breakpoint: Breakpoint,
+        /// The number of lines up to and including this one.
+        count: usize,
     }
 
     // Dynamic programming table.
@@ -362,6 +428,7 @@ fn linebreak_optimized_approximate(
         end: 0,
         unbreakable: false,
         breakpoint: Breakpoint::Mandatory,
+        count: 0,
     }];
 
     let mut active = 0;
@@ -390,7 +457,7 @@ fn linebreak_optimized_approximate(
             let trimmed_end = start + p.text[start..end].trim_end().len();
             let line_ratio = raw_ratio(
                 p,
-                width,
+                width - exclusion(exclusions, pred.count),
                 estimates.widths.estimate(start..trimmed_end)
                     + if breakpoint.is_hyphen() {
                         metrics.approx_hyphen_width
@@ -430,6 +497,7 @@ fn linebreak_optimized_approximate(
                     end,
                     unbreakable,
                     breakpoint,
+                    count: pred.count + 1,
                 });
             }
         }
@@ -460,12 +528,19 @@ fn linebreak_optimized_approximate(
     // got here is only likely to be good, not guaranteed to be the best. We now
     // computes its exact cost as that gives us a sound upper bound for the
     // proper optimization pass.
-    for idx in indices.into_iter().rev() {
+    for (i, idx) in indices.into_iter().rev().enumerate() {
         let Entry { end, breakpoint, unbreakable, .. } = table[idx];
 
         let attempt = line(engine, p, start..end, breakpoint, Some(&pred));
-        let (ratio, line_cost) =
-            ratio_and_cost(p, metrics, width, &pred, &attempt, breakpoint, unbreakable);
+        let (ratio, line_cost) = ratio_and_cost(
+            p,
+            metrics,
+            width - exclusion(exclusions, i),
+            &pred,
+            &attempt,
+            breakpoint,
+            unbreakable,
+        );
 
         // If approximation produces a valid layout without too much shrinking,
         // exact layout is guaranteed to find the same layout. If, however, the
@@ -496,12 +571,24 @@ fn ratio_and_cost(
     breakpoint: Breakpoint,
     unbreakable: bool,
 ) -> (f64, Cost) {
+    // The built-in hanging punctuation is not taken into account to keep line
+    // breaks stable, but custom protrusion is.
+    let (left, right) = attempt.protrusion(false);
+    let expandability = attempt.expandability();
+
+    // Justified Arabic text can additionally stretch through kashidas.
+    let kashidas = if p.config.kashida && attempt.justify {
+        kashida_capacity(attempt)
+    } else {
+        Abs::zero()
+    };
+
     let ratio = raw_ratio(
         p,
         available_width,
-        attempt.width,
-        attempt.stretchability(),
-        attempt.shrinkability(),
+        attempt.width - left - right,
+        attempt.stretchability() + expandability + kashidas,
+        attempt.shrinkability() + expandability,
         attempt.justifiables(),
     );
 
@@ -721,31 +808,22 @@ fn breakpoints(p: &Preparation, mut f: impl FnMut(usize, Breakpoint)) {
 fn hyphenations(
     p: &Preparation,
     lb: &CodePointMapDataBorrowed<LineBreak>,
-    mut offset: usize,
+    offset: usize,
     word: &str,
     mut f: impl FnMut(usize, Breakpoint),
 ) {
-    let Some(lang) = lang_at(p, offset) else { return };
+    let Some(points) = hyphenation_points(p, offset, word) else { return };
     let count = word.chars().count();
-    let end = offset + word.len();
-
-    let mut chars = 0;
-    for syllable in hypher::hyphenate(word, lang) {
-        offset += syllable.len();
-        chars += syllable.chars().count();
-
-        // Don't hyphenate after the final syllable.
-        if offset == end {
-            continue;
-        }
 
+    for point in points {
         // Filter out hyphenation opportunities where hyphenation was actually
         // disabled.
-        if !hyphenate_at(p, offset) {
+        if !hyphenate_at(p, offset + point) {
             continue;
         }
 
         // Filter out forbidden hyphenation opportunities.
+        let syllable = &word[..point];
         if matches!(
             syllable.chars().next_back().map(|c| lb.get(c)),
             Some(LineBreak::Glue | LineBreak::WordJoiner | LineBreak::ZWJ)
@@ -754,12 +832,51 @@ fn hyphenations(
         }
 
         // Determine the number of codepoints before and after the hyphenation.
+        let chars = syllable.chars().count();
         let l = chars.saturating_as::<u8>();
         let r = (count - chars).saturating_as::<u8>();
 
         // Call `f` for the word-internal hyphenation opportunity.
-        f(offset, Breakpoint::Hyphen(l, r));
+        f(offset + point, Breakpoint::Hyphen(l, r));
+    }
+}
+
+/// The hyphenation opportunities within a word as byte offsets into it.
+///
+/// Exceptions take precedence over custom patterns for the text language,
+/// which in turn take precedence over the built-in patterns.
+fn hyphenation_points(p: &Preparation, offset: usize, word: &str) -> Option<Vec<usize>> {
+    let (_, item) = p.get(offset);
+    let styles = item.text()?.styles;
+
+    if let Some(points) = TextElem::hyphenation_exceptions_in(styles)
+        .iter()
+        .find_map(|entry| hyphenation_exception(entry, word))
+    {
+        return Some(points);
+    }
+
+    let lang = text_lang_at(p, offset)?;
+    let limits = TextElem::hyphenation_limits_in(styles);
+    let custom = TextElem::hyphenation_patterns_in(styles);
+    if let Some(patterns) = custom.get(lang) {
+        let (left, right) = limits.resolve(lang, DEFAULT_HYPHENATION_LIMITS);
+        return Some(patterns.hyphenate(word, left, right));
+    }
+
+    let builtin = hypher_lang(lang)?;
+    let (left, right) = limits.resolve(lang, builtin.bounds());
+    let mut points = vec![];
+    let mut end = 0;
+    for syllable in hypher::hyphenate_bounded(word, builtin, left, right) {
+        end += syllable.len();
+        // Don't hyphenate after the final syllable.
+        if end < word.len() {
+            points.push(end);
+        }
     }
+
+    Some(points)
 }
 
 /// Produce linebreak opportunities for a link.
@@ -832,13 +949,16 @@ fn hyphenate_at(p: &Preparation, offset: usize) -> bool {
 }
 
 /// The text language at the given offset.
-fn lang_at(p: &Preparation, offset: usize) -> Option<hypher::Lang> {
-    let lang = p.config.lang.or_else(|| {
+fn text_lang_at(p: &Preparation, offset: usize) -> Option<Lang> {
+    p.config.lang.or_else(|| {
         let (_, item) = p.get(offset);
         let styles = item.text()?.styles;
         Some(TextElem::lang_in(styles))
-    })?;
+    })
+}
 
+/// The language with built-in hyphenation patterns for a text language.
+fn hypher_lang(lang: Lang) -> Option<hypher::Lang> {
     let bytes = lang.as_str().as_bytes().try_into().ok()?;
     hypher::Lang::from_iso(bytes)
 }
@@ -902,13 +1022,17 @@ impl Estimates {
 
         for (range, item) in p.items.iter() {
             if let Item::Text(shaped) = item {
+                let expansion = TextElem::expansion_in(shaped.styles).get();
                 for g in shaped.glyphs.iter() {
                     let byte_len = g.range.len();
+                    let width = g.x_advance.at(shaped.size);
                     let stretch = g.stretchability().0 + g.stretchability().1;
                     let shrink = g.shrinkability().0 + g.shrinkability().1;
-                    widths.push(byte_len, g.x_advance.at(shaped.size));
-                    stretchability.push(byte_len, stretch.at(shaped.size));
-                    shrinkability.push(byte_len, shrink.at(shaped.size));
+                    widths.push(byte_len, width);
+                    stretchability
+                        .push(byte_len, stretch.at(shaped.size) + expansion * width);
+                    shrinkability
+                        .push(byte_len, shrink.at(shaped.size) + expansion * width);
                     justifiables.push(byte_len, g.is_justifiable() as usize);
                 }
             } else {
@@ -3,9 +3,11 @@ mod box_;
 mod collect;
 mod deco;
 mod finalize;
+mod kashida;
 mod line;
 mod linebreak;
 mod prepare;
+mod ruby;
 mod shaping;
 
 pub use self::box_::layout_box;
@@ -15,7 +17,9 @@ use typst_library::diag::SourceResult;
 use typst_library::engine::{Engine, Route, Sink, Traced};
 use typst_library::foundations::{Packed, Resolve, Smart, StyleChain};
 use typst_library::introspection::{Introspector, Locator, LocatorLink, SplitLocator};
-use typst_library::layout::{Abs, AlignElem, Dir, FixedAlignment, Fragment, Size};
+use typst_library::layout::{
+    Abs, AlignElem, Angle, Dir, FixedAlignment, Fragment, Frame, Point, Size, Transform,
+};
 use typst_library::model::{
     EnumElem, FirstLineIndent, Linebreaks, ListElem, ParElem, ParLine, ParLineMarker,
     TermsElem,
@@ -28,9 +32,11 @@ use typst_utils::{Numeric, SliceExt};
 use self::collect::{collect, Item, Segment, SpanMapper};
 use self::deco::decorate;
 use self::finalize::finalize;
+use self::kashida::{distribute_kashidas, insert_kashidas, kashida_capacity};
 use self::line::{apply_baseline_shift, commit, line, Line};
 use self::linebreak::{linebreak, Breakpoint};
 use self::prepare::{prepare, Preparation};
+use self::ruby::layout_ruby;
 use self::shaping::{
     cjk_punct_style, is_of_cj_script, shape_range, ShapedGlyph, ShapedText,
     BEGIN_PUNCT_PAT, END_PUNCT_PAT,
@@ -45,8 +51,7 @@ pub fn layout_par(
     engine: &mut Engine,
     locator: Locator,
     styles: StyleChain,
-    region: Size,
-    expand: bool,
+    space: ParSpace,
     situation: ParSituation,
 ) -> SourceResult<Fragment> {
     layout_par_impl(
@@ -59,12 +64,24 @@ pub fn layout_par(
         engine.route.track(),
         locator.track(),
         styles,
-        region,
-        expand,
+        space,
         situation,
     )
 }
 
+/// The space available to a paragraph's lines.
+#[derive(Debug, Copy, Clone, Hash)]
+pub struct ParSpace<'a> {
+    /// The size of the region the paragraph is laid out in.
+    pub region: Size,
+    /// Whether the paragraph should expand to the full width of the region.
+    pub expand: bool,
+    /// Reduces the width available to the individual lines, for instance next
+    /// to a side float. Lines past the end of the slice have the full width
+    /// available.
+    pub exclusions: &'a [Abs],
+}
+
 /// The internal, memoized implementation of `layout_par`.
 #[comemo::memoize]
 #[allow(clippy::too_many_arguments)]
@@ -78,8 +95,7 @@ fn layout_par_impl(
     route: Tracked<Route>,
     locator: Tracked<Locator>,
     styles: StyleChain,
-    region: Size,
-    expand: bool,
+    space: ParSpace,
     situation: ParSituation,
 ) -> SourceResult<Fragment> {
     let link = LocatorLink::new(locator);
@@ -108,11 +124,13 @@ fn layout_par_impl(
         &children,
         &mut locator,
         styles,
-        region,
-        expand,
+        space.region,
+        space.expand,
         Some(situation),
+        space.exclusions,
         &ConfigBase {
             justify: elem.justify(styles),
+            kashida: elem.kashida(styles),
             linebreaks: elem.linebreaks(styles),
             first_line_indent: elem.first_line_indent(styles),
             hanging_indent: elem.hanging_indent(styles),
@@ -137,8 +155,10 @@ pub fn layout_inline<'a>(
         region,
         expand,
         None,
+        &[],
         &ConfigBase {
             justify: ParElem::justify_in(shared),
+            kashida: ParElem::kashida_in(shared),
             linebreaks: ParElem::linebreaks_in(shared),
             first_line_indent: ParElem::first_line_indent_in(shared),
             hanging_indent: ParElem::hanging_indent_in(shared),
@@ -156,8 +176,13 @@ fn layout_inline_impl<'a>(
     region: Size,
     expand: bool,
     par: Option<ParSituation>,
+    exclusions: &[Abs],
     base: &ConfigBase,
 ) -> SourceResult<Fragment> {
+    if TextElem::dir_in(shared) == Dir::TTB {
+        return layout_vertical(engine, children, locator, shared, region, par, base);
+    }
+
     // Prepare configuration that is shared across the whole inline layout.
     let config = configuration(base, children, shared, par);
 
@@ -169,10 +194,70 @@ fn layout_inline_impl<'a>(
     let p = prepare(engine, &config, &text, segments, spans)?;
 
     // Break the text into lines.
-    let lines = linebreak(engine, &p, region.x - config.hanging_indent);
+    let lines = linebreak(engine, &p, region.x - config.hanging_indent, exclusions);
 
     // Turn the selected lines into frames.
-    finalize(engine, &p, &lines, region, expand, locator)
+    finalize(engine, &p, &lines, region, expand, exclusions, locator)
+}
+
+/// Lays out inline content in vertical writing mode, with lines running from
+/// top to bottom and progressing from right to left.
+///
+/// The lines are laid out with swapped axes and stacked into one frame, which
+/// is then turned. Within the turned frame, the column length is fit to the
+/// longest line.
+fn layout_vertical<'a>(
+    engine: &mut Engine,
+    children: &[Pair<'a>],
+    locator: &mut SplitLocator<'a>,
+    shared: StyleChain<'a>,
+    region: Size,
+    par: Option<ParSituation>,
+    base: &ConfigBase,
+) -> SourceResult<Fragment> {
+    // The marker makes the direction resolve to left-to-right and switches
+    // shaping to vertical glyph orientation.
+    let marker = TextElem::set_vertical(true).wrap();
+    let children: Vec<Pair> = children
+        .iter()
+        .map(|&(child, styles)| (child, styles.chain(&marker)))
+        .collect();
+    let mut locator = locator.next(&()).split();
+    let lines = layout_inline_impl(
+        engine,
+        &children,
+        &mut locator,
+        shared.chain(&marker),
+        Size::new(region.y, region.x),
+        false,
+        par,
+        &[],
+        base,
+    )?;
+
+    let leading = ParElem::leading_in(shared);
+    let width = lines.iter().map(Frame::width).max().unwrap_or_default();
+    let height = lines.iter().map(Frame::height).sum::<Abs>()
+        + leading * lines.len().saturating_sub(1) as f64;
+
+    let mut output = Frame::soft(Size::new(width, height));
+    let mut y = Abs::zero();
+    for line in lines {
+        let height = line.height();
+        output.push_frame(Point::with_y(y), line);
+        y += height + leading;
+    }
+
+    Ok(Fragment::frame(turn(output)))
+}
+
+/// Turn a frame laid out for vertical writing mode clockwise by 90 degrees.
+pub fn turn(mut frame: Frame) -> Frame {
+    let size = Size::new(frame.height(), frame.width());
+    frame.transform(Transform::rotate(Angle::deg(90.0)));
+    let mut turned = Frame::hard(size);
+    turned.push_frame(Point::with_x(size.x), frame);
+    turned
 }
 
 /// Determine the inline layout's configuration.
@@ -188,6 +273,7 @@ fn configuration(
 
     Config {
         justify,
+        kashida: base.kashida,
         linebreaks: base.linebreaks.unwrap_or_else(|| {
             if justify {
                 Linebreaks::Optimized
@@ -258,6 +344,7 @@ pub enum ParSituation {
 /// Raw values from a `ParElem` or style chain. Used to initialize a [`Config`].
 struct ConfigBase {
     justify: bool,
+    kashida: bool,
     linebreaks: Smart<Linebreaks>,
     first_line_indent: FirstLineIndent,
     hanging_indent: Abs,
@@ -267,6 +354,8 @@ struct ConfigBase {
 struct Config {
     /// Whether to justify text.
     justify: bool,
+    /// Whether to justify Arabic text with kashidas.
+    kashida: bool,
     /// How to determine line breaks.
     linebreaks: Linebreaks,
     /// The indent the first line of a paragraph should have.
@@ -295,6 +384,12 @@ struct Config {
     costs: Costs,
 }
 
+/// The amount by which the width available to the line with the given index is
+/// reduced.
+fn exclusion(exclusions: &[Abs], line: usize) -> Abs {
+    exclusions.get(line).copied().unwrap_or_default()
+}
+
 /// Get a style property, but only if it is the same for all of the children.
 fn shared_get<T: PartialEq>(
     children: &[Pair],
@@ -0,0 +1,158 @@
+use typst_library::diag::{At, SourceResult};
+use typst_library::engine::Engine;
+use typst_library::foundations::{Content, Packed, Resolve, StyleChain};
+use typst_library::introspection::SplitLocator;
+use typst_library::layout::{Abs, Frame, Point, Size};
+use typst_library::routines::{Arenas, RealizationKind};
+use typst_library::text::{RubyElem, RubyMode, RubyPosition, TextElem};
+
+use super::layout_inline;
+
+/// Lay out a ruby element into one frame per annotated piece of its base
+/// text.
+///
+/// The characters `before` and `after` the element may be overhung by its
+/// outermost annotations.
+pub fn layout_ruby(
+    elem: &Packed<RubyElem>,
+    engine: &mut Engine,
+    locator: &mut SplitLocator,
+    styles: StyleChain,
+    before: Option<char>,
+    after: Option<char>,
+) -> SourceResult<Vec<Frame>> {
+    let position = elem.position(styles);
+    let gap = elem.gap(styles).resolve(styles);
+    let overhang = elem.overhang(styles);
+    let local = TextElem::set_size(elem.size(styles)).wrap();
+    let annotation_styles = styles.chain(&local);
+    let size = TextElem::size_in(annotation_styles);
+
+    let mut pieces = vec![];
+    for (base, annotation) in elem.units(styles).at(elem.span())? {
+        let base = build(engine, locator, &base, styles)?;
+        let annotation = if annotation.is_empty() {
+            None
+        } else {
+            Some(build(engine, locator, &annotation, annotation_styles)?)
+        };
+        pieces.push((base, annotation));
+    }
+
+    // Jukugo ruby is set as group ruby if any of its annotations is wider
+    // than its base character.
+    if elem.distribution(styles) == RubyMode::Jukugo
+        && pieces.iter().any(|(base, annotation)| {
+            annotation.as_ref().is_some_and(|a| a.width() > base.width())
+        })
+    {
+        let (base, annotation) = elem.group();
+        let base = build(engine, locator, &base, styles)?;
+        let annotation = build(engine, locator, &annotation, annotation_styles)?;
+        pieces = vec![(base, Some(annotation))];
+    }
+
+    // An annotation may overhang adjacent kana by up to one annotation
+    // character.
+    let allowance = |c: Option<char>| match c {
+        Some(c) if overhang && is_kana(c) => size,
+        _ => Abs::zero(),
+    };
+
+    let count = pieces.len();
+    let mut frames = Vec::with_capacity(count);
+    for (i, (base, annotation)) in pieces.into_iter().enumerate() {
+        let Some(annotation) = annotation else {
+            frames.push(base);
+            continue;
+        };
+
+        // Inner pieces of mono ruby neighbour other annotations, which can't
+        // be overhung.
+        let before = allowance(before.filter(|_| i == 0));
+        let after = allowance(after.filter(|_| i + 1 == count));
+        frames.push(combine(base, annotation, position, gap, before, after));
+    }
+
+    Ok(frames)
+}
+
+/// Lay out a piece of base or annotation content with its styles.
+fn build(
+    engine: &mut Engine,
+    locator: &mut SplitLocator,
+    content: &Content,
+    styles: StyleChain,
+) -> SourceResult<Frame> {
+    let arenas = Arenas::default();
+    let mut locator = locator.next(&content.span()).split();
+    let children = (engine.routines.realize)(
+        RealizationKind::LayoutPar,
+        engine,
+        &mut locator,
+        &arenas,
+        content,
+        styles,
+    )?;
+
+    Ok(layout_inline(
+        engine,
+        &children,
+        &mut locator,
+        styles,
+        Size::splat(Abs::inf()),
+        false,
+    )?
+    .into_frame())
+}
+
+/// Combine base text with its annotation. The resulting frame's baseline is
+/// the one of the base text.
+///
+/// An annotation that is wider than its base text first overhangs the
+/// neighbouring text by up to `before` and `after`, preferring to stay
+/// centered. The base text is evenly spaced out by what remains.
+fn combine(
+    base: Frame,
+    annotation: Frame,
+    position: RubyPosition,
+    gap: Abs,
+    before: Abs,
+    after: Abs,
+) -> Frame {
+    let excess = annotation.width() - base.width();
+    let (pad, x) = if excess > Abs::zero() {
+        let half = excess / 2.0;
+        let mut start = before.min(half);
+        let mut end = after.min(half);
+
+        // If only one side may be overhung by half of the excess, the
+        // annotation is shifted towards it.
+        let rest = excess - start - end;
+        let shift = (before - start).min(rest);
+        start += shift;
+        end += (after - end).min(rest - shift);
+
+        ((excess - start - end) / 2.0, -start)
+    } else {
+        (Abs::zero(), -excess / 2.0)
+    };
+
+    let width = base.width() + 2.0 * pad;
+    let height = base.height() + gap + annotation.height();
+    let (base_y, annotation_y) = match position {
+        RubyPosition::Over => (annotation.height() + gap, Abs::zero()),
+        RubyPosition::Under => (Abs::zero(), base.height() + gap),
+    };
+
+    let mut frame = Frame::soft(Size::new(width, height));
+    frame.set_baseline(base_y + base.baseline());
+    frame.push_frame(Point::new(pad, base_y), base);
+    frame.push_frame(Point::new(x, annotation_y), annotation);
+    frame
+}
+
+/// Whether a character is Hiragana or Katakana.
+fn is_kana(c: char) -> bool {
+    matches!(c, '\u{3041}'..='\u{309F}' | '\u{30A0}'..='\u{30FF}')
+}
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::fmt::{self, Debug, Formatter};
+use std::num::NonZeroUsize;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -9,13 +10,16 @@ use rustybuzz::{BufferFlags, ShapePlan, UnicodeBuffer};
 use ttf_parser::Tag;
 use typst_library::engine::Engine;
 use typst_library::foundations::{Smart, StyleChain};
-use typst_library::layout::{Abs, Dir, Em, Frame, FrameItem, Point, Size};
+use typst_library::layout::{
+    Abs, Angle, Dir, Em, Frame, FrameItem, Point, Ratio, Size, Transform,
+};
 use typst_library::text::{
-    families, features, is_default_ignorable, variant, Font, FontFamily, FontVariant,
-    Glyph, Lang, Region, TextEdgeBounds, TextElem, TextItem,
+    families, features, is_default_ignorable, variant, variations, Font, FontFamily,
+    FontVariant, Glyph, Lang, Region, TextEdgeBounds, TextElem, TextItem,
+    TextOrientation,
 };
 use typst_library::World;
-use typst_utils::SliceExt;
+use typst_utils::{Scalar, SliceExt};
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
 use unicode_script::{Script, UnicodeScript};
 
@@ -47,10 +51,29 @@ pub struct ShapedText<'a> {
     pub size: Abs,
     /// The width of the text's bounding box.
     pub width: Abs,
+    /// How the glyphs are oriented in vertical text.
+    pub orientation: Orientation,
     /// The shaped glyphs.
     pub glyphs: Cow<'a, [ShapedGlyph]>,
 }
 
+/// How the glyphs of a text run are oriented in vertical text.
+///
+/// Vertical lines are laid out like horizontal ones and turned clockwise
+/// afterwards. Sideways runs are thus laid out like horizontal text, while
+/// upright glyphs are turned back against the rotation of the line.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Orientation {
+    /// The glyphs turn with the line. This is the case for all horizontal
+    /// text.
+    Sideways,
+    /// Each glyph stands upright and advances by its vertical advance.
+    Upright,
+    /// The run is set horizontally and stands upright within one em
+    /// (tate-chu-yoko).
+    Combined,
+}
+
 /// A single glyph resulting from shaping.
 #[derive(Debug, Clone)]
 pub struct ShapedGlyph {
@@ -217,6 +240,10 @@ impl<'a> ShapedText<'a> {
         justification_ratio: f64,
         extra_justification: Abs,
     ) -> Frame {
+        if self.orientation == Orientation::Combined {
+            return self.build_combined(engine, spans);
+        }
+
         let (top, bottom) = self.measure(engine);
         let size = Size::new(self.width, top + bottom);
 
@@ -314,7 +341,13 @@ impl<'a> ShapedText<'a> {
             };
 
             let width = item.width();
-            if decos.is_empty() {
+            if self.orientation == Orientation::Upright {
+                push_upright(&mut frame, pos, &item);
+                let pos = Point::new(pos.x, top + shift);
+                for deco in &decos {
+                    decorate(&mut frame, deco, &item, width, shift, pos);
+                }
+            } else if decos.is_empty() {
                 frame.push(pos, FrameItem::Text(item));
             } else {
                 // Apply line decorations.
@@ -331,6 +364,37 @@ impl<'a> ShapedText<'a> {
         frame
     }
 
+    /// Build the frame of a run that is combined into one upright em.
+    fn build_combined(&self, engine: &Engine, spans: &SpanMapper) -> Frame {
+        let sideways = ShapedText {
+            orientation: Orientation::Sideways,
+            width: self.glyphs.iter().map(|g| g.x_advance).sum::<Em>().at(self.size),
+            ..self.clone()
+        };
+
+        let mut inner = sideways.build(engine, spans, 0.0, Abs::zero());
+        let top = inner.baseline();
+        let (w, h) = (inner.width(), inner.height());
+        let scale = if w > self.width { self.width / w } else { 1.0 };
+
+        let mut frame = Frame::soft(Size::new(self.width, h));
+        frame.set_baseline(top);
+
+        let center = top
+            - self
+                .glyphs
+                .first()
+                .map_or(Abs::zero(), |g| central_shift(&g.font).at(self.size));
+
+        inner.transform(
+            Transform::rotate(Angle::deg(-90.0))
+                .pre_concat(Transform::scale(Ratio::new(scale), Ratio::one())),
+        );
+        let pos = Point::new((self.width - h) / 2.0, center + w * scale / 2.0);
+        frame.push_frame(pos, inner);
+        frame
+    }
+
     /// Measure the top and bottom extent of this text.
     pub fn measure(&self, engine: &Engine) -> (Abs, Abs) {
         let mut top = Abs::zero();
@@ -356,6 +420,7 @@ impl<'a> ShapedText<'a> {
                     .select(family.as_str(), self.variant)
                     .and_then(|id| world.font(id))
                 {
+                    let font = font.instance(self.variant, &variations(self.styles));
                     expand(&font, TextEdgeBounds::Zero);
                     break;
                 }
@@ -402,6 +467,11 @@ impl<'a> ShapedText<'a> {
             .at(self.size)
     }
 
+    /// How much the text can stretch or shrink through glyph expansion.
+    pub fn expandability(&self) -> Abs {
+        TextElem::expansion_in(self.styles).get() * self.width
+    }
+
     /// Reshape a range of the shaped text, reusing information from this
     /// shaping process if possible.
     ///
@@ -420,7 +490,8 @@ impl<'a> ShapedText<'a> {
                 styles: self.styles,
                 size: self.size,
                 variant: self.variant,
-                width: glyphs.iter().map(|g| g.x_advance).sum::<Em>().at(self.size),
+                width: run_width(glyphs, self.size, self.orientation),
+                orientation: self.orientation,
                 glyphs: Cow::Borrowed(glyphs),
             }
         } else {
@@ -432,6 +503,7 @@ impl<'a> ShapedText<'a> {
                 self.dir,
                 self.lang,
                 self.region,
+                self.orientation,
             )
         }
     }
@@ -470,8 +542,9 @@ impl<'a> ShapedText<'a> {
             .chain(fallback_func.iter().map(|f| f()))
             .flatten();
 
+        let coords = variations(self.styles);
         chain.find_map(|id| {
-            let font = world.font(id)?;
+            let font = world.font(id)?.instance(self.variant, &coords);
             let ttf = font.ttf();
             let glyph_id = ttf.glyph_index('-')?;
             let x_advance = font.to_em(ttf.glyph_hor_advance(glyph_id)?);
@@ -602,46 +675,179 @@ pub fn shape_range<'a>(
     let script = TextElem::script_in(styles);
     let lang = TextElem::lang_in(styles);
     let region = TextElem::region_in(styles);
-    let mut process = |range: Range, level: BidiLevel| {
+    let mut process = |range: Range, level: BidiLevel, orientation: Orientation| {
         let dir = if level.is_ltr() { Dir::LTR } else { Dir::RTL };
+        let text = &text[range.clone()];
         let shaped =
-            shape(engine, range.start, &text[range.clone()], styles, dir, lang, region);
+            shape(engine, range.start, text, styles, dir, lang, region, orientation);
         items.push((range, Item::Text(shaped)));
     };
 
-    let mut prev_level = BidiLevel::ltr();
-    let mut prev_script = Script::Unknown;
-    let mut cursor = range.start;
+    let runs = if TextElem::vertical_in(styles) {
+        orientation_runs(text, range, styles)
+    } else {
+        vec![(range, Orientation::Sideways)]
+    };
 
-    // Group by embedding level and script.  If the text's script is explicitly
-    // set (rather than inferred from the glyphs), we keep the script at an
-    // unchanging `Script::Unknown` so that only level changes cause breaks.
-    for i in range.clone() {
-        if !text.is_char_boundary(i) {
-            continue;
+    for (range, orientation) in runs {
+        let mut prev_level = BidiLevel::ltr();
+        let mut prev_script = Script::Unknown;
+        let mut cursor = range.start;
+
+        // Group by embedding level and script.  If the text's script is
+        // explicitly set (rather than inferred from the glyphs), we keep the
+        // script at an unchanging `Script::Unknown` so that only level changes
+        // cause breaks.
+        for i in range.clone() {
+            if !text.is_char_boundary(i) {
+                continue;
+            }
+
+            let level = bidi.levels[i];
+            let curr_script = match script {
+                Smart::Auto => {
+                    text[i..].chars().next().map_or(Script::Unknown, |c| c.script())
+                }
+                Smart::Custom(_) => Script::Unknown,
+            };
+
+            if level != prev_level || !is_compatible(curr_script, prev_script) {
+                if cursor < i {
+                    process(cursor..i, prev_level, orientation);
+                }
+                cursor = i;
+                prev_level = level;
+                prev_script = curr_script;
+            } else if is_generic_script(prev_script) {
+                prev_script = curr_script;
+            }
         }
 
-        let level = bidi.levels[i];
-        let curr_script = match script {
-            Smart::Auto => {
-                text[i..].chars().next().map_or(Script::Unknown, |c| c.script())
+        process(cursor..range.end, prev_level, orientation);
+    }
+}
+
+/// Split vertical text into runs of equally oriented glyphs.
+fn orientation_runs(
+    text: &str,
+    range: Range,
+    styles: StyleChain,
+) -> Vec<(Range, Orientation)> {
+    let mode = TextElem::orientation_in(styles);
+    let combine = TextElem::combine_upright_in(styles).map_or(0, NonZeroUsize::get);
+    let orientation = |c: char| match mode {
+        TextOrientation::Upright => Orientation::Upright,
+        TextOrientation::Sideways => Orientation::Sideways,
+        TextOrientation::Mixed if is_upright(c) => Orientation::Upright,
+        TextOrientation::Mixed => Orientation::Sideways,
+    };
+
+    let mut runs: Vec<(Range, Orientation)> = vec![];
+    let mut push = |range: Range, orientation: Orientation| match runs.last_mut() {
+        Some((prev, o)) if *o == orientation && orientation != Orientation::Combined => {
+            prev.end = range.end;
+        }
+        _ => runs.push((range, orientation)),
+    };
+
+    let mut chars = text[range.clone()].char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let start = range.start + i;
+        if c.is_ascii_digit() {
+            // Find the end of the digit run.
+            let mut end = start + 1;
+            let mut count = 1;
+            while let Some((j, _)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                end = range.start + j + 1;
+                count += 1;
             }
-            Smart::Custom(_) => Script::Unknown,
-        };
 
-        if level != prev_level || !is_compatible(curr_script, prev_script) {
-            if cursor < i {
-                process(cursor..i, prev_level);
+            if count <= combine {
+                push(start..end, Orientation::Combined);
+            } else {
+                push(start..end, orientation(c));
             }
-            cursor = i;
-            prev_level = level;
-            prev_script = curr_script;
-        } else if is_generic_script(prev_script) {
-            prev_script = curr_script;
+        } else {
+            push(start..start + c.len_utf8(), orientation(c));
         }
     }
 
-    process(cursor..range.end, prev_level);
+    if runs.is_empty() {
+        runs.push((range, Orientation::Sideways));
+    }
+
+    runs
+}
+
+/// Whether a character stands upright in vertical text with mixed
+/// orientation.
+///
+/// This is a simplified version of the `U` and `Tu` classes from Unicode
+/// Standard Annex #50.
+fn is_upright(c: char) -> bool {
+    matches!(
+        c.script(),
+        Script::Han
+            | Script::Hiragana
+            | Script::Katakana
+            | Script::Hangul
+            | Script::Bopomofo
+            | Script::Yi
+    ) || matches!(
+        c,
+        '\u{00A7}'
+            | '\u{00A9}'
+            | '\u{00AE}'
+            | '\u{00B1}'
+            | '\u{00BC}'..='\u{00BE}'
+            | '\u{00D7}'
+            | '\u{00F7}'
+            | '\u{2E80}'..='\u{2FFF}'
+            | '\u{3000}'..='\u{33FF}'
+            | '\u{FE10}'..='\u{FE1F}'
+            | '\u{FE30}'..='\u{FE6F}'
+            | '\u{FF01}'..='\u{FF60}'
+            | '\u{FFE0}'..='\u{FFE7}'
+            | '\u{1F000}'..='\u{1FAFF}'
+    )
+}
+
+/// Push the glyphs of an upright run, each of them turned back against the
+/// rotation of the line.
+fn push_upright(frame: &mut Frame, pos: Point, item: &TextItem) {
+    let center = pos.y - central_shift(&item.font).at(item.size);
+    let mut x = pos.x;
+    for glyph in &item.glyphs {
+        let mut upright = Frame::soft(Size::zero());
+        let advance = item.font.advance(glyph.id).unwrap_or_default();
+        let single = Glyph {
+            x_advance: advance,
+            x_offset: Em::zero(),
+            ..glyph.clone()
+        };
+        upright.push(
+            Point::zero(),
+            FrameItem::Text(TextItem { glyphs: vec![single], ..item.clone() }),
+        );
+        upright.transform(Transform::rotate(Angle::deg(-90.0)));
+        frame.push_frame(Point::new(x + glyph.x_offset.at(item.size), center), upright);
+        x += glyph.x_advance.at(item.size);
+    }
+}
+
+/// The distance from the baseline to the center of the font's em box, around
+/// which vertical text is aligned.
+fn central_shift(font: &Font) -> Em {
+    let metrics = font.metrics();
+    (metrics.ascender + metrics.descender) / 2.0
+}
+
+/// The width of a run of glyphs.
+fn run_width(glyphs: &[ShapedGlyph], size: Abs, orientation: Orientation) -> Abs {
+    match orientation {
+        Orientation::Combined => size,
+        _ => glyphs.iter().map(|g| g.x_advance).sum::<Em>().at(size),
+    }
 }
 
 /// Whether this is not a specific script.
@@ -664,6 +870,7 @@ fn shape<'a>(
     dir: Dir,
     lang: Lang,
     region: Option<Region>,
+    orientation: Orientation,
 ) -> ShapedText<'a> {
     let size = TextElem::size_in(styles);
     let mut ctx = ShapingContext {
@@ -674,8 +881,10 @@ fn shape<'a>(
         styles,
         variant: variant(styles),
         features: features(styles),
+        variations: variations(styles),
         fallback: TextElem::fallback_in(styles),
         dir,
+        upright: orientation == Orientation::Upright,
     };
 
     if !text.is_empty() {
@@ -685,6 +894,14 @@ fn shape<'a>(
     track_and_space(&mut ctx);
     calculate_adjustability(&mut ctx, lang, region);
 
+    // Combined runs behave like a single unbreakable character.
+    if orientation == Orientation::Combined {
+        for glyph in &mut ctx.glyphs {
+            glyph.adjustability = Adjustability::default();
+            glyph.is_justifiable = false;
+        }
+    }
+
     #[cfg(debug_assertions)]
     assert_all_glyphs_in_range(&ctx.glyphs, text, base..(base + text.len()));
     #[cfg(debug_assertions)]
@@ -699,7 +916,8 @@ fn shape<'a>(
         styles,
         variant: ctx.variant,
         size,
-        width: ctx.glyphs.iter().map(|g| g.x_advance).sum::<Em>().at(size),
+        width: run_width(&ctx.glyphs, size, orientation),
+        orientation,
         glyphs: Cow::Owned(ctx.glyphs),
     }
 }
@@ -713,8 +931,10 @@ struct ShapingContext<'a, 'v> {
     size: Abs,
     variant: FontVariant,
     features: Vec<rustybuzz::Feature>,
+    variations: Vec<(Tag, Scalar)>,
     fallback: bool,
     dir: Dir,
+    upright: bool,
 }
 
 /// Shape text with font fallback using the `families` iterator.
@@ -741,6 +961,7 @@ fn shape_segment<'a>(
         selection = book
             .select(family.as_str(), ctx.variant)
             .and_then(|id| world.font(id))
+            .map(|font| font.instance(ctx.variant, &ctx.variations))
             .filter(|font| !ctx.used.contains(font));
         if selection.is_some() {
             covers = family.covers();
@@ -754,6 +975,7 @@ fn shape_segment<'a>(
         selection = book
             .select_fallback(first, ctx.variant, text)
             .and_then(|id| world.font(id))
+            .map(|font| font.instance(ctx.variant, &ctx.variations))
             .filter(|font| !ctx.used.contains(font));
     }
 
@@ -767,42 +989,24 @@ fn shape_segment<'a>(
 
     ctx.used.push(font.clone());
 
-    // Fill the buffer with our text.
-    let mut buffer = UnicodeBuffer::new();
-    buffer.push_str(text);
-    buffer.set_language(language(ctx.styles));
-    if let Some(script) = TextElem::script_in(ctx.styles).custom().and_then(|script| {
+    let script = TextElem::script_in(ctx.styles).custom().and_then(|script| {
         rustybuzz::Script::from_iso15924_tag(Tag::from_bytes(script.as_bytes()))
-    }) {
-        buffer.set_script(script)
-    }
-    buffer.set_direction(match ctx.dir {
+    });
+
+    // Upright glyphs are shaped vertically, which also applies the `vert`
+    // feature for vertical alternates.
+    let direction = match ctx.dir {
+        _ if ctx.upright => rustybuzz::Direction::TopToBottom,
         Dir::LTR => rustybuzz::Direction::LeftToRight,
         Dir::RTL => rustybuzz::Direction::RightToLeft,
         _ => unimplemented!("vertical text layout"),
-    });
-    buffer.guess_segment_properties();
-
-    // By default, Harfbuzz will create zero-width space glyphs for default
-    // ignorables. This is probably useful for GUI apps that want noticeable
-    // effects on the cursor for those, but for us it's not useful and hurts
-    // text extraction.
-    buffer.set_flags(BufferFlags::REMOVE_DEFAULT_IGNORABLES);
-
-    // Prepare the shape plan. This plan depends on direction, script, language,
-    // and features, but is independent from the text and can thus be memoized.
-    let plan = create_shape_plan(
-        &font,
-        buffer.direction(),
-        buffer.script(),
-        buffer.language().as_ref(),
-        &ctx.features,
-    );
+    };
 
     // Shape!
-    let buffer = rustybuzz::shape_with_plan(font.rusty(), &plan, buffer);
-    let infos = buffer.glyph_infos();
-    let pos = buffer.glyph_positions();
+    let run =
+        shape_run(&font, text, direction, script, language(ctx.styles), &ctx.features);
+    let infos = &run.infos;
+    let pos = &run.positions;
     let ltr = ctx.dir.is_positive();
 
     // Whether the character at the given offset is covered by the coverage.
@@ -833,14 +1037,31 @@ fn shape_segment<'a>(
 
             let c = text[cluster..].chars().next().unwrap();
             let script = c.script();
-            let x_advance = font.to_em(pos[i].x_advance);
+
+            // For upright glyphs, the advance and offset along the line come
+            // from the vertical shaping and the horizontal offset becomes the
+            // offset across the line.
+            let (x_advance, x_offset, y_offset) = if ctx.upright {
+                (
+                    font.to_em(-pos[i].y_advance),
+                    font.to_em(-pos[i].y_offset),
+                    font.to_em(pos[i].x_offset),
+                )
+            } else {
+                (
+                    font.to_em(pos[i].x_advance),
+                    font.to_em(pos[i].x_offset),
+                    font.to_em(pos[i].y_offset),
+                )
+            };
+
             ctx.glyphs.push(ShapedGlyph {
                 font: font.clone(),
                 glyph_id: info.glyph_id as u16,
                 // TODO: Don't ignore y_advance.
                 x_advance,
-                x_offset: font.to_em(pos[i].x_offset),
-                y_offset: font.to_em(pos[i].y_offset),
+                x_offset,
+                y_offset,
                 adjustability: Adjustability::default(),
                 range: start..end,
                 safe_to_break: !info.unsafe_to_break(),
@@ -902,6 +1123,144 @@ fn shape_segment<'a>(
     ctx.used.pop();
 }
 
+/// The glyphs that result from shaping a run of text with a single font.
+struct ShapedRun {
+    infos: Vec<rustybuzz::GlyphInfo>,
+    positions: Vec<rustybuzz::GlyphPosition>,
+}
+
+/// Shape a run of text with a single font.
+///
+/// The result only depends on the text, font, direction, script, language,
+/// and features, but not on the run's position in the paragraph. It is thus
+/// memoized, so that identical runs like repeated headers or labels are
+/// shaped only once across paragraphs and layout iterations.
+#[comemo::memoize]
+fn shape_run(
+    font: &Font,
+    text: &str,
+    direction: rustybuzz::Direction,
+    script: Option<rustybuzz::Script>,
+    language: rustybuzz::Language,
+    features: &[rustybuzz::Feature],
+) -> Arc<ShapedRun> {
+    // Fill the buffer with our text.
+    let mut buffer = UnicodeBuffer::new();
+    buffer.push_str(text);
+    buffer.set_language(language);
+    if let Some(script) = script {
+        buffer.set_script(script);
+    }
+    buffer.set_direction(direction);
+    buffer.guess_segment_properties();
+
+    // By default, Harfbuzz will create zero-width space glyphs for default
+    // ignorables. This is probably useful for GUI apps that want noticeable
+    // effects on the cursor for those, but for us it's not useful and hurts
+    // text extraction.
+    buffer.set_flags(BufferFlags::REMOVE_DEFAULT_IGNORABLES);
+
+    // Prepare the shape plan. This plan depends on direction, script, language,
+    // and features, but is independent from the text and can thus be memoized.
+    let plan = create_shape_plan(
+        font,
+        buffer.direction(),
+        buffer.script(),
+        buffer.language().as_ref(),
+        features,
+    );
+
+    let buffer = rustybuzz::shape_with_plan(font.rusty(), &plan, buffer);
+    Arc::new(ShapedRun {
+        infos: buffer.glyph_infos().to_vec(),
+        positions: buffer.glyph_positions().to_vec(),
+    })
+}
+
+/// Shape a tatweel (the Arabic kashida) in the given font.
+///
+/// Returns the glyph and its advance, or `None` if the font lacks it.
+pub fn tatweel(font: &Font, styles: StyleChain) -> Option<(u16, Em)> {
+    let run = shape_run(
+        font,
+        "\u{640}",
+        rustybuzz::Direction::RightToLeft,
+        Some(rustybuzz::script::ARABIC),
+        language(styles),
+        &features(styles),
+    );
+
+    match (run.infos.as_slice(), run.positions.as_slice()) {
+        ([info], [pos]) if info.glyph_id != 0 => {
+            Some((info.glyph_id as u16, font.to_em(pos.x_advance)))
+        }
+        _ => None,
+    }
+}
+
+/// Shape a word of Arabic text with a single font, e.g. with tatweels inserted
+/// for kashida justification.
+///
+/// The glyphs are returned in visual order. The `offset` function maps byte
+/// offsets in the word to offsets in the paragraph's text, from which the
+/// glyphs' ranges are determined.
+pub fn shape_arabic_word(
+    font: &Font,
+    word: &str,
+    styles: StyleChain,
+    offset: impl Fn(usize) -> usize,
+) -> Vec<ShapedGlyph> {
+    let run = shape_run(
+        font,
+        word,
+        rustybuzz::Direction::RightToLeft,
+        Some(rustybuzz::script::ARABIC),
+        language(styles),
+        &features(styles),
+    );
+
+    let infos = &run.infos;
+    let pos = &run.positions;
+    (0..infos.len())
+        .map(|i| {
+            let cluster = infos[i].cluster as usize;
+            let start = offset(cluster);
+
+            // The glyph ends where the next cluster in logical order starts,
+            // which comes before it in visual order. Glyphs with inserted
+            // characters may share their cluster with the following ones.
+            let end = infos[..i]
+                .iter()
+                .rev()
+                .map(|info| offset(info.cluster as usize))
+                .find(|&end| end > start)
+                .unwrap_or_else(|| offset(word.len()));
+
+            let c = word[cluster..].chars().next().unwrap();
+            let script = c.script();
+            let x_advance = font.to_em(pos[i].x_advance);
+            ShapedGlyph {
+                font: font.clone(),
+                glyph_id: infos[i].glyph_id as u16,
+                x_advance,
+                x_offset: font.to_em(pos[i].x_offset),
+                y_offset: font.to_em(pos[i].y_offset),
+                adjustability: Adjustability::default(),
+                range: start..end,
+                safe_to_break: !infos[i].unsafe_to_break(),
+                c,
+                is_justifiable: is_justifiable(
+                    c,
+                    script,
+                    x_advance,
+                    Adjustability::default().stretchability,
+                ),
+                script,
+            }
+        })
+        .collect()
+}
+
 /// Create a shape plan.
 #[comemo::memoize]
 fn create_shape_plan(
@@ -15,7 +15,7 @@ mod underover;
 
 use rustybuzz::Feature;
 use ttf_parser::Tag;
-use typst_library::diag::{bail, SourceResult};
+use typst_library::diag::{bail, warning, SourceResult};
 use typst_library::engine::Engine;
 use typst_library::foundations::{
     Content, NativeElement, Packed, Resolve, StyleChain, SymbolElem,
@@ -30,7 +30,7 @@ use typst_library::math::*;
 use typst_library::model::ParElem;
 use typst_library::routines::{Arenas, RealizationKind};
 use typst_library::text::{
-    families, features, variant, Font, LinebreakElem, SpaceElem, TextEdgeBounds, TextElem,
+    features, variant, Font, FontList, LinebreakElem, SpaceElem, TextEdgeBounds, TextElem,
 };
 use typst_library::World;
 use typst_syntax::Span;
@@ -55,7 +55,7 @@ pub fn layout_equation_inline(
 ) -> SourceResult<Vec<InlineItem>> {
     assert!(!elem.block(styles));
 
-    let font = find_math_font(engine, styles, elem.span())?;
+    let font = find_math_font(engine, styles, elem.font(styles), elem.span())?;
 
     let mut locator = locator.split();
     let mut ctx = MathContext::new(engine, &mut locator, styles, region, &font);
@@ -110,7 +110,7 @@ pub fn layout_equation_block(
     assert!(elem.block(styles));
 
     let span = elem.span();
-    let font = find_math_font(engine, styles, span)?;
+    let font = find_math_font(engine, styles, elem.font(styles), span)?;
 
     let mut locator = locator.split();
     let mut ctx = MathContext::new(engine, &mut locator, styles, regions.base(), &font);
@@ -236,21 +236,61 @@ pub fn layout_equation_block(
     Ok(Fragment::frames(frames))
 }
 
+/// Find the font to typeset math with.
+///
+/// The families of the text font are tried first so that show-set rules for
+/// the text font keep working. Then, the equation's own font families act as
+/// the fallback chain.
 fn find_math_font(
     engine: &mut Engine<'_>,
     styles: StyleChain,
+    math_families: &FontList,
     span: Span,
 ) -> SourceResult<Font> {
     let variant = variant(styles);
     let world = engine.world;
-    let Some(font) = families(styles).find_map(|family| {
-        let id = world.book().select(family.as_str(), variant)?;
-        let font = world.font(id)?;
-        let _ = font.ttf().tables().math?.constants?;
-        Some(font)
-    }) else {
-        bail!(span, "current font does not support math");
+    let mut skipped = vec![];
+    let found =
+        TextElem::font_in(styles)
+            .into_iter()
+            .chain(math_families)
+            .find_map(|family| {
+                let id = world.book().select(family.as_str(), variant)?;
+                let font = world.font(id)?;
+                if font.ttf().tables().math.and_then(|math| math.constants).is_none() {
+                    skipped.push(family);
+                    return None;
+                }
+                Some(font)
+            });
+
+    let Some(font) = found else {
+        bail!(
+            span, "current font does not support math";
+            hint: "set `math.equation(font: ..)` to a font with a MATH table"
+        );
     };
+
+    // Families from the equation's list were already checked when they were
+    // set, and falling back from the default text font is expected. The
+    // warning is detached so that it is emitted once per family instead of
+    // once per equation.
+    let defaults = TextElem::font_in(StyleChain::default());
+    for family in skipped {
+        if !math_families
+            .into_iter()
+            .chain(defaults)
+            .any(|f| f.as_str() == family.as_str())
+        {
+            engine.sink.warn(warning!(
+                Span::detached(),
+                "font family {} has no MATH table",
+                family.as_str();
+                hint: "using {} for math instead", font.info().family
+            ));
+        }
+    }
+
     Ok(font)
 }
 
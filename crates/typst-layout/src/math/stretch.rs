@@ -5,6 +5,7 @@ use typst_library::foundations::{Packed, StyleChain};
 use typst_library::layout::{Abs, Axis, Frame, Point, Rel, Size};
 use typst_library::math::StretchElem;
 use typst_utils::Get;
+use unicode_math_class::MathClass;
 
 use super::{
     delimiter_alignment, GlyphFragment, MathContext, MathFragment, Scaled,
@@ -56,7 +57,15 @@ pub fn stretch_fragment(
 
     // Return if we attempt to stretch along an axis which isn't stretchable,
     // so that the original fragment isn't modified.
-    let Some(stretch_axis) = stretch_axis(ctx, &glyph) else { return };
+    let Some(stretch_axis) = stretch_axis(ctx, &glyph) else {
+        if axis.is_none_or(|axis| axis == Axis::Y) {
+            let target = stretch.relative_to(relative_to.unwrap_or(glyph.height()));
+            if target - short_fall > glyph.height() {
+                warn_unstretchable(ctx, &glyph, Axis::Y);
+            }
+        }
+        return;
+    };
     let axis = axis.unwrap_or(stretch_axis);
     if axis != stretch_axis {
         return;
@@ -147,6 +156,10 @@ pub fn stretch_glyph(
         })
         .unwrap_or(GlyphConstruction { assembly: None, variants: LazyArray16::new(&[]) });
 
+    if construction.variants.is_empty() && construction.assembly.is_none() {
+        warn_unstretchable(ctx, &base, axis);
+    }
+
     // Search for a pre-made variant with a good advance.
     let mut best_id = base.id;
     let mut best_advance = base.width;
@@ -169,6 +182,35 @@ pub fn stretch_glyph(
     assemble(ctx, base, assembly, min_overlap, target, axis)
 }
 
+/// Warn if a glyph that math layout needs to grow along the given axis has
+/// neither larger variants nor an assembly in the math font.
+fn warn_unstretchable(ctx: &mut MathContext, base: &GlyphFragment, axis: Axis) {
+    let needs_stretching = match axis {
+        Axis::X => matches!(base.c, '⎴' | '⎵' | '⏜'..='⏡'),
+        Axis::Y => {
+            base.c == '√'
+                || matches!(
+                    base.class,
+                    MathClass::Opening
+                        | MathClass::Closing
+                        | MathClass::Fence
+                        | MathClass::Large
+                )
+        }
+    };
+
+    if needs_stretching {
+        ctx.engine.sink.warn(warning!(
+            base.span,
+            "math font {} cannot stretch {}",
+            base.font.info().family,
+            base.c;
+            hint: "the font has no larger variants or assembly for this glyph";
+            hint: "try a different font with `math.equation(font: ..)`"
+        ));
+    }
+}
+
 /// Assemble a glyph from parts.
 fn assemble(
     ctx: &mut MathContext,
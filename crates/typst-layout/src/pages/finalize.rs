@@ -1,9 +1,15 @@
 use typst_library::diag::SourceResult;
 use typst_library::engine::Engine;
-use typst_library::introspection::{ManualPageCounter, Tag};
-use typst_library::layout::{Frame, FrameItem, Page, Point};
+use typst_library::foundations::{Packed, Resolve, StyleChain};
+use typst_library::introspection::{Locator, ManualPageCounter, Tag};
+use typst_library::layout::{
+    Abs, Axes, Binding, FloatSide, Frame, FrameItem, Page, Point, Region, Sides, Size,
+};
+use typst_library::model::MarginNoteElem;
+use typst_library::text::TextElem;
 
 use super::LayoutedPage;
+use crate::layout_frame;
 
 /// Piece together the inner page frame and the marginals. We can only do this
 /// at the very end because inside/outside margins require knowledge of the
@@ -12,9 +18,11 @@ pub fn finalize(
     engine: &mut Engine,
     counter: &mut ManualPageCounter,
     tags: &mut Vec<Tag>,
+    notes: &mut Vec<Packed<MarginNoteElem>>,
     LayoutedPage {
         inner,
         mut margin,
+        auto_height,
         binding,
         two_sided,
         header,
@@ -52,8 +60,26 @@ pub fn finalize(
     }
 
     // Add the inner contents.
+    let mut anchored = vec![];
+    find_margin_notes(&mut anchored, &inner, margin.top);
     frame.push_frame(Point::new(margin.left, margin.top), inner);
 
+    // Add the margin notes next to their anchors.
+    let inside_left = if two_sided {
+        !binding.swap(counter.physical())
+    } else {
+        binding == Binding::Left
+    };
+    place_margin_notes(
+        engine,
+        &mut frame,
+        notes,
+        anchored,
+        margin,
+        inside_left,
+        auto_height,
+    )?;
+
     // Add the "after" marginals.
     if let Some(footer) = footer {
         let y = frame.height() - footer.height();
@@ -72,3 +98,127 @@ pub fn finalize(
 
     Ok(Page { frame, fill, numbering, supplement, number })
 }
+
+/// Lay out margin notes and place them into the side margins of the page.
+///
+/// The notes in `spill` were carried over from the previous page and are
+/// placed first, at the top of the margin. New notes that don't fit into the
+/// margin anymore are pushed into `spill` for the next page. If the page's
+/// height is `auto`, it grows to fit all notes instead.
+fn place_margin_notes(
+    engine: &mut Engine,
+    frame: &mut Frame,
+    spill: &mut Vec<Packed<MarginNoteElem>>,
+    anchored: Vec<(Abs, Packed<MarginNoteElem>)>,
+    margin: Sides<Abs>,
+    inside_left: bool,
+    auto_height: bool,
+) -> SourceResult<()> {
+    let top = margin.top;
+    let bottom = frame.height() - margin.bottom;
+
+    let carried = std::mem::take(spill);
+    let notes = carried
+        .into_iter()
+        .map(|note| (top, note, true))
+        .chain(anchored.into_iter().map(|(y, note)| (y, note, false)));
+
+    // The bottom edge of the last note in the left and right margin.
+    let mut cursors: [Option<Abs>; 2] = [None, None];
+
+    for (anchor, note, carried) in notes {
+        let map = note.styles().cloned().unwrap_or_default();
+        let styles = StyleChain::new(&map);
+        let dir = TextElem::dir_in(styles);
+        let left = match note.side(styles) {
+            FloatSide::Left => true,
+            FloatSide::Right => false,
+            FloatSide::Start => dir.is_positive(),
+            FloatSide::End => !dir.is_positive(),
+            FloatSide::Inside => inside_left,
+            FloatSide::Outside => !inside_left,
+        };
+
+        let gap = note.gap(styles).resolve(styles);
+        let available = if left { margin.left } else { margin.right };
+        let width = note
+            .width(styles)
+            .map(|width| width.resolve(styles))
+            .unwrap_or(available - 2.0 * gap)
+            .max(Abs::zero());
+
+        let Some(loc) = note.location() else { continue };
+        let body = layout_frame(
+            engine,
+            &note.body,
+            Locator::synthesize(loc),
+            styles,
+            Region::new(Size::new(width, Abs::inf()), Axes::new(true, false)),
+        )?;
+
+        // Align the first baseline of the note with the baseline of the anchor
+        // line, but don't overlap the previous note on the same side.
+        let cursor = &mut cursors[usize::from(!left)];
+        let spacing = note.spacing(styles).resolve(styles);
+        let mut y = anchor + note.dy(styles).resolve(styles);
+        if !carried {
+            y -= first_baseline(&body, Abs::zero()).unwrap_or_default();
+        }
+        y.set_max(top);
+        if let Some(prev) = *cursor {
+            y.set_max(prev + spacing);
+        }
+
+        // Move the note to the next page if it doesn't fit anymore, unless it
+        // is the first note in its margin (then it never fits).
+        if !auto_height && !carried && cursor.is_some() && y + body.height() > bottom {
+            spill.push(note);
+            continue;
+        }
+
+        let x = if left {
+            margin.left - gap - width
+        } else {
+            frame.width() - margin.right + gap
+        };
+
+        *cursor = Some(y + body.height());
+        frame.push_frame(Point::new(x, y), body);
+    }
+
+    if auto_height {
+        let end = cursors.into_iter().flatten().fold(bottom, Abs::max);
+        frame.size_mut().y += end - bottom;
+    }
+
+    Ok(())
+}
+
+/// Collect all margin notes and their vertical positions in the frame.
+fn find_margin_notes(
+    output: &mut Vec<(Abs, Packed<MarginNoteElem>)>,
+    frame: &Frame,
+    y_offset: Abs,
+) {
+    for (pos, item) in frame.items() {
+        let y = y_offset + pos.y;
+        match item {
+            FrameItem::Group(group) => find_margin_notes(output, &group.frame, y),
+            FrameItem::Tag(Tag::Start(elem)) => {
+                if let Some(note) = elem.to_packed::<MarginNoteElem>() {
+                    output.push((y, note.clone()));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The vertical position of the first baseline in the frame.
+fn first_baseline(frame: &Frame, y_offset: Abs) -> Option<Abs> {
+    frame.items().find_map(|(pos, item)| match item {
+        FrameItem::Group(group) => first_baseline(&group.frame, y_offset + pos.y),
+        FrameItem::Text(_) => Some(y_offset + pos.y),
+        _ => None,
+    })
+}
@@ -5,7 +5,7 @@ mod finalize;
 mod run;
 
 use comemo::{Tracked, TrackedMut};
-use typst_library::diag::SourceResult;
+use typst_library::diag::{warning, SourceResult};
 use typst_library::engine::{Engine, Route, Sink, Traced};
 use typst_library::foundations::{Content, StyleChain};
 use typst_library::introspection::{
@@ -114,6 +114,7 @@ fn layout_pages<'a>(
 
     let mut pages = vec![];
     let mut tags = vec![];
+    let mut notes = vec![];
     let mut counter = ManualPageCounter::new();
 
     // Collect and finalize the runs, handling things like page parity and tags
@@ -123,7 +124,8 @@ fn layout_pages<'a>(
             Item::Run(..) => {
                 let layouted = runs.next().unwrap()?;
                 for layouted in layouted {
-                    let page = finalize(engine, &mut counter, &mut tags, layouted)?;
+                    let page =
+                        finalize(engine, &mut counter, &mut tags, &mut notes, layouted)?;
                     pages.push(page);
                 }
             }
@@ -133,7 +135,8 @@ fn layout_pages<'a>(
                 }
 
                 let layouted = layout_blank_page(engine, locator.relayout(), *initial)?;
-                let page = finalize(engine, &mut counter, &mut tags, layouted)?;
+                let page =
+                    finalize(engine, &mut counter, &mut tags, &mut notes, layouted)?;
                 pages.push(page);
             }
             Item::Tags(items) => {
@@ -147,6 +150,15 @@ fn layout_pages<'a>(
         }
     }
 
+    // Margin notes that didn't fit on the last page have nowhere to go.
+    for note in notes {
+        engine.sink.warn(warning!(
+            note.span(),
+            "margin note does not fit into the margin of the last page";
+            hint: "the note was not placed"
+        ));
+    }
+
     // Add the remaining tags to the very end of the last page.
     if !tags.is_empty() {
         let last = pages.last_mut().unwrap();
@@ -8,18 +8,20 @@ use typst_library::introspection::{
     Counter, CounterDisplayElem, CounterKey, Introspector, Locator, LocatorLink, TagElem,
 };
 use typst_library::layout::{
-    Abs, AlignElem, Alignment, Axes, Binding, ColumnsElem, Dir, Frame, HAlignment,
-    Length, OuterVAlignment, PageElem, Paper, Region, Regions, Rel, Sides, Size,
-    VAlignment,
+    Abs, AlignElem, Alignment, Axes, BaselineGrid, Binding, ColumnsElem, Dir, Frame,
+    FrameItem, HAlignment, Length, OuterVAlignment, PageElem, Paper, Point, Region,
+    Regions, Rel, Sides, Size, VAlignment,
 };
 use typst_library::model::Numbering;
 use typst_library::routines::{Pair, Routines};
-use typst_library::text::{LocalName, TextElem};
-use typst_library::visualize::Paint;
+use typst_library::text::{LocalName, TextDir, TextElem};
+use typst_library::visualize::{Geometry, Paint};
 use typst_library::World;
+use typst_syntax::Span;
 use typst_utils::Numeric;
 
 use crate::flow::{layout_flow, FlowMode};
+use crate::inline::turn;
 
 /// A mostly finished layout for one page. Needs only knowledge of its exact
 /// page number to be finalized into a `Page`. (Because the margins can depend
@@ -28,6 +30,7 @@ use crate::flow::{layout_flow, FlowMode};
 pub struct LayoutedPage {
     pub inner: Frame,
     pub margin: Sides<Abs>,
+    pub auto_height: bool,
     pub binding: Binding,
     pub two_sided: bool,
     pub header: Option<Frame>,
@@ -129,11 +132,13 @@ fn layout_page_run_impl(
     let header_ascent = PageElem::header_ascent_in(styles).relative_to(margin.top);
     let footer_descent = PageElem::footer_descent_in(styles).relative_to(margin.bottom);
     let numbering = PageElem::numbering_in(styles);
+    let grid = PageElem::baseline_grid_in(styles);
     let supplement = match PageElem::supplement_in(styles) {
         Smart::Auto => TextElem::packed(PageElem::local_name_in(styles)),
         Smart::Custom(content) => content.unwrap_or_default(),
     };
     let number_align = PageElem::number_align_in(styles);
+    let vertical = TextElem::dir_in(styles) == Dir::TTB;
     let binding =
         PageElem::binding_in(styles).unwrap_or_else(|| match TextElem::dir_in(styles) {
             Dir::LTR => Binding::Left,
@@ -171,23 +176,40 @@ fn layout_page_run_impl(
         (header.as_ref().unwrap_or(&None), footer.as_ref().unwrap_or(&numbering_marginal))
     };
 
-    // Layout the children.
-    let area = size - margin.sum_by_axis();
+    // Layout the children. In vertical writing mode, the flow is laid out
+    // with swapped axes and turned afterwards, so that its lines run from top
+    // to bottom and progress from right to left.
+    let mut area = size - margin.sum_by_axis();
+    let marker = TextElem::set_vertical(true).wrap();
+    let vertical_children: Vec<Pair>;
+    let (children, flow_styles) = if vertical {
+        std::mem::swap(&mut area.x, &mut area.y);
+        vertical_children =
+            children.iter().map(|&(child, s)| (child, s.chain(&marker))).collect();
+        (vertical_children.as_slice(), styles.chain(&marker))
+    } else {
+        (children, styles)
+    };
+
     let fragment = layout_flow(
         &mut engine,
         children,
         &mut locator,
-        styles,
+        flow_styles,
         Regions::repeat(area, area.map(Abs::is_finite)),
         PageElem::columns_in(styles),
         ColumnsElem::gutter_in(styles),
+        ColumnsElem::balance_in(styles),
         FlowMode::Root,
     )?;
 
     // Layouts a single marginal.
     let mut layout_marginal = |content: &Option<Content>, area, align| {
         let Some(content) = content else { return Ok(None) };
-        let aligned = content.clone().styled(AlignElem::set_alignment(align));
+        let mut aligned = content.clone().styled(AlignElem::set_alignment(align));
+        if vertical {
+            aligned = aligned.styled(TextElem::set_dir(TextDir(Smart::Auto)));
+        }
         crate::layout_frame(
             &mut engine,
             &aligned,
@@ -200,7 +222,15 @@ fn layout_page_run_impl(
 
     // Layout marginals.
     let mut layouted = Vec::with_capacity(fragment.len());
-    for inner in fragment {
+    for mut inner in fragment {
+        if let Some(grid) = &grid {
+            draw_baseline_grid(&mut inner, grid, styles);
+        }
+
+        if vertical {
+            inner = turn(inner);
+        }
+
         let header_size = Size::new(inner.width(), margin.top - header_ascent);
         let footer_size = Size::new(inner.width(), margin.bottom - footer_descent);
         let full_size = inner.size() + margin.sum_by_axis();
@@ -215,6 +245,7 @@ fn layout_page_run_impl(
             background: layout_marginal(background, full_size, mid)?,
             foreground: layout_marginal(foreground, full_size, mid)?,
             margin,
+            auto_height: !size.y.is_finite(),
             binding,
             two_sided,
         });
@@ -223,6 +254,30 @@ fn layout_page_run_impl(
     Ok(layouted)
 }
 
+/// Draws the lines of a baseline grid on top of the page's body if the grid
+/// has a stroke.
+fn draw_baseline_grid(frame: &mut Frame, grid: &BaselineGrid, styles: StyleChain) {
+    let Some(stroke) = &grid.stroke else { return };
+    let step = grid.step.resolve(styles);
+    if step <= Abs::zero() {
+        return;
+    }
+
+    let stroke = stroke.clone().resolve(styles).unwrap_or_default();
+    let line = Geometry::Line(Point::with_x(frame.width())).stroked(stroke);
+
+    // Start at the first grid line within the frame.
+    let mut y = grid.offset.resolve(styles);
+    if y < Abs::zero() {
+        y += step * (-y / step).ceil();
+    }
+
+    while y <= frame.height() {
+        frame.push(Point::with_y(y), FrameItem::Shape(line.clone(), Span::detached()));
+        y += step;
+    }
+}
+
 /// Determines the styles used for a page run itself and page-level content like
 /// marginals and footnotes.
 ///
@@ -4,9 +4,9 @@ use std::collections::HashSet;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use comemo::{Track, Tracked, TrackedMut, Validate};
-use ecow::EcoVec;
+use ecow::{EcoString, EcoVec};
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
-use typst_syntax::{FileId, Span};
+use typst_syntax::{FileId, Span, Spanned};
 
 use crate::diag::{bail, HintedStrResult, SourceDiagnostic, SourceResult, StrResult};
 use crate::foundations::{Styles, Value};
@@ -83,7 +83,12 @@ impl Engine<'_> {
         // Apply the subsinks to the outer sink.
         for (_, sink) in &mut pairs {
             let sink = std::mem::take(sink);
-            self.sink.extend(sink.delayed, sink.warnings, sink.values);
+            self.sink.extend(
+                sink.delayed,
+                sink.warnings,
+                sink.values,
+                sink.unknown_families,
+            );
         }
 
         pairs.into_iter().map(|(output, _)| output)
@@ -138,6 +143,9 @@ pub struct Sink {
     warnings_set: HashSet<u128>,
     /// A sequence of traced values for a span.
     values: EcoVec<(Value, Option<Styles>)>,
+    /// Requested font families that are not available, together with the
+    /// span of the font list that requested them.
+    unknown_families: EcoVec<Spanned<EcoString>>,
 }
 
 impl Sink {
@@ -164,9 +172,14 @@ impl Sink {
         self.values
     }
 
+    /// Get the requested font families that are not available.
+    pub fn unknown_families(&mut self) -> EcoVec<Spanned<EcoString>> {
+        std::mem::take(&mut self.unknown_families)
+    }
+
     /// Extend from another sink.
     pub fn extend_from_sink(&mut self, other: Sink) {
-        self.extend(other.delayed, other.warnings, other.values);
+        self.extend(other.delayed, other.warnings, other.values, other.unknown_families);
     }
 }
 
@@ -193,12 +206,20 @@ impl Sink {
         }
     }
 
+    /// Record a requested font family that is not available.
+    pub fn unknown_family(&mut self, family: Spanned<EcoString>) {
+        if !self.unknown_families.contains(&family) {
+            self.unknown_families.push(family);
+        }
+    }
+
     /// Extend from parts of another sink.
     fn extend(
         &mut self,
         delayed: EcoVec<SourceDiagnostic>,
         warnings: EcoVec<SourceDiagnostic>,
         values: EcoVec<(Value, Option<Styles>)>,
+        unknown_families: EcoVec<Spanned<EcoString>>,
     ) {
         self.delayed.extend(delayed);
         for warning in warnings {
@@ -207,6 +228,9 @@ impl Sink {
         if let Some(remaining) = Self::MAX_VALUES.checked_sub(self.values.len()) {
             self.values.extend(values.into_iter().take(remaining));
         }
+        for family in unknown_families {
+            self.unknown_family(family);
+        }
     }
 }
 
@@ -13,7 +13,7 @@ use crate::diag::{bail, At, HintedStrResult, SourceDiagnostic, SourceResult, Str
 use crate::engine::Engine;
 use crate::foundations::{
     cast, func, ops, repr, scope, ty, Args, Bytes, CastInfo, Context, Dict, FromValue,
-    Func, IntoValue, Reflect, Repr, Str, Value, Version,
+    Func, IndexMap, IntoValue, Reflect, Repr, Str, Value, Version,
 };
 
 /// Create a new [`Array`] from values.
@@ -143,6 +143,35 @@ impl Array {
 
         Ok(self.iter().cloned().cycle().take(count).collect())
     }
+
+    /// Find the first item whose key compares as `wanted` against all others.
+    fn extremum_by(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        key: Func,
+        default: Option<Value>,
+        wanted: Ordering,
+    ) -> SourceResult<Value> {
+        let mut best: Option<(Value, Value)> = None;
+        for item in self {
+            let k = key.call(engine, context, [item.clone()])?;
+            let replace = match &best {
+                Some((best_key, _)) => ops::compare(&k, best_key).at(span)? == wanted,
+                None => true,
+            };
+            if replace {
+                best = Some((k, item));
+            }
+        }
+
+        match best {
+            Some((_, item)) => Ok(item),
+            None => default.ok_or("cannot find extremum of empty array with no default"),
+        }
+        .at(span)
+    }
 }
 
 #[scope]
@@ -957,6 +986,208 @@ impl Array {
         }
         Ok(acc)
     }
+
+    /// Folds all items into a single value using an accumulator function, but
+    /// returns all intermediate accumulated values instead of just the final
+    /// one.
+    ///
+    /// ```example
+    /// #(1, 2, 3, 4).scan(0, (acc, x) => acc + x)
+    /// ```
+    #[func]
+    pub fn scan(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The initial value to start with. It is not part of the result.
+        init: Value,
+        /// The folding function. Must have two parameters: One for the
+        /// accumulated value and one for an item.
+        folder: Func,
+    ) -> SourceResult<Array> {
+        let mut out = EcoVec::with_capacity(self.0.len());
+        let mut acc = init;
+        for item in self {
+            acc = folder.call(engine, context, [acc, item])?;
+            out.push(acc.clone());
+        }
+        Ok(out.into())
+    }
+
+    /// Transforms all items with the given function and combines the
+    /// resulting arrays into a single flat one.
+    ///
+    /// In contrast to `map` followed by `flatten`, only one level of nesting
+    /// is removed. Results that are not arrays are kept as single items.
+    ///
+    /// ```example
+    /// #(1, 2, 3).flat-map(x => (x,) * x)
+    /// ```
+    #[func]
+    pub fn flat_map(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to each item.
+        mapper: Func,
+    ) -> SourceResult<Array> {
+        let mut flat = EcoVec::with_capacity(self.0.len());
+        for item in self {
+            match mapper.call(engine, context, [item])? {
+                Value::Array(nested) => flat.extend(nested),
+                other => flat.push(other),
+            }
+        }
+        Ok(flat.into())
+    }
+
+    /// Splits the array into two arrays: One with the items for which the
+    /// given function returns `{true}` and one with the rest. Both arrays
+    /// keep the original order.
+    ///
+    /// ```example
+    /// #let (even, odd) = range(10).partition(calc.even)
+    /// #even \
+    /// #odd
+    /// ```
+    #[func]
+    pub fn partition(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to each item. Must return a boolean.
+        test: Func,
+    ) -> SourceResult<Array> {
+        let mut kept = EcoVec::new();
+        let mut rejected = EcoVec::new();
+        for item in self {
+            if test
+                .call(engine, context, [item.clone()])?
+                .cast::<bool>()
+                .at(test.span())?
+            {
+                kept.push(item);
+            } else {
+                rejected.push(item);
+            }
+        }
+        Ok(array![Array(kept), Array(rejected)])
+    }
+
+    /// Groups the items into a dictionary by the string returned from the
+    /// given key function. Groups appear in the order of their first item and
+    /// each group keeps the original order of its items.
+    ///
+    /// ```example
+    /// #let words = ("apple", "avocado", "banana", "blueberry", "cherry")
+    /// #words.group-by(word => word.first())
+    /// ```
+    #[func]
+    pub fn group_by(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function that determines the group of each item. Must return a
+        /// string.
+        key: Func,
+    ) -> SourceResult<Dict> {
+        let mut groups = IndexMap::<Str, EcoVec<Value>>::new();
+        for item in self {
+            let group = key
+                .call(engine, context, [item.clone()])?
+                .cast::<Str>()
+                .at(key.span())?;
+            groups.entry(group).or_default().push(item);
+        }
+        Ok(groups
+            .into_iter()
+            .map(|(group, items)| (group, Array(items).into_value()))
+            .collect())
+    }
+
+    /// Deduplicates all items in the array by the value returned from the
+    /// given key function. Only the first item for each key is kept.
+    ///
+    /// In contrast to [`dedup`]($array.dedup) with a `key`, the key function
+    /// is called exactly once per item. Keys are compared with `==`, so the
+    /// time this takes still grows quadratically with the number of distinct
+    /// keys.
+    ///
+    /// ```example
+    /// #let people = (
+    ///   (name: "Ada", city: "London"),
+    ///   (name: "Grace", city: "New York"),
+    ///   (name: "Alan", city: "London"),
+    /// )
+    /// #people.unique-by(it => it.city).map(it => it.name)
+    /// ```
+    #[func]
+    pub fn unique_by(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to each item to determine its key.
+        key: Func,
+    ) -> SourceResult<Array> {
+        let mut keys: Vec<Value> = Vec::with_capacity(self.0.len());
+        let mut out = EcoVec::with_capacity(self.0.len());
+        for item in self {
+            let k = key.call(engine, context, [item.clone()])?;
+            if !keys.iter().any(|seen| ops::equal(seen, &k)) {
+                keys.push(k);
+                out.push(item);
+            }
+        }
+        Ok(out.into())
+    }
+
+    /// Returns the item for which the given key function returns the largest
+    /// value. If several items share the largest key, the first of them is
+    /// returned.
+    ///
+    /// ```example
+    /// #("fig", "banana", "kiwi").max-by(str.len)
+    /// ```
+    #[func]
+    pub fn max_by(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// The function to apply to each item to determine the key to compare
+        /// by.
+        key: Func,
+        /// What to return if the array is empty. Must be set if the array can
+        /// be empty.
+        #[named]
+        default: Option<Value>,
+    ) -> SourceResult<Value> {
+        self.extremum_by(engine, context, span, key, default, Ordering::Greater)
+    }
+
+    /// Returns the item for which the given key function returns the smallest
+    /// value. If several items share the smallest key, the first of them is
+    /// returned.
+    ///
+    /// ```example
+    /// #("fig", "banana", "kiwi").min-by(str.len)
+    /// ```
+    #[func]
+    pub fn min_by(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// The function to apply to each item to determine the key to compare
+        /// by.
+        key: Func,
+        /// What to return if the array is empty. Must be set if the array can
+        /// be empty.
+        #[named]
+        default: Option<Value>,
+    ) -> SourceResult<Value> {
+        self.extremum_by(engine, context, span, key, default, Ordering::Less)
+    }
 }
 
 /// A value that can be cast to bytes.
@@ -3,15 +3,18 @@ use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign};
 use std::sync::Arc;
 
+use comemo::Tracked;
 use ecow::{eco_format, EcoString};
 use indexmap::IndexMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use typst_syntax::is_ident;
 use typst_utils::ArcExt;
 
-use crate::diag::{Hint, HintedStrResult, StrResult};
+use crate::diag::{At, Hint, HintedStrResult, SourceResult, StrResult};
+use crate::engine::Engine;
 use crate::foundations::{
-    array, cast, func, repr, scope, ty, Array, Module, Repr, Str, Value,
+    array, cast, func, repr, scope, ty, Array, Context, Func, IntoValue, Module, Repr,
+    Str, Value,
 };
 
 /// Create a new [`Dict`] from key-value pairs.
@@ -156,6 +159,23 @@ impl Dict {
 
         msg.into()
     }
+
+    /// Merge `other` into `self`, recursing into dictionaries present on
+    /// both sides.
+    fn merge_deep(mut self, other: Dict) -> Dict {
+        let map = Arc::make_mut(&mut self.0);
+        for (key, value) in other {
+            let value = match (map.get_mut(&key), value) {
+                (Some(Value::Dict(prev)), Value::Dict(next)) => {
+                    *prev = std::mem::take(prev).merge_deep(next);
+                    continue;
+                }
+                (_, value) => value,
+            };
+            map.insert(key, value);
+        }
+        self
+    }
 }
 
 #[scope]
@@ -254,6 +274,146 @@ impl Dict {
             .map(|(k, v)| Value::Array(array![k.clone(), v.clone()]))
             .collect()
     }
+
+    /// Produces a new dictionary with the same keys, in which all values were
+    /// transformed with the given function.
+    ///
+    /// ```example
+    /// #let prices = (apple: 2, pear: 3)
+    /// #prices.map((key, value) => value * 2)
+    /// ```
+    #[func]
+    pub fn map(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to each pair. Must have two parameters: One
+        /// for the key and one for the value.
+        mapper: Func,
+    ) -> SourceResult<Dict> {
+        self.into_iter()
+            .map(|(key, value)| {
+                let mapped =
+                    mapper.call(engine, context, [key.clone().into_value(), value])?;
+                Ok((key, mapped))
+            })
+            .collect()
+    }
+
+    /// Produces a new dictionary with only the pairs from the original one for
+    /// which the given function returns `{true}`.
+    ///
+    /// ```example
+    /// #let stock = (apple: 0, pear: 3, plum: 5)
+    /// #stock.filter((key, value) => value > 0)
+    /// ```
+    #[func]
+    pub fn filter(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to each pair. Must have two parameters: One
+        /// for the key and one for the value. Must return a boolean.
+        test: Func,
+    ) -> SourceResult<Dict> {
+        let mut kept = IndexMap::new();
+        for (key, value) in self {
+            if test
+                .call(engine, context, [key.clone().into_value(), value.clone()])?
+                .cast::<bool>()
+                .at(test.span())?
+            {
+                kept.insert(key, value);
+            }
+        }
+        Ok(kept.into())
+    }
+
+    /// Merges other dictionaries into this one, recursively.
+    ///
+    /// In contrast to adding dictionaries with `+`, nested dictionaries that
+    /// exist on both sides are merged instead of replaced. For all other
+    /// values, the value from the later dictionary wins.
+    ///
+    /// ```example
+    /// #let defaults = (font: (size: 11pt, family: "Libertinus Serif"))
+    /// #defaults.merge((font: (size: 12pt)))
+    /// ```
+    #[func]
+    pub fn merge(
+        self,
+        /// The dictionaries to merge into this one, in order.
+        #[variadic]
+        others: Vec<Dict>,
+    ) -> Dict {
+        others.into_iter().fold(self, Dict::merge_deep)
+    }
+
+    /// Retrieves a value nested in dictionaries and arrays by following a path
+    /// of keys and indices. Returns the default value if any step of the path
+    /// does not exist or fails with an error if no default value was
+    /// specified.
+    ///
+    /// ```example
+    /// #let config = (authors: ((name: "Ada"), (name: "Grace")))
+    /// #config.get-path(("authors", 1, "name")) \
+    /// #config.get-path("authors.0.name") \
+    /// #config.get-path("publisher.name", default: none)
+    /// ```
+    #[func]
+    pub fn get_path(
+        &self,
+        /// The path to follow. Either an array of string keys and integer
+        /// indices or a string in which the steps are separated by dots.
+        path: DictPath,
+        /// A default value to return if the path does not exist.
+        #[named]
+        default: Option<Value>,
+    ) -> StrResult<Value> {
+        let mut current = Value::Dict(self.clone());
+        for segment in &path.0 {
+            let next = match (&current, segment) {
+                (Value::Dict(dict), PathSegment::Key(key)) => dict.0.get(key).cloned(),
+                (Value::Array(array), PathSegment::Index(index)) => {
+                    array.at(*index, None).ok()
+                }
+                (Value::Array(array), PathSegment::Key(key)) => {
+                    key.parse::<i64>().ok().and_then(|index| array.at(index, None).ok())
+                }
+                _ => None,
+            };
+
+            let Some(next) = next else {
+                return default.ok_or_else(|| missing_path(segment));
+            };
+
+            current = next;
+        }
+        Ok(current)
+    }
+}
+
+/// A path into nested dictionaries and arrays.
+pub struct DictPath(Vec<PathSegment>);
+
+/// One step of a [`DictPath`].
+enum PathSegment {
+    /// A dictionary key.
+    Key(Str),
+    /// An array index.
+    Index(i64),
+}
+
+cast! {
+    DictPath,
+    v: Str => Self(v.as_str().split('.').map(|key| PathSegment::Key(key.into())).collect()),
+    v: Array => Self(v.into_iter().map(Value::cast).collect::<HintedStrResult<_>>()?),
+}
+
+cast! {
+    PathSegment,
+    v: Str => Self::Key(v),
+    v: i64 => Self::Index(v),
 }
 
 /// A value that can be cast to dictionary.
@@ -389,6 +549,21 @@ fn missing_key(key: &str) -> EcoString {
     eco_format!("dictionary does not contain key {}", key.repr())
 }
 
+/// The error message when a path step does not exist and no default was
+/// given.
+#[cold]
+fn missing_path(segment: &PathSegment) -> EcoString {
+    match segment {
+        PathSegment::Key(key) => eco_format!(
+            "path does not exist at key {} and no default value was specified",
+            key.repr()
+        ),
+        PathSegment::Index(index) => eco_format!(
+            "path does not exist at index {index} and no default value was specified"
+        ),
+    }
+}
+
 /// The missing key access error message when no default was given.
 #[cold]
 fn missing_key_no_default(key: &str) -> EcoString {
@@ -0,0 +1,217 @@
+//! Encoding, hashing and checksums of strings and bytes.
+
+use ::base64::Engine as _;
+use ::md5::Md5;
+use ::sha1::Sha1;
+use ecow::{eco_format, EcoString};
+use sha2::{Digest, Sha256};
+
+use crate::diag::{bail, StrResult};
+use crate::foundations::{func, Bytes, Module, Repr, Scope, Str};
+use crate::loading::Readable;
+
+/// A module with encoding, hashing and checksum definitions.
+pub fn module() -> Module {
+    let mut scope = Scope::new();
+    scope.define_func::<base64>();
+    scope.define_func::<base64_decode>();
+    scope.define_func::<hex>();
+    scope.define_func::<hex_decode>();
+    scope.define_func::<sha256>();
+    scope.define_func::<sha1>();
+    scope.define_func::<md5>();
+    scope.define_func::<crc32>();
+    Module::new("encoding", scope)
+}
+
+/// Encodes a string or bytes with Base64.
+///
+/// Strings are encoded as their UTF-8 representation.
+///
+/// ```example
+/// #encoding.base64("Typst") \
+/// #encoding.base64(bytes((255, 254)), url-safe: true)
+/// ```
+#[func]
+pub fn base64(
+    /// The data to encode.
+    data: Readable,
+    /// Whether to use the URL- and filename-safe alphabet, which uses `-` and
+    /// `_` instead of `+` and `/`.
+    #[named]
+    #[default(false)]
+    url_safe: bool,
+    /// Whether to pad the output with `=` to a multiple of four characters.
+    #[named]
+    #[default(true)]
+    padding: bool,
+) -> Str {
+    let bytes = data.into_bytes();
+    base64_engine(url_safe, padding).encode(bytes.as_slice()).into()
+}
+
+/// Decodes a Base64 string into bytes.
+///
+/// Padding is optional when decoding.
+///
+/// ```example
+/// #str(encoding.base64-decode("VHlwc3Q="))
+/// ```
+#[func(title = "Decode Base64")]
+pub fn base64_decode(
+    /// The Base64 text to decode.
+    text: Str,
+    /// Whether the text uses the URL- and filename-safe alphabet.
+    #[named]
+    #[default(false)]
+    url_safe: bool,
+) -> StrResult<Bytes> {
+    let engine = ::base64::engine::GeneralPurpose::new(
+        if url_safe {
+            &::base64::alphabet::URL_SAFE
+        } else {
+            &::base64::alphabet::STANDARD
+        },
+        ::base64::engine::GeneralPurposeConfig::new()
+            .with_decode_padding_mode(::base64::engine::DecodePaddingMode::Indifferent),
+    );
+    engine
+        .decode(text.as_str())
+        .map(Bytes::new)
+        .map_err(|err| eco_format!("failed to decode base64 ({err})"))
+}
+
+/// Encodes a string or bytes as hexadecimal digits, two per byte.
+///
+/// ```example
+/// #encoding.hex("Hi") \
+/// #encoding.hex(bytes((171, 205)), upper: true)
+/// ```
+#[func(title = "Hexadecimal")]
+pub fn hex(
+    /// The data to encode.
+    data: Readable,
+    /// Whether to use uppercase digits.
+    #[named]
+    #[default(false)]
+    upper: bool,
+) -> Str {
+    let digits = if upper { b"0123456789ABCDEF" } else { b"0123456789abcdef" };
+    let bytes = data.into_bytes();
+    let mut out = EcoString::with_capacity(2 * bytes.len());
+    for &byte in bytes.as_slice() {
+        out.push(digits[usize::from(byte >> 4)] as char);
+        out.push(digits[usize::from(byte & 0xF)] as char);
+    }
+    out.into()
+}
+
+/// Decodes a string of hexadecimal digits into bytes.
+///
+/// Both lowercase and uppercase digits are accepted.
+///
+/// ```example
+/// #array(encoding.hex-decode("abCD"))
+/// ```
+#[func(title = "Decode Hexadecimal")]
+pub fn hex_decode(
+    /// The hexadecimal text to decode.
+    text: Str,
+) -> StrResult<Bytes> {
+    let digits = text
+        .chars()
+        .map(|c| {
+            c.to_digit(16)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| eco_format!("invalid hexadecimal digit {}", c.repr()))
+        })
+        .collect::<StrResult<Vec<u8>>>()?;
+
+    if digits.len() % 2 != 0 {
+        bail!("hexadecimal text must have an even number of digits");
+    }
+
+    Ok(Bytes::new(
+        digits
+            .chunks_exact(2)
+            .map(|pair| pair[0] << 4 | pair[1])
+            .collect::<Vec<u8>>(),
+    ))
+}
+
+/// Computes the SHA-256 digest of a string or bytes.
+///
+/// Combine with [`hex`]($encoding.hex) to get the usual textual
+/// representation.
+///
+/// ```example
+/// #encoding.hex(encoding.sha256("Typst"))
+/// ```
+#[func(title = "SHA-256")]
+pub fn sha256(
+    /// The data to hash.
+    data: Readable,
+) -> Bytes {
+    digest::<Sha256>(data)
+}
+
+/// Computes the SHA-1 digest of a string or bytes.
+///
+/// SHA-1 is not collision resistant and should only be used for
+/// compatibility with existing formats.
+///
+/// ```example
+/// #encoding.hex(encoding.sha1("Typst"))
+/// ```
+#[func(title = "SHA-1")]
+pub fn sha1(
+    /// The data to hash.
+    data: Readable,
+) -> Bytes {
+    digest::<Sha1>(data)
+}
+
+/// Computes the MD5 digest of a string or bytes.
+///
+/// MD5 is not collision resistant and should only be used for compatibility
+/// with existing formats.
+///
+/// ```example
+/// #encoding.hex(encoding.md5("Typst"))
+/// ```
+#[func(title = "MD5")]
+pub fn md5(
+    /// The data to hash.
+    data: Readable,
+) -> Bytes {
+    digest::<Md5>(data)
+}
+
+/// Computes the CRC-32 checksum (as used by ZIP and PNG) of a string or bytes.
+///
+/// ```example
+/// #encoding.crc32("Typst")
+/// ```
+#[func(title = "CRC-32")]
+pub fn crc32(
+    /// The data to checksum.
+    data: Readable,
+) -> i64 {
+    crc32fast::hash(data.into_bytes().as_slice()).into()
+}
+
+/// Hash the data with the given digest algorithm.
+fn digest<D: Digest>(data: Readable) -> Bytes {
+    Bytes::new(D::digest(data.into_bytes().as_slice()).to_vec())
+}
+
+/// The Base64 engine for the given alphabet and padding configuration.
+fn base64_engine(url_safe: bool, padding: bool) -> ::base64::engine::GeneralPurpose {
+    use ::base64::engine::general_purpose::*;
+    match (url_safe, padding) {
+        (false, true) => STANDARD,
+        (false, false) => STANDARD_NO_PAD,
+        (true, true) => URL_SAFE,
+        (true, false) => URL_SAFE_NO_PAD,
+    }
+}
@@ -1,6 +1,7 @@
 //! Foundational types and functions.
 
 pub mod calc;
+pub mod encoding;
 pub mod ops;
 pub mod repr;
 pub mod sys;
@@ -117,6 +118,7 @@ pub(super) fn define(global: &mut Scope, inputs: Dict, features: &Features) {
         global.define_func::<target>();
     }
     global.define("calc", calc::module());
+    global.define("encoding", encoding::module());
     global.define("sys", sys::module(inputs));
     global.reset_category();
 }
@@ -7,14 +7,16 @@ use comemo::Tracked;
 use ecow::EcoString;
 use serde::{Deserialize, Serialize};
 use typst_syntax::{Span, Spanned};
+use typst_utils::Numeric;
 use unicode_normalization::UnicodeNormalization;
 use unicode_segmentation::UnicodeSegmentation;
+use unscanny::Scanner;
 
-use crate::diag::{bail, At, SourceResult, StrResult};
+use crate::diag::{bail, At, HintedStrResult, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, dict, func, repr, scope, ty, Array, Bytes, Cast, Context, Decimal, Dict, Func,
-    IntoValue, Label, Repr, Type, Value, Version,
+    cast, dict, func, repr, scope, ty, Args, Array, Bytes, Cast, Context, Decimal, Dict,
+    Func, IntoValue, Label, Repr, Type, Value, Version,
 };
 use crate::layout::Alignment;
 
@@ -632,6 +634,102 @@ impl Str {
         }
         s.into()
     }
+
+    /// Pads the string at its start until it is at least `width` grapheme
+    /// clusters long.
+    ///
+    /// ```example
+    /// #"7".pad-start(3, fill: "0") \
+    /// #"Typst".pad-start(3)
+    /// ```
+    #[func]
+    pub fn pad_start(
+        &self,
+        /// The minimum number of grapheme clusters of the resulting string.
+        width: usize,
+        /// The character to pad with.
+        #[named]
+        #[default(' ')]
+        fill: char,
+    ) -> StrResult<Str> {
+        Ok(pad(self, check_width(width)?, fill, FormatAlign::Right).into())
+    }
+
+    /// Pads the string at its end until it is at least `width` grapheme
+    /// clusters long.
+    ///
+    /// ```example
+    /// #"ab".pad-end(4, fill: ".") \
+    /// #"Typst".pad-end(3)
+    /// ```
+    #[func]
+    pub fn pad_end(
+        &self,
+        /// The minimum number of grapheme clusters of the resulting string.
+        width: usize,
+        /// The character to pad with.
+        #[named]
+        #[default(' ')]
+        fill: char,
+    ) -> StrResult<Str> {
+        Ok(pad(self, check_width(width)?, fill, FormatAlign::Left).into())
+    }
+
+    /// Interpolates values into this string, which serves as a format string.
+    ///
+    /// Each placeholder in curly braces is replaced by a formatted argument:
+    /// - `{}` takes the next positional argument.
+    /// - `{1}` takes the positional argument with the given index.
+    /// - `{name}` takes the named argument `name`.
+    ///
+    /// To produce a literal brace, double it: `{{` or `}}`.
+    ///
+    /// After the argument selector, a colon may introduce a format
+    /// specification of the form `[[fill]align][sign][0][width][.precision]`:
+    /// - `align` is `<` (left), `>` (right) or `^` (center). Strings are
+    ///   aligned left and numbers right by default. The `fill` character
+    ///   defaults to a space.
+    /// - `sign` is `+` to also show a plus sign for positive numbers, or `-`
+    ///   for the default behaviour of only showing the minus sign.
+    /// - `0` pads numbers with zeros after their sign.
+    /// - `width` is the minimum number of grapheme clusters to produce.
+    /// - `precision` is the number of fractional digits for floats, decimals
+    ///   and lengths or the maximum number of grapheme clusters to keep from
+    ///   strings.
+    ///
+    /// Both `width` and `precision` may be at most 65535.
+    ///
+    /// Integers, floats, decimals and lengths are supported along with all
+    /// values that can be converted with [`str`]($str).
+    ///
+    /// ```example
+    /// #"{} has {} items".format("Cart", 3) \
+    /// #"{name:>8}|".format(name: "Typst") \
+    /// #"ID-{:05}".format(42) \
+    /// #"{:+.2}".format(3.14159) \
+    /// #"{:^9.1}".format(12.25pt) \
+    /// #"{0}, {0}, {1}!".format("Hip", "hooray")
+    /// ```
+    #[func]
+    pub fn format(
+        &self,
+        args: &mut Args,
+        /// The values to interpolate, referenced by position or name.
+        #[external]
+        #[variadic]
+        values: Vec<Value>,
+    ) -> HintedStrResult<Str> {
+        let args = args.take();
+        let mut positional = vec![];
+        let mut named = Dict::new();
+        for arg in args.items {
+            match arg.name {
+                Some(name) => named.insert(name, arg.value.v),
+                None => positional.push(arg.value.v),
+            }
+        }
+        format(self, &positional, &named)
+    }
 }
 
 impl Deref for Str {
@@ -856,6 +954,264 @@ fn captures_to_dict(cap: regex::Captures) -> Dict {
     }
 }
 
+/// Interpolate the arguments into a format string.
+fn format(template: &str, positional: &[Value], named: &Dict) -> HintedStrResult<Str> {
+    let mut output = EcoString::with_capacity(template.len());
+    let mut next = 0;
+    let mut s = Scanner::new(template);
+
+    while let Some(c) = s.eat() {
+        match c {
+            '{' if s.eat_if('{') => output.push('{'),
+            '}' if s.eat_if('}') => output.push('}'),
+            '{' => {
+                let placeholder = s.eat_until('}');
+                if !s.eat_if('}') {
+                    bail!("unclosed placeholder in format string");
+                }
+
+                let (selector, spec) = match placeholder.split_once(':') {
+                    Some((selector, spec)) => (selector.trim(), spec),
+                    None => (placeholder.trim(), ""),
+                };
+
+                let value = if selector.is_empty() {
+                    next += 1;
+                    positional.get(next - 1).ok_or_else(|| {
+                        eco_format!("missing positional argument {}", next - 1)
+                    })?
+                } else if let Ok(index) = selector.parse::<usize>() {
+                    positional.get(index).ok_or_else(|| {
+                        eco_format!("missing positional argument {index}")
+                    })?
+                } else {
+                    named.get(selector).map_err(|_| {
+                        eco_format!("missing named argument {}", selector.repr())
+                    })?
+                };
+
+                let spec = FormatSpec::parse(spec)?;
+                output.push_str(&spec.apply(value)?);
+            }
+            '}' => bail!(
+                "unmatched closing brace in format string";
+                hint: "use `}}}}` to insert a literal closing brace"
+            ),
+            c => output.push(c),
+        }
+    }
+
+    Ok(output.into())
+}
+
+/// A format specification of the form `[[fill]align][sign][0][width][.precision]`.
+struct FormatSpec {
+    fill: char,
+    align: Option<FormatAlign>,
+    plus: bool,
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+}
+
+/// How to align a formatted value within its width.
+#[derive(Copy, Clone)]
+enum FormatAlign {
+    Left,
+    Center,
+    Right,
+}
+
+impl FormatAlign {
+    /// Parse an alignment character.
+    fn parse(c: char) -> Option<Self> {
+        match c {
+            '<' => Some(Self::Left),
+            '^' => Some(Self::Center),
+            '>' => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+impl FormatSpec {
+    /// Parse a format specification (the part after the colon).
+    fn parse(spec: &str) -> StrResult<Self> {
+        let mut s = Scanner::new(spec);
+        let mut fill = ' ';
+        let mut align = None;
+
+        let mut chars = spec.chars();
+        let first = chars.next();
+        let second = chars.next();
+        if let Some(a) = second.and_then(FormatAlign::parse) {
+            fill = first.unwrap();
+            align = Some(a);
+            s.eat();
+            s.eat();
+        } else if let Some(a) = first.and_then(FormatAlign::parse) {
+            align = Some(a);
+            s.eat();
+        }
+
+        let plus = s.eat_if('+');
+        if !plus {
+            s.eat_if('-');
+        }
+
+        let zero = s.eat_if('0');
+        let width = s.eat_while(|c: char| c.is_ascii_digit());
+        let width = if width.is_empty() { 0 } else { parse_count(width)? };
+
+        let precision = if s.eat_if('.') {
+            let digits = s.eat_while(|c: char| c.is_ascii_digit());
+            if digits.is_empty() {
+                bail!("expected precision after `.` in format specification");
+            }
+            Some(parse_count(digits)?)
+        } else {
+            None
+        };
+
+        if !s.done() {
+            bail!("invalid format specification {}", spec.repr());
+        }
+
+        Ok(Self { fill, align, plus, zero, width, precision })
+    }
+
+    /// Format a value according to this specification.
+    fn apply(&self, value: &Value) -> StrResult<EcoString> {
+        let (sign, body, numeric) = match value {
+            Value::Int(n) => {
+                if self.precision.is_some() {
+                    bail!("precision is not supported for integers");
+                }
+                (*n < 0, eco_format!("{}", n.unsigned_abs()), true)
+            }
+            Value::Float(f) => {
+                let body = match self.precision {
+                    _ if !f.is_finite() => repr::display_float(f.abs()),
+                    Some(p) => eco_format!("{:.*}", p, f.abs()),
+                    None => eco_format!("{}", f.abs()),
+                };
+                (f.is_sign_negative() && !f.is_nan() && *f != 0.0, body, true)
+            }
+            Value::Decimal(d) => {
+                let body = match self.precision {
+                    Some(p) => eco_format!("{:.*}", p, d.abs()),
+                    None => eco_format!("{}", d.abs()),
+                };
+                (*d < Decimal::ZERO, body, true)
+            }
+            Value::Length(length) => match (length.abs.is_zero(), length.em.is_zero()) {
+                (_, true) => {
+                    let pt = length.abs.to_pt();
+                    (pt < 0.0, self.number_with_unit(pt.abs(), "pt"), true)
+                }
+                (true, false) => {
+                    let em = length.em.get();
+                    (em < 0.0, self.number_with_unit(em.abs(), "em"), true)
+                }
+                (false, false) => {
+                    let em = self.signed_number_with_unit(length.em.get(), "em");
+                    let pt = self.signed_number_with_unit(length.abs.to_pt(), "pt");
+                    (false, eco_format!("{em} + {pt}"), false)
+                }
+            },
+            _ => {
+                let string = match value.clone().cast::<ToStr>() {
+                    Ok(ToStr::Str(s)) => s,
+                    Ok(ToStr::Int(_)) => unreachable!(),
+                    Err(_) => bail!("cannot format {}", value.ty()),
+                };
+                let body = match self.precision {
+                    Some(p) => string.graphemes(true).take(p).collect::<String>().into(),
+                    None => string.into(),
+                };
+                (false, body, false)
+            }
+        };
+
+        let sign = match (sign, self.plus && numeric) {
+            (true, _) => repr::MINUS_SIGN,
+            (false, true) => "+",
+            (false, false) => "",
+        };
+
+        // Zero padding goes between the sign and the digits.
+        if self.zero && numeric && self.align.is_none() {
+            let len = sign.graphemes(true).count() + body.graphemes(true).count();
+            let zeros = "0".repeat(self.width.saturating_sub(len));
+            return Ok(eco_format!("{sign}{zeros}{body}"));
+        }
+
+        let default = if numeric { FormatAlign::Right } else { FormatAlign::Left };
+        let text = eco_format!("{sign}{body}");
+        Ok(pad(&text, self.width, self.fill, self.align.unwrap_or(default)))
+    }
+
+    /// Format an unsigned number followed by a unit, respecting the precision.
+    fn number_with_unit(&self, value: f64, unit: &str) -> EcoString {
+        match self.precision {
+            Some(p) => eco_format!("{:.*}{unit}", p, value),
+            None => repr::format_float(value, None, false, unit),
+        }
+    }
+
+    /// Format a number followed by a unit, respecting the precision and
+    /// prefixing negative numbers with a proper minus sign.
+    fn signed_number_with_unit(&self, value: f64, unit: &str) -> EcoString {
+        let body = self.number_with_unit(value.abs(), unit);
+        if value < 0.0 {
+            eco_format!("{}{body}", repr::MINUS_SIGN)
+        } else {
+            body
+        }
+    }
+}
+
+/// The maximum width or precision in a format specification.
+const MAX_FORMAT_COUNT: usize = u16::MAX as usize;
+
+/// Parse a width or precision from a format specification.
+fn parse_count(digits: &str) -> StrResult<usize> {
+    digits
+        .parse()
+        .ok()
+        .filter(|&count| count <= MAX_FORMAT_COUNT)
+        .ok_or_else(|| {
+            eco_format!("number {digits} in format specification is too large")
+        })
+}
+
+/// Ensure that a padding width stays within the limit of format
+/// specifications.
+fn check_width(width: usize) -> StrResult<usize> {
+    if width > MAX_FORMAT_COUNT {
+        bail!("width {width} is too large");
+    }
+    Ok(width)
+}
+
+/// Pad a string with `fill` until it is at least `width` grapheme clusters
+/// long.
+fn pad(text: &str, width: usize, fill: char, align: FormatAlign) -> EcoString {
+    let len = text.graphemes(true).count();
+    let missing = width.saturating_sub(len);
+    let (before, after) = match align {
+        FormatAlign::Left => (0, missing),
+        FormatAlign::Center => (missing / 2, missing - missing / 2),
+        FormatAlign::Right => (missing, 0),
+    };
+
+    let mut out = EcoString::with_capacity(text.len() + missing * fill.len_utf8());
+    out.extend(std::iter::repeat_n(fill, before));
+    out.push_str(text);
+    out.extend(std::iter::repeat_n(fill, after));
+    out
+}
+
 /// The out of bounds access error message.
 #[cold]
 fn out_of_bounds(index: i64, len: usize) -> EcoString {
@@ -648,6 +648,7 @@ pub mod attr {
     attrs! {
         charset
         cite
+        class
         colspan
         content
         href
@@ -2,16 +2,16 @@ use std::num::NonZeroUsize;
 
 use crate::diag::SourceResult;
 use crate::engine::Engine;
-use crate::foundations::{elem, Content, NativeElement, Packed, Show, StyleChain};
+use crate::foundations::{cast, elem, Content, NativeElement, Packed, Show, StyleChain};
 use crate::layout::{BlockElem, Length, Ratio, Rel};
 
 /// Separates a region into multiple equally sized columns.
 ///
 /// The `column` function lets you separate the interior of any container into
-/// multiple columns. It will currently not balance the height of the columns.
-/// Instead, the columns will take up the height of their container or the
-/// remaining height on the page. Support for balanced columns is planned for
-/// the future.
+/// multiple columns. By default, the columns are filled one after another and
+/// take up the height of their container or the remaining height on the page.
+/// To let the columns end at roughly the same height instead, you can
+/// [balance]($columns.balance) them.
 ///
 /// # Page-level columns { #page-level }
 /// If you need to insert columns across your whole document, use the `{page}`
@@ -53,6 +53,37 @@ pub struct ColumnsElem {
     #[default(Ratio::new(0.04).into())]
     pub gutter: Rel<Length>,
 
+    /// Whether to balance the columns so that they end at roughly the same
+    /// height.
+    ///
+    /// - `{false}`: The columns are filled one after another.
+    /// - `{true}`: The columns on the last page (or in the last region of a
+    ///   container) are balanced. This avoids ending a multi-column article
+    ///   with one full and one nearly empty column.
+    /// - `{"all"}`: Like `{true}`, but additionally balances pages that end
+    ///   without anything breaking across into the next page, e.g. because of
+    ///   a [page break]($pagebreak) or because the next unbreakable block
+    ///   didn't fit anymore. Pages on which a paragraph, block, or footnote
+    ///   continues on the next page are left as they are. Since running text
+    ///   usually breaks across pages, this often balances only the last page,
+    ///   just like `{true}`.
+    ///
+    /// Balancing respects breakable blocks, footnotes, and floats: The columns
+    /// are made as short as possible while still fitting the same content as
+    /// without balancing. To find the best height, each balanced region is
+    /// laid out several times, so balancing makes layout slower, especially
+    /// with `{"all"}`.
+    ///
+    /// This setting also applies to the [page's columns]($page.columns).
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #columns(2, balance: true)[
+    ///   #lorem(30)
+    /// ]
+    /// ```
+    pub balance: ColumnBalance,
+
     /// The content that should be layouted into the columns.
     #[required]
     pub body: Content,
@@ -66,6 +97,31 @@ impl Show for Packed<ColumnsElem> {
     }
 }
 
+/// How the heights of columns are balanced.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ColumnBalance {
+    /// The columns are filled one after another.
+    #[default]
+    Off,
+    /// The columns in the last region are balanced.
+    Last,
+    /// The columns in the last region and in all regions from which nothing
+    /// breaks across into the next one are balanced.
+    All,
+}
+
+cast! {
+    ColumnBalance,
+    self => match self {
+        Self::Off => false.into_value(),
+        Self::Last => true.into_value(),
+        Self::All => "all".into_value(),
+    },
+    v: bool => if v { Self::Last } else { Self::Off },
+    /// Balances the columns in all regions, not just the last one.
+    "all" => Self::All,
+}
+
 /// Forces a column break.
 ///
 /// The function will behave like a [page break]($pagebreak) when used in a
@@ -356,6 +356,25 @@ pub struct BlockElem {
     #[default(false)]
     pub sticky: bool,
 
+    /// Whether this block must stick to the preceding content, with no break
+    /// in between.
+    ///
+    /// This is the counterpart to [`sticky`]($block.sticky). If the block
+    /// does not fit into the current page or column anymore, the content
+    /// before it (the last line of a paragraph or a block) moves along with
+    /// it.
+    ///
+    /// ```example
+    /// >>> #set page(height: 100pt)
+    /// #lorem(16)
+    ///
+    /// #block(attached: true, rect(height: 40pt)[
+    ///   Signature
+    /// ])
+    /// ```
+    #[default(false)]
+    pub attached: bool,
+
     /// The contents of the block.
     #[positional]
     #[borrowed]
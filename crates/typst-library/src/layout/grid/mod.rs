@@ -3,18 +3,19 @@ pub mod resolve;
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 
-use comemo::Track;
+use comemo::{Track, Tracked};
 use smallvec::{smallvec, SmallVec};
-use typst_utils::NonZeroExt;
+use typst_utils::{NonZeroExt, Numeric};
 
 use crate::diag::{bail, At, HintedStrResult, HintedString, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Array, CastInfo, Content, Context, Fold, FromValue, Func,
+    cast, elem, scope, Array, Cast, CastInfo, Content, Context, Fold, FromValue, Func,
     IntoValue, NativeElement, Packed, Reflect, Resolve, Show, Smart, StyleChain, Value,
 };
 use crate::layout::{
-    Alignment, BlockElem, Length, OuterHAlignment, OuterVAlignment, Rel, Sides, Sizing,
+    Alignment, Angle, BlockElem, Length, OuterHAlignment, OuterVAlignment, Rel,
+    RotateElem, Sides, Sizing,
 };
 use crate::model::{TableCell, TableFooter, TableHLine, TableHeader, TableVLine};
 use crate::visualize::{Paint, Stroke};
@@ -177,6 +178,52 @@ pub struct GridElem {
     #[borrowed]
     pub row_gutter: TrackSizings,
 
+    /// How `{auto}` columns share the available width when their contents
+    /// don't fit next to each other.
+    ///
+    /// By default, columns that are wider than a fair share of the available
+    /// width are shrunk to that share. With `{"content"}`, each column instead
+    /// keeps at least the width of its widest unbreakable content (like a long
+    /// word) and the rest of the width is distributed in proportion to how much
+    /// wider each column would like to be. This is similar to how tables are
+    /// sized on the web.
+    ///
+    /// ```example
+    /// #set page(width: 160pt)
+    /// #grid(
+    ///   columns: 2,
+    ///   gutter: 6pt,
+    ///   column-fit: "content",
+    ///   [Incomprehensibilities],
+    ///   lorem(8),
+    /// )
+    /// ```
+    #[default(ColumnFit::Fair)]
+    pub column_fit: ColumnFit,
+
+    /// The minimum widths of `{auto}` columns.
+    ///
+    /// This can be a single width that applies to all columns or an array with
+    /// a width per column. Columns without a width (or with `{auto}`) have no
+    /// minimum. A column's minimum width takes precedence over its maximum
+    /// width and is kept even if the columns don't fit.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 3,
+    ///   gutter: 6pt,
+    ///   column-min: (auto, 2cm),
+    ///   [A], [B], [C],
+    /// )
+    /// ```
+    pub column_min: TrackBounds,
+
+    /// The maximum widths of `{auto}` columns.
+    ///
+    /// Takes the same values as `column-min`. Content that is wider than the
+    /// maximum is laid out with the maximum as the available width.
+    pub column_max: TrackBounds,
+
     /// How to fill the cells.
     ///
     /// This can be a color or a function that returns a color. The function
@@ -307,6 +354,19 @@ pub struct GridElem {
     /// The cells are populated in row-major order.
     #[variadic]
     pub children: Vec<GridChild>,
+
+    /// Whether the grid was broken before the current region. Set while
+    /// laying out repeated headers and footers.
+    #[internal]
+    #[ghost]
+    #[default(false)]
+    pub continued: bool,
+
+    /// Whether the grid continues in the next region. Set while laying out
+    /// repeated footers.
+    #[internal]
+    #[ghost]
+    pub continues: Smart<bool>,
 }
 
 #[scope]
@@ -325,6 +385,28 @@ impl GridElem {
 
     #[elem]
     type GridFooter;
+
+    /// Whether the grid was broken before the region (e.g. the page) that it
+    /// is currently laid out in.
+    ///
+    /// Within a repeated [header]($grid.header) or [footer]($grid.footer),
+    /// this can be used to mark the grid as continued. Everywhere else, it is
+    /// `{false}`. You can find an example at the
+    /// [`table.continued`]($table.continued) function.
+    #[func(contextual)]
+    pub fn continued(context: Tracked<Context>) -> HintedStrResult<bool> {
+        Ok(Self::continued_in(context.styles()?))
+    }
+
+    /// Whether the grid continues in the next region (e.g. on the next page).
+    ///
+    /// Within a repeated [footer]($grid.footer), this can be used to point
+    /// readers to the rest of the grid. Since this isn't known yet when a
+    /// header is laid out, it is `{auto}` in headers and everywhere else.
+    #[func(contextual)]
+    pub fn continues(context: Tracked<Context>) -> HintedStrResult<Smart<bool>> {
+        Ok(Self::continues_in(context.styles()?))
+    }
 }
 
 impl Show for Packed<GridElem> {
@@ -347,6 +429,53 @@ cast! {
     values: Array => Self(values.into_iter().map(Value::cast).collect::<HintedStrResult<_>>()?),
 }
 
+/// How automatic columns share the available width if their contents don't
+/// fit.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum ColumnFit {
+    /// Overlarge columns are shrunk to a fair share of the available width.
+    #[default]
+    Fair,
+    /// Columns keep their min-content width and share the rest in proportion
+    /// to their max-content width.
+    Content,
+}
+
+/// Width bounds for automatic columns.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum TrackBounds {
+    /// A bound that applies to all columns.
+    Value(Smart<Rel<Length>>),
+    /// A bound per column.
+    Array(Vec<Smart<Rel<Length>>>),
+}
+
+impl TrackBounds {
+    /// The bound for the column with the given index.
+    pub fn get(&self, x: usize) -> Smart<Rel<Length>> {
+        match self {
+            Self::Value(value) => *value,
+            Self::Array(array) => array.get(x).copied().unwrap_or(Smart::Auto),
+        }
+    }
+}
+
+impl Default for TrackBounds {
+    fn default() -> Self {
+        Self::Value(Smart::Auto)
+    }
+}
+
+cast! {
+    TrackBounds,
+    self => match self {
+        Self::Value(value) => value.into_value(),
+        Self::Array(array) => array.into_value(),
+    },
+    value: Smart<Rel<Length>> => Self::Value(value),
+    values: Array => Self::Array(values.into_iter().map(Value::cast).collect::<HintedStrResult<_>>()?),
+}
+
 /// Any child of a grid element.
 #[derive(Debug, PartialEq, Clone, Hash)]
 pub enum GridChild {
@@ -740,6 +869,20 @@ pub struct GridCell {
     /// unbreakable, while a cell spanning at least one `{auto}`-sized row is
     /// breakable.
     pub breakable: Smart<bool>,
+
+    /// The angle by which the cell's content is rotated.
+    ///
+    /// The grid sizes the cell based on its rotated content. With `{-90deg}`,
+    /// for instance, text runs vertically and the cell's row grows to fit it.
+    /// Rotated content is laid out with the full height of the page (or of the
+    /// cell's fixed-size row) available, so that it looks the same wherever
+    /// the row ends up, e.g. when it is part of a repeated header. The cell's
+    /// inset is not rotated. Cells with rotated content are not breakable by
+    /// default.
+    ///
+    /// See the [`table.cell`]($table.cell.rotate) parameter for an example.
+    #[default(Angle::zero())]
+    pub rotate: Angle,
 }
 
 cast! {
@@ -749,7 +892,12 @@ cast! {
 
 impl Show for Packed<GridCell> {
     fn show(&self, _engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
-        show_grid_cell(self.body.clone(), self.inset(styles), self.align(styles))
+        show_grid_cell(
+            self.body.clone(),
+            self.inset(styles),
+            self.align(styles),
+            self.rotate(styles),
+        )
     }
 }
 
@@ -771,9 +919,14 @@ pub(crate) fn show_grid_cell(
     mut body: Content,
     inset: Smart<Sides<Option<Rel<Length>>>>,
     align: Smart<Alignment>,
+    rotate: Angle,
 ) -> SourceResult<Content> {
     let inset = inset.unwrap_or_default().map(Option::unwrap_or_default);
 
+    if !rotate.is_zero() {
+        body = RotateElem::new(body).with_angle(rotate).with_reflow(true).pack();
+    }
+
     if inset != Sides::default() {
         // Only pad if some inset is not 0pt.
         // Avoids a bug where using .padded() in any way inside Show causes
@@ -10,8 +10,9 @@ use typst_library::engine::Engine;
 use typst_library::foundations::{Content, Fold, Packed, Smart, StyleChain};
 use typst_library::introspection::Locator;
 use typst_library::layout::{
-    Abs, Alignment, Axes, Celled, GridCell, GridChild, GridElem, GridItem, Length,
-    OuterHAlignment, OuterVAlignment, Rel, ResolvedCelled, Sides, Sizing,
+    Abs, Alignment, Axes, Celled, ColumnFit, GridCell, GridChild, GridElem, GridItem,
+    Length, OuterHAlignment, OuterVAlignment, Rel, ResolvedCelled, Sides, Sizing,
+    TrackBounds,
 };
 use typst_library::model::{TableCell, TableChild, TableElem, TableItem};
 use typst_library::text::TextElem;
@@ -19,7 +20,7 @@ use typst_library::visualize::{Paint, Stroke};
 use typst_library::Dir;
 
 use typst_syntax::Span;
-use typst_utils::NonZeroExt;
+use typst_utils::{NonZeroExt, Numeric};
 
 use crate::introspection::SplitLocator;
 
@@ -60,7 +61,7 @@ pub fn grid_to_cellgrid<'a>(
             ResolvableGridChild::Item(grid_item_to_resolvable(item, styles))
         }
     });
-    resolve_cellgrid(
+    let mut grid = resolve_cellgrid(
         tracks,
         gutter,
         locator,
@@ -73,7 +74,11 @@ pub fn grid_to_cellgrid<'a>(
         styles,
         elem.span(),
     )
-    .trace(engine.world, tracepoint, elem.span())
+    .trace(engine.world, tracepoint, elem.span())?;
+    grid.column_fit = elem.column_fit(styles);
+    grid.column_min = elem.column_min(styles);
+    grid.column_max = elem.column_max(styles);
+    Ok(grid)
 }
 
 /// Convert a table to a cell grid.
@@ -113,7 +118,7 @@ pub fn table_to_cellgrid<'a>(
             ResolvableGridChild::Item(table_item_to_resolvable(item, styles))
         }
     });
-    resolve_cellgrid(
+    let mut grid = resolve_cellgrid(
         tracks,
         gutter,
         locator,
@@ -126,7 +131,11 @@ pub fn table_to_cellgrid<'a>(
         styles,
         elem.span(),
     )
-    .trace(engine.world, tracepoint, elem.span())
+    .trace(engine.world, tracepoint, elem.span())?;
+    grid.column_fit = elem.column_fit(styles);
+    grid.column_min = elem.column_min(styles);
+    grid.column_max = elem.column_max(styles);
+    Ok(grid)
 }
 
 fn grid_item_to_resolvable(
@@ -219,7 +228,8 @@ impl ResolvableCell for Packed<TableCell> {
         let cell = &mut *self;
         let colspan = cell.colspan(styles);
         let rowspan = cell.rowspan(styles);
-        let breakable = cell.breakable(styles).unwrap_or(breakable);
+        let rotated = !cell.rotate(styles).is_zero();
+        let breakable = cell.breakable(styles).unwrap_or(breakable && !rotated);
         let fill = cell.fill(styles).unwrap_or_else(|| fill.clone());
 
         let cell_stroke = cell.stroke(styles);
@@ -274,6 +284,7 @@ impl ResolvableCell for Packed<TableCell> {
             stroke,
             stroke_overridden,
             breakable,
+            rotated,
         }
     }
 
@@ -314,7 +325,8 @@ impl ResolvableCell for Packed<GridCell> {
         let cell = &mut *self;
         let colspan = cell.colspan(styles);
         let rowspan = cell.rowspan(styles);
-        let breakable = cell.breakable(styles).unwrap_or(breakable);
+        let rotated = !cell.rotate(styles).is_zero();
+        let breakable = cell.breakable(styles).unwrap_or(breakable && !rotated);
         let fill = cell.fill(styles).unwrap_or_else(|| fill.clone());
 
         let cell_stroke = cell.stroke(styles);
@@ -369,6 +381,7 @@ impl ResolvableCell for Packed<GridCell> {
             stroke,
             stroke_overridden,
             breakable,
+            rotated,
         }
     }
 
@@ -564,6 +577,9 @@ pub struct Cell<'a> {
     /// By default, a cell spanning only fixed-size rows is unbreakable, while
     /// a cell spanning at least one `auto`-sized row is breakable.
     pub breakable: bool,
+    /// Whether the cell's content is rotated. Such cells are laid out with
+    /// the full height of the region available.
+    pub rotated: bool,
 }
 
 impl<'a> Cell<'a> {
@@ -578,6 +594,7 @@ impl<'a> Cell<'a> {
             stroke: Sides::splat(None),
             stroke_overridden: Sides::splat(false),
             breakable: true,
+            rotated: false,
         }
     }
 }
@@ -644,6 +661,12 @@ pub struct CellGrid<'a> {
     pub footer: Option<Repeatable<Footer>>,
     /// Whether this grid has gutters.
     pub has_gutter: bool,
+    /// How auto columns share the available width if they don't fit.
+    pub column_fit: ColumnFit,
+    /// The minimum widths of auto columns, indexed without gutter.
+    pub column_min: TrackBounds,
+    /// The maximum widths of auto columns, indexed without gutter.
+    pub column_max: TrackBounds,
 }
 
 impl<'a> CellGrid<'a> {
@@ -720,6 +743,9 @@ impl<'a> CellGrid<'a> {
             header,
             footer,
             has_gutter,
+            column_fit: ColumnFit::default(),
+            column_min: TrackBounds::default(),
+            column_max: TrackBounds::default(),
         }
     }
 
@@ -9,8 +9,8 @@ use typst_utils::{singleton, NonZeroExt, Scalar};
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, Args, AutoValue, Cast, Construct, Content, Context, Dict, Fold, Func,
-    NativeElement, Set, Smart, StyleChain, Value,
+    cast, dict, elem, Args, AutoValue, Cast, Construct, Content, Context, Dict, Fold,
+    Func, NativeElement, Set, Smart, StyleChain, Value,
 };
 use crate::introspection::Introspector;
 use crate::layout::{
@@ -19,7 +19,7 @@ use crate::layout::{
 };
 use crate::model::{DocumentInfo, Numbering};
 use crate::text::LocalName;
-use crate::visualize::{Color, Paint};
+use crate::visualize::{Color, Paint, Stroke};
 
 /// Layouts its child onto one or multiple pages.
 ///
@@ -165,7 +165,9 @@ pub struct PageElem {
     /// How many columns the page has.
     ///
     /// If you need to insert columns into a page or other container, you can
-    /// also use the [`columns` function]($columns).
+    /// also use the [`columns` function]($columns). To make the columns end at
+    /// the same height, set the columns function's
+    /// [`balance`]($columns.balance) parameter.
     ///
     /// ```example:single
     /// #set page(columns: 2, height: 4.8cm)
@@ -183,6 +185,70 @@ pub struct PageElem {
     #[ghost]
     pub columns: NonZeroUsize,
 
+    /// A baseline grid to which the lines of the page's body text are aligned.
+    ///
+    /// When a grid is set, the baseline of each line of a paragraph in the
+    /// page's flow is moved down onto the next grid line. Other blocks, like
+    /// headings and figures, are padded at the bottom up to the next grid line
+    /// so that the text after them stays in rhythm. This way, lines align
+    /// across columns and facing pages. Lines within containers such as
+    /// [blocks]($block) are not aligned individually.
+    ///
+    /// - `{none}`: Lines are not aligned to a grid.
+    /// - A length: The distance between two grid lines.
+    /// - A dictionary: With the following keys:
+    ///   - `step`: The distance between two grid lines.
+    ///   - `offset`: The position of the first grid line, measured from the
+    ///     top of the page's body area. Defaults to `{0pt}`.
+    ///   - `stroke`: How to draw the grid lines on top of the page. This is
+    ///     useful to debug the grid. Defaults to `{none}`.
+    ///
+    /// ```example
+    /// #set page(
+    ///   height: 120pt,
+    ///   columns: 2,
+    ///   baseline-grid: (step: 12pt, stroke: 0.25pt + aqua),
+    /// )
+    /// #set par(leading: 0.5em)
+    ///
+    /// Text on the grid.
+    /// #text(1.3em)[Bigger text.]
+    /// More text on the grid.
+    /// #colbreak()
+    /// #lorem(8)
+    /// ```
+    #[ghost]
+    pub baseline_grid: Option<BaselineGrid>,
+
+    /// Whether to vertically justify the page's contents.
+    ///
+    /// When a page or column ends before its content reaches the bottom, for
+    /// example because a large figure moved to the next page, the leftover
+    /// space is distributed across the spacing between paragraphs and blocks
+    /// (including the spacing around equations). This way, the last line
+    /// always ends at the bottom of the page. The last page of a flow and
+    /// pages ended by an explicit break are not justified.
+    ///
+    /// - `{false}`: The page has a ragged bottom.
+    /// - `{true}`: The spacing can stretch arbitrarily.
+    /// - A dictionary with a `limit` key: How much each gap can stretch at
+    ///   most, relative to its natural size. If the limit is reached, the
+    ///   remaining space stays free at the bottom.
+    ///
+    /// ```example
+    /// #set page(height: 140pt, vertical-justify: (limit: 100%))
+    /// = Results
+    /// #lorem(12)
+    ///
+    /// $ a^2 + b^2 = c^2 $
+    ///
+    /// #lorem(5)
+    /// #pagebreak(weak: true)
+    /// #lorem(20)
+    /// ```
+    #[ghost]
+    pub vertical_justify: VerticalJustify,
+
     /// The page's background fill.
     ///
     /// Setting this to something non-transparent instructs the printer to color
@@ -613,6 +679,72 @@ cast! {
     }
 }
 
+/// A baseline grid to which lines of body text are aligned.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct BaselineGrid {
+    /// The distance between two grid lines.
+    pub step: Length,
+    /// The position of the first grid line.
+    pub offset: Length,
+    /// How to draw the grid lines, if at all.
+    pub stroke: Option<Stroke>,
+}
+
+cast! {
+    BaselineGrid,
+    self => Value::Dict(self.into()),
+    step: Length => Self { step, offset: Length::zero(), stroke: None },
+    mut dict: Dict => {
+        let step = dict.take("step")?.cast()?;
+        let offset: Option<Length> =
+            dict.take("offset").ok().map(Value::cast).transpose()?;
+        let stroke: Option<Option<Stroke>> =
+            dict.take("stroke").ok().map(Value::cast).transpose()?;
+        dict.finish(&["step", "offset", "stroke"])?;
+        Self {
+            step,
+            offset: offset.unwrap_or_default(),
+            stroke: stroke.flatten(),
+        }
+    },
+}
+
+impl From<BaselineGrid> for Dict {
+    fn from(grid: BaselineGrid) -> Self {
+        dict! {
+            "step" => grid.step,
+            "offset" => grid.offset,
+            "stroke" => grid.stroke,
+        }
+    }
+}
+
+/// How to vertically justify the contents of a page.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Hash)]
+pub enum VerticalJustify {
+    /// The contents are not justified.
+    #[default]
+    Off,
+    /// The spacing between paragraphs and blocks is stretched, by at most the
+    /// given amount relative to the natural spacing, if any.
+    On(Option<Rel<Length>>),
+}
+
+cast! {
+    VerticalJustify,
+    self => match self {
+        Self::Off => false.into_value(),
+        Self::On(None) => true.into_value(),
+        Self::On(Some(limit)) => Value::Dict(dict! { "limit" => limit }),
+    },
+    v: bool => if v { Self::On(None) } else { Self::Off },
+    mut dict: Dict => {
+        let limit = dict.take("limit")?.cast()?;
+        dict.finish(&["limit"])?;
+        Self::On(Some(limit))
+    },
+}
+
 /// Specification of the page's binding.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Binding {
@@ -1,6 +1,9 @@
-use crate::foundations::{elem, scope, Cast, Content, Packed, Smart};
+use std::num::NonZeroUsize;
+
+use crate::diag::bail;
+use crate::foundations::{cast, elem, scope, Cast, Content, Packed, Smart};
 use crate::introspection::{Locatable, Unqueriable};
-use crate::layout::{Alignment, Em, Length, Rel};
+use crate::layout::{Alignment, Em, Length, Ratio, Rel};
 
 /// Places content relatively to its parent container.
 ///
@@ -129,14 +132,108 @@ pub struct PlaceElem {
     /// ```
     pub float: bool,
 
+    /// The side to which a floating element is moved so that in-flow text
+    /// wraps around it.
+    ///
+    /// By default, floating elements are placed at the top or bottom of the
+    /// parent container. With a side, the element is instead placed at its
+    /// position in the flow, at the given side of the container. Paragraphs
+    /// next to it are shortened to leave room for it. Other blocks next to it
+    /// are narrowed if they can't break across pages and moved below it
+    /// otherwise. The [`alignment`]($place.alignment) has no effect on such
+    /// side floats.
+    ///
+    /// The side can be `{left}`, `{right}`, `{start}`, or `{end}`. It can also
+    /// be `{"inside"}` or `{"outside"}` to move the element to the side of the
+    /// page where it is bound or the opposite one, depending on the
+    /// [binding]($page.binding) and whether the page is even or odd.
+    ///
+    /// Requires `float` to be `{true}`.
+    ///
+    /// ```example
+    /// #set page(height: 140pt)
+    /// #place(
+    ///   float: true,
+    ///   side: left,
+    ///   clearance: 8pt,
+    ///   circle(radius: 24pt, fill: aqua),
+    /// )
+    ///
+    /// #lorem(45)
+    /// ```
+    pub side: Option<FloatSide>,
+
     /// The spacing between the placed element and other elements in a floating
-    /// layout.
+    /// layout. For side floats, this is the gap to the text next to and below
+    /// the element.
     ///
     /// Has no effect if `float` is `{false}`.
     #[default(Em::new(1.5).into())]
     #[resolve]
     pub clearance: Length,
 
+    /// Whether a floating element must not appear before its position in the
+    /// text.
+    ///
+    /// By default, a float can move to the top of the page or column in which
+    /// it is placed, above the text that precedes it. When this is `{true}`,
+    /// it can only go to the bottom of that page or column or to the top of a
+    /// later one.
+    ///
+    /// Has no effect if `float` is `{false}` or for side floats.
+    #[default(false)]
+    pub defer: bool,
+
+    /// Whether a floating element is put on a page (or column) of its own.
+    ///
+    /// Such floats are never placed next to in-flow content. Instead, they
+    /// are collected on the next page or column, together with the following
+    /// floats that request the same and fit. This is useful for large
+    /// figures and tables that would otherwise leave little space for text.
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #lorem(10)
+    /// #place(float: true, float-page: true, rect(height: 40pt)[A])
+    /// #place(float: true, float-page: true, rect(height: 40pt)[B])
+    /// #lorem(15)
+    /// ```
+    ///
+    /// Has no effect if `float` is `{false}` or for side floats.
+    #[default(false)]
+    pub float_page: bool,
+
+    /// The maximum number of floats in the parent container's top and bottom
+    /// areas, including this one.
+    ///
+    /// If there are already that many floats on the page or column, this
+    /// float moves to the next one. The first float on a page or column is
+    /// always placed, so that a float cannot be deferred indefinitely. Like
+    /// the other properties of floats, this can also be configured for
+    /// [figures]($figure) with a show-set rule:
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #show figure: set place(max-count: 1)
+    ///
+    /// #figure(placement: top, rect[A])
+    /// #figure(placement: bottom, rect[B])
+    /// #lorem(10)
+    /// ```
+    ///
+    /// Has no effect if `float` is `{false}` or for side floats.
+    pub max_count: Option<NonZeroUsize>,
+
+    /// The maximum fraction of the parent container's height that floats may
+    /// take up, including this one.
+    ///
+    /// If placing this float on the current page or column would exceed the
+    /// fraction, it moves to the next one. Just like with `max-count`, the
+    /// first float on a page or column is always placed.
+    ///
+    /// Has no effect if `float` is `{false}` or for side floats.
+    pub max_fraction: Option<Ratio>,
+
     /// The horizontal displacement of the placed content.
     ///
     /// ```example
@@ -184,11 +281,52 @@ pub enum PlacementScope {
     Parent,
 }
 
+/// The side to which a floating element is moved when text wraps around it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum FloatSide {
+    /// The start side in the text direction.
+    Start,
+    /// The end side in the text direction.
+    End,
+    /// The left side.
+    Left,
+    /// The right side.
+    Right,
+    /// The side of the page where it is bound.
+    Inside,
+    /// The side of the page opposite to where it is bound.
+    Outside,
+}
+
+cast! {
+    FloatSide,
+    self => match self {
+        Self::Start => Alignment::START.into_value(),
+        Self::End => Alignment::END.into_value(),
+        Self::Left => Alignment::LEFT.into_value(),
+        Self::Right => Alignment::RIGHT.into_value(),
+        Self::Inside => "inside".into_value(),
+        Self::Outside => "outside".into_value(),
+    },
+    v: Alignment => match v {
+        Alignment::START => Self::Start,
+        Alignment::END => Self::End,
+        Alignment::LEFT => Self::Left,
+        Alignment::RIGHT => Self::Right,
+        _ => bail!("must be `start`, `end`, `left`, or `right`"),
+    },
+    /// The side of the page where it is bound.
+    "inside" => Self::Inside,
+    /// The side of the page opposite to where it is bound.
+    "outside" => Self::Outside,
+}
+
 /// Asks the layout algorithm to place pending floating elements before
 /// continuing with the content.
 ///
 /// This is useful for preventing floating figures from spilling
-/// into the next section.
+/// into the next section. Content following a flush is also moved below
+/// side floats instead of wrapping around them.
 ///
 /// ```example
 /// >>> #set page(height: 160pt, width: 150pt)
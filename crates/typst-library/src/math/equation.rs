@@ -1,9 +1,10 @@
 use std::num::NonZeroUsize;
 
+use typst_syntax::Spanned;
 use typst_utils::NonZeroExt;
 use unicode_math_class::MathClass;
 
-use crate::diag::SourceResult;
+use crate::diag::{warning, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
     elem, Content, NativeElement, Packed, Show, ShowSet, Smart, StyleChain, Styles,
@@ -16,7 +17,9 @@ use crate::layout::{
 };
 use crate::math::{MathSize, MathVariant};
 use crate::model::{Numbering, Outlinable, ParLine, Refable, Supplement};
-use crate::text::{FontFamily, FontList, FontWeight, LocalName, TextElem};
+use crate::text::{
+    check_font_list, FontFamily, FontList, FontWeight, LocalName, TextElem,
+};
 
 /// A mathematical equation.
 ///
@@ -101,6 +104,35 @@ pub struct EquationElem {
     /// ```
     pub supplement: Smart<Option<Supplement>>,
 
+    /// The font families to typeset math with, in order of preference.
+    ///
+    /// Only fonts with an OpenType MATH table can be used for math. Math
+    /// layout picks the first family from the list that has one. Since this
+    /// is independent from the [text font]($text.font), changing the font of
+    /// the surrounding text does not affect equations.
+    ///
+    /// Characters that the math font does not contain are taken from the
+    /// remaining families in the list.
+    ///
+    /// ```example
+    /// #set text(font: "Inria Serif")
+    /// #set math.equation(font: ("Fira Math", "New Computer Modern Math"))
+    ///
+    /// The area of a circle is
+    /// $A = pi r^2$.
+    /// ```
+    #[parse({
+        let font_list: Option<Spanned<FontList>> = args.named("font")?;
+        if let Some(list) = &font_list {
+            check_font_list(engine, list);
+            check_math_font_list(engine, list);
+        }
+        font_list.map(|font_list| font_list.v)
+    })]
+    #[default(FontList(vec![FontFamily::new("New Computer Modern Math")]))]
+    #[borrowed]
+    pub font: FontList,
+
     /// The contents of the equation.
     #[required]
     pub body: Content,
@@ -194,9 +226,7 @@ impl ShowSet for Packed<EquationElem> {
             out.set(EquationElem::set_size(MathSize::Text));
         }
         out.set(TextElem::set_weight(FontWeight::from_number(450)));
-        out.set(TextElem::set_font(FontList(vec![FontFamily::new(
-            "New Computer Modern Math",
-        )])));
+        out.set(TextElem::set_font(self.font(styles).clone()));
         out
     }
 }
@@ -248,3 +278,27 @@ impl Outlinable for Packed<EquationElem> {
         Content::empty()
     }
 }
+
+/// Checks for font families that exist, but can't be used for math.
+fn check_math_font_list(engine: &mut Engine, list: &Spanned<FontList>) {
+    let world = engine.world;
+    for family in &list.v {
+        let mut ids = world.book().select_family(family.as_str()).peekable();
+        if ids.peek().is_none() {
+            continue;
+        }
+
+        let has_math = ids.any(|id| {
+            world.font(id).is_some_and(|font| font.ttf().tables().math.is_some())
+        });
+
+        if !has_math {
+            engine.sink.warn(warning!(
+                list.span,
+                "font family {} has no MATH table",
+                family.as_str();
+                hint: "only fonts with a MATH table can be used to typeset math"
+            ));
+        }
+    }
+}
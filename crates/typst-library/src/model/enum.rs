@@ -172,6 +172,25 @@ pub struct EnumElem {
     /// (non-tight) enumerations.
     pub spacing: Smart<Length>,
 
+    /// The number of items up to which the enumeration is kept together.
+    ///
+    /// An enumeration with at most this many items is never broken across
+    /// pages or columns. Instead, it moves to the next page or column as a
+    /// whole if it doesn't fit. Longer enumerations can break between their
+    /// items as usual.
+    ///
+    /// ```example
+    /// >>> #set page(height: 70pt)
+    /// #set enum(keep: 3)
+    /// #lorem(8)
+    ///
+    /// + First
+    /// + Second
+    /// + Third
+    /// ```
+    #[default(0)]
+    pub keep: usize,
+
     /// The alignment that enum numbers should have.
     ///
     /// By default, this is set to `{end + top}`, which aligns enum numbers
@@ -253,10 +272,13 @@ impl Show for Packed<EnumElem> {
             return Ok(elem.with_body(Some(body)).pack().spanned(self.span()));
         }
 
-        let mut realized =
-            BlockElem::multi_layouter(self.clone(), engine.routines.layout_enum)
-                .pack()
-                .spanned(self.span());
+        let mut block =
+            BlockElem::multi_layouter(self.clone(), engine.routines.layout_enum);
+        if self.children.len() <= self.keep(styles) {
+            block.push_breakable(false);
+        }
+
+        let mut realized = block.pack().spanned(self.span());
 
         if tight {
             let leading = ParElem::leading_in(styles);
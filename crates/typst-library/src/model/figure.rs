@@ -16,11 +16,12 @@ use crate::introspection::{
     Count, Counter, CounterKey, CounterUpdate, Locatable, Location,
 };
 use crate::layout::{
-    AlignElem, Alignment, BlockBody, BlockElem, Em, HAlignment, Length, OuterVAlignment,
-    PlaceElem, PlacementScope, VAlignment, VElem,
+    AlignElem, Alignment, BlockBody, BlockElem, Em, GridElem, HAlignment, Length,
+    OuterVAlignment, PlaceElem, PlacementScope, Rel, Sides, VAlignment, VElem,
 };
 use crate::model::{
     Numbering, NumberingPattern, Outlinable, ParbreakElem, Refable, Supplement,
+    TableCell, TableChild, TableElem, TableFooter, TableHeader, TableItem,
 };
 use crate::text::{Lang, Region, TextElem};
 use crate::visualize::ImageElem;
@@ -336,18 +337,29 @@ impl Show for Packed<FigureElem> {
 
         // Build the caption, if any.
         if let Some(caption) = self.caption(styles).clone() {
-            let (first, second) = match caption.position(styles) {
-                OuterVAlignment::Top => (caption.pack(), realized),
-                OuterVAlignment::Bottom => (realized, caption.pack()),
-            };
-            let mut seq = Vec::with_capacity(3);
-            seq.push(first);
-            if !target.is_html() {
-                let v = VElem::new(self.gap(styles).into()).with_weak(true);
-                seq.push(v.pack().spanned(span))
+            let position = caption.position(styles);
+            let table = self
+                .body
+                .to_packed::<TableElem>()
+                .filter(|_| caption.repeat(styles) && !target.is_html());
+
+            if let Some(table) = table {
+                realized =
+                    repeat_caption(table, caption, position, self.gap(styles), styles);
+            } else {
+                let (first, second) = match position {
+                    OuterVAlignment::Top => (caption.pack(), realized),
+                    OuterVAlignment::Bottom => (realized, caption.pack()),
+                };
+                let mut seq = Vec::with_capacity(3);
+                seq.push(first);
+                if !target.is_html() {
+                    let v = VElem::new(self.gap(styles).into()).with_weak(true);
+                    seq.push(v.pack().spanned(span))
+                }
+                seq.push(second);
+                realized = Content::sequence(seq)
             }
-            seq.push(second);
-            realized = Content::sequence(seq)
         }
 
         // Ensure that the body is considered a paragraph.
@@ -386,6 +398,70 @@ impl Show for Packed<FigureElem> {
     }
 }
 
+/// Makes the caption a part of the table's header (or footer, for bottom
+/// captions), so that it is repeated on each page the table breaks across.
+fn repeat_caption(
+    table: &Packed<TableElem>,
+    caption: Packed<FigureCaption>,
+    position: OuterVAlignment,
+    gap: Length,
+    styles: StyleChain,
+) -> Content {
+    let columns = table.columns(styles).0.len().max(1);
+
+    // The caption spans the whole table and has no padding, fill, or stroke
+    // except at the side where it borders the table.
+    let zero = Some(Rel::zero());
+    let gap = Some(gap.into());
+    let (inset, stroke) = match position {
+        OuterVAlignment::Top => (
+            Sides::new(zero, zero, zero, gap),
+            Sides::new(Some(None), Some(None), Some(None), None),
+        ),
+        OuterVAlignment::Bottom => (
+            Sides::new(zero, gap, zero, zero),
+            Sides::new(Some(None), None, Some(None), Some(None)),
+        ),
+    };
+
+    let span = caption.span();
+    let cell = TableCell::new(caption.pack())
+        .with_colspan(NonZeroUsize::new(columns).unwrap())
+        .with_fill(Smart::Custom(None))
+        .with_inset(Smart::Custom(inset))
+        .with_stroke(stroke);
+    let item = TableItem::Cell(Packed::new(cell).spanned(span));
+
+    let mut table = table.clone();
+    let children = &mut table.children;
+    match position {
+        OuterVAlignment::Top => {
+            if let Some(header) = children.iter_mut().find_map(|child| match child {
+                TableChild::Header(header) => Some(header),
+                _ => None,
+            }) {
+                header.children.insert(0, item);
+            } else {
+                let header = TableHeader::new(vec![item]);
+                children.insert(0, TableChild::Header(Packed::new(header).spanned(span)));
+            }
+        }
+        OuterVAlignment::Bottom => {
+            if let Some(footer) = children.iter_mut().find_map(|child| match child {
+                TableChild::Footer(footer) => Some(footer),
+                _ => None,
+            }) {
+                footer.children.push(item);
+            } else {
+                let footer = TableFooter::new(vec![item]);
+                children.push(TableChild::Footer(Packed::new(footer).spanned(span)));
+            }
+        }
+    }
+
+    table.pack()
+}
+
 impl ShowSet for Packed<FigureElem> {
     fn show_set(&self, _: StyleChain) -> Styles {
         // Still allows breakable figures with
@@ -515,6 +591,41 @@ pub struct FigureCaption {
     /// ```
     pub separator: Smart<Content>,
 
+    /// Whether to repeat the caption on each page (or column) that a table in
+    /// the figure breaks across.
+    ///
+    /// The caption then becomes the first row of the table's
+    /// [header]($table.header) (or the last row of its
+    /// [footer]($table.footer), for bottom captions). Note that this shifts
+    /// the indices of the table's rows by one. This has no effect if the
+    /// figure's body isn't a table.
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #show figure: set block(breakable: true)
+    /// #show figure.where(kind: table): set figure.caption(
+    ///   position: top,
+    ///   repeat: true,
+    ///   continuation: [(continued)],
+    /// )
+    ///
+    /// #figure(
+    ///   table(
+    ///     columns: 2,
+    ///     ..range(8).map(n => (str(n), str(n * n))).flatten(),
+    ///   ),
+    ///   caption: [Squares],
+    /// )
+    /// ```
+    #[default(false)]
+    pub repeat: bool,
+
+    /// What to show instead of the separator and body when the caption is
+    /// repeated after a break.
+    ///
+    /// If `{none}`, the full caption is repeated.
+    pub continuation: Option<Content>,
+
     /// The caption's body.
     ///
     /// Can be used alongside `kind`, `supplement`, `counter`, `numbering`, and
@@ -590,7 +701,10 @@ impl Synthesize for Packed<FigureCaption> {
 impl Show for Packed<FigureCaption> {
     #[typst_macros::time(name = "figure.caption", span = self.span())]
     fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
-        let mut realized = self.body.clone();
+        // When repeated in a continued table, the body may be replaced.
+        let continuation =
+            self.continuation(styles).filter(|_| GridElem::continued_in(styles));
+        let mut realized = continuation.clone().unwrap_or_else(|| self.body.clone());
 
         if let (
             Some(Some(mut supplement)),
@@ -607,7 +721,12 @@ impl Show for Packed<FigureCaption> {
             if !supplement.is_empty() {
                 supplement += TextElem::packed('\u{a0}');
             }
-            realized = supplement + numbers + self.get_separator(styles) + realized;
+            let separator = if continuation.is_some() {
+                TextElem::packed(' ')
+            } else {
+                self.get_separator(styles)
+            };
+            realized = supplement + numbers + separator + realized;
         }
 
         Ok(if TargetElem::target_in(styles).is_html() {
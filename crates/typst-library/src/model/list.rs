@@ -113,6 +113,24 @@ pub struct ListElem {
     /// lists.
     pub spacing: Smart<Length>,
 
+    /// The number of items up to which the list is kept together.
+    ///
+    /// A list with at most this many items is never broken across pages or
+    /// columns. Instead, it moves to the next page or column as a whole if it
+    /// doesn't fit. Longer lists can break between their items as usual.
+    ///
+    /// ```example
+    /// >>> #set page(height: 70pt)
+    /// #set list(keep: 3)
+    /// #lorem(8)
+    ///
+    /// - First
+    /// - Second
+    /// - Third
+    /// ```
+    #[default(0)]
+    pub keep: usize,
+
     /// The bullet list's children.
     ///
     /// When using the list syntax, adjacent items are automatically collected
@@ -160,10 +178,13 @@ impl Show for Packed<ListElem> {
                 .spanned(self.span()));
         }
 
-        let mut realized =
-            BlockElem::multi_layouter(self.clone(), engine.routines.layout_list)
-                .pack()
-                .spanned(self.span());
+        let mut block =
+            BlockElem::multi_layouter(self.clone(), engine.routines.layout_list);
+        if self.children.len() <= self.keep(styles) {
+            block.push_breakable(false);
+        }
+
+        let mut realized = block.pack().spanned(self.span());
 
         if tight {
             let leading = ParElem::leading_in(styles);
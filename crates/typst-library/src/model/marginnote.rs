@@ -0,0 +1,120 @@
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, Content, NativeElement, Packed, Show, Smart, StyleChain, Styles, Synthesize,
+    TargetElem,
+};
+use crate::html::{attr, tag, HtmlElem};
+use crate::introspection::Locatable;
+use crate::layout::{Em, FloatSide, Length};
+
+/// A note in the page margin.
+///
+/// Margin notes (also known as sidenotes) are placed next to the line in which
+/// they appear, but in the page's left or right margin. This is common in
+/// Tufte-style documents and legal texts, where remarks should be visible
+/// without interrupting the flow of the main text.
+///
+/// The first line of a note is aligned with the line in which the note appears.
+/// When multiple notes would overlap, they are stacked below each other. If a
+/// note doesn't fit into the margin of its page anymore, it moves to the top of
+/// the margin on the next page. Pages with an `{auto}` height grow to fit
+/// their notes instead.
+///
+/// In HTML export, a note stays in the text as a `span` element with the class
+/// `marginnote`, which can be moved into the margin with CSS.
+///
+/// # Example
+/// ```example
+/// >>> #set page(width: 240pt, height: 120pt, margin: (right: 80pt))
+/// #set marginnote(side: right)
+/// #show marginnote: set text(0.8em)
+///
+/// The first draft of the
+/// treaty #marginnote[Signed in 1648.]
+/// was written in Latin and
+/// later translated.
+/// ```
+///
+/// Like footnotes, margin notes can be [queried]($query):
+///
+/// ```example
+/// >>> #set page(width: 240pt, height: 120pt, margin: (right: 80pt))
+/// #set marginnote(side: right)
+/// Yes #marginnote[First]
+/// and no. #marginnote[Second]
+///
+/// #context [
+///   There are #query(marginnote).len()
+///   margin notes.
+/// ]
+/// ```
+#[elem(name = "marginnote", title = "Margin Note", Locatable, Synthesize, Show)]
+pub struct MarginNoteElem {
+    /// In which margin to put the note.
+    ///
+    /// Can be `{start}`, `{end}`, `{left}`, or `{right}`. The sides `{"inside"}`
+    /// and `{"outside"}` refer to the margin next to and opposite of the
+    /// page's [binding]($page.binding), which alternates between left and
+    /// right for two-sided documents.
+    ///
+    /// ```example
+    /// >>> #set page(width: 240pt, height: 80pt, margin: (x: 70pt))
+    /// Left #marginnote(side: left)[Note]
+    /// and right #marginnote(side: right)[Note]
+    /// ```
+    #[default(FloatSide::Outside)]
+    pub side: FloatSide,
+
+    /// The distance between the note and the text area as well as between the
+    /// note and the edge of the page.
+    #[default(Em::new(1.0).into())]
+    pub gap: Length,
+
+    /// The width of the note. If `{auto}`, the note takes up the width of the
+    /// margin minus the gap on both sides.
+    pub width: Smart<Length>,
+
+    /// The minimum vertical spacing between two stacked notes.
+    #[default(Em::new(0.5).into())]
+    pub spacing: Length,
+
+    /// An additional vertical offset for the note relative to its anchor
+    /// line.
+    pub dy: Length,
+
+    /// The content of the note.
+    #[required]
+    pub body: Content,
+
+    /// The styles active where the note was created. The note is laid out with
+    /// these at the page level.
+    #[internal]
+    #[synthesized]
+    pub styles: Styles,
+}
+
+impl Synthesize for Packed<MarginNoteElem> {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        self.push_styles(styles.to_map());
+        Ok(())
+    }
+}
+
+impl Show for Packed<MarginNoteElem> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        // HTML has no page margins, so the note stays in the text and can be
+        // moved aside with CSS.
+        if TargetElem::target_in(styles).is_html() {
+            return Ok(HtmlElem::new(tag::span)
+                .with_attr(attr::class, "marginnote")
+                .with_body(Some(self.body.clone()))
+                .pack()
+                .spanned(self.span()));
+        }
+
+        // The note itself is laid out in the page margin. In the text, it
+        // leaves behind only its introspection tags as an anchor.
+        Ok(Content::empty())
+    }
+}
@@ -11,6 +11,7 @@ mod footnote;
 mod heading;
 mod link;
 mod list;
+mod marginnote;
 #[path = "numbering.rs"]
 mod numbering_;
 mod outline;
@@ -31,6 +32,7 @@ pub use self::footnote::*;
 pub use self::heading::*;
 pub use self::link::*;
 pub use self::list::*;
+pub use self::marginnote::*;
 pub use self::numbering_::*;
 pub use self::outline::*;
 pub use self::par::*;
@@ -52,6 +54,7 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<HeadingElem>();
     global.define_elem::<FigureElem>();
     global.define_elem::<FootnoteElem>();
+    global.define_elem::<MarginNoteElem>();
     global.define_elem::<QuoteElem>();
     global.define_elem::<CiteElem>();
     global.define_elem::<BibliographyElem>();
@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+
 use typst_utils::singleton;
 
 use crate::diag::{bail, SourceResult};
@@ -138,6 +140,27 @@ pub struct ParElem {
     #[default(false)]
     pub justify: bool,
 
+    /// Whether to justify Arabic text by elongating the connections between
+    /// letters with kashidas.
+    ///
+    /// Only has an effect on [justified]($par.justify) paragraphs. The
+    /// kashidas take up as much of the extra space of each line as they can
+    /// before spaces are stretched. Each Arabic word is elongated at most at
+    /// one place, which is chosen according to the traditional priorities:
+    /// For instance, connections after seen and sad are preferred over those
+    /// before the final form of heh, dal, or teh marbuta.
+    ///
+    /// ```example
+    /// #set page(width: 150pt)
+    /// #set par(justify: true, kashida: true)
+    /// #set text(lang: "ar", font: "Noto Sans Arabic")
+    ///
+    /// السلام عليكم ورحمة الله وبركاته،
+    /// كيف حالك اليوم يا صديقي العزيز؟
+    /// ```
+    #[default(false)]
+    pub kashida: bool,
+
     /// How to determine line breaks.
     ///
     /// When this property is set to `{auto}`, its default value, optimized line
@@ -216,6 +239,33 @@ pub struct ParElem {
     #[resolve]
     pub hanging_indent: Length,
 
+    /// The minimum number of lines of a paragraph that must stay together at
+    /// the end of a page or column when the paragraph breaks across it.
+    ///
+    /// If fewer lines fit, the whole paragraph moves to the next page or
+    /// column. Paragraphs that are shorter than the combined `orphans` and
+    /// [`widows`]($par.widows) are kept together entirely. A value of `{1}`
+    /// allows single lines. This has no effect if the
+    /// [orphan cost]($text.costs) is `{0%}`.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #set par(orphans: 3)
+    /// #v(20pt)
+    /// #lorem(20)
+    /// ```
+    #[default(NonZeroUsize::new(2).unwrap())]
+    pub orphans: NonZeroUsize,
+
+    /// The minimum number of lines of a paragraph that must stay together at
+    /// the start of a page or column when the paragraph breaks across it.
+    ///
+    /// If fewer lines would be left for the next page or column, additional
+    /// lines are moved there. A value of `{1}` allows single lines. This has
+    /// no effect if the [widow cost]($text.costs) is `{0%}`.
+    #[default(NonZeroUsize::new(2).unwrap())]
+    pub widows: NonZeroUsize,
+
     /// The contents of the paragraph.
     #[required]
     pub body: Content,
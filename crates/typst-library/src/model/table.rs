@@ -1,21 +1,22 @@
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 
+use comemo::Tracked;
 use typst_utils::NonZeroExt;
 
 use crate::diag::{bail, HintedStrResult, HintedString, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Content, NativeElement, Packed, Show, Smart, StyleChain,
+    cast, elem, scope, Content, Context, NativeElement, Packed, Show, Smart, StyleChain,
     TargetElem,
 };
 use crate::html::{attr, tag, HtmlAttrs, HtmlElem, HtmlTag};
 use crate::introspection::Locator;
 use crate::layout::grid::resolve::{table_to_cellgrid, Cell, CellGrid, Entry};
 use crate::layout::{
-    show_grid_cell, Abs, Alignment, BlockElem, Celled, GridCell, GridFooter, GridHLine,
-    GridHeader, GridVLine, Length, OuterHAlignment, OuterVAlignment, Rel, Sides,
-    TrackSizings,
+    show_grid_cell, Abs, Alignment, Angle, BlockElem, Celled, ColumnFit, GridCell,
+    GridElem, GridFooter, GridHLine, GridHeader, GridVLine, Length, OuterHAlignment,
+    OuterVAlignment, Rel, Sides, TrackBounds, TrackSizings,
 };
 use crate::model::Figurable;
 use crate::text::LocalName;
@@ -154,6 +155,30 @@ pub struct TableElem {
     #[borrowed]
     pub row_gutter: TrackSizings,
 
+    /// How `{auto}` columns share the available width when their contents
+    /// don't fit next to each other. See the [grid
+    /// documentation]($grid.column-fit) for more information.
+    ///
+    /// ```example
+    /// #set page(width: 160pt)
+    /// #table(
+    ///   columns: 2,
+    ///   column-fit: "content",
+    ///   [Incomprehensibilities],
+    ///   lorem(8),
+    /// )
+    /// ```
+    #[default(ColumnFit::Fair)]
+    pub column_fit: ColumnFit,
+
+    /// The minimum widths of `{auto}` columns. Either a single width for all
+    /// columns or an array with a width per column.
+    pub column_min: TrackBounds,
+
+    /// The maximum widths of `{auto}` columns. Takes the same values as
+    /// `column-min`.
+    pub column_max: TrackBounds,
+
     /// How to fill the cells.
     ///
     /// This can be a color or a function that returns a color. The function
@@ -260,6 +285,50 @@ impl TableElem {
 
     #[elem]
     type TableFooter;
+
+    /// Whether the table was broken before the region (e.g. the page) that it
+    /// is currently laid out in.
+    ///
+    /// Within a repeated [header]($table.header) or [footer]($table.footer),
+    /// this can be used to mark the table as continued. Everywhere else, it is
+    /// `{false}`.
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #table(
+    ///   columns: 2,
+    ///   table.header(
+    ///     [*Key*],
+    ///     [*Value* #context if table.continued() [(cont.)]],
+    ///   ),
+    ///   ..range(8).map(n => (str(n), str(n * n))).flatten(),
+    /// )
+    /// ```
+    #[func(contextual)]
+    pub fn continued(context: Tracked<Context>) -> HintedStrResult<bool> {
+        Ok(GridElem::continued_in(context.styles()?))
+    }
+
+    /// Whether the table continues in the next region (e.g. on the next page).
+    ///
+    /// Within a repeated [footer]($table.footer), this can be used to point
+    /// readers to the rest of the table. Since this isn't known yet when a
+    /// header is laid out, it is `{auto}` in headers and everywhere else.
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #table(
+    ///   columns: 2,
+    ///   ..range(8).map(n => (str(n), str(n * n))).flatten(),
+    ///   table.footer(table.cell(colspan: 2, context {
+    ///     if table.continues() == true [_Continued on next page_]
+    ///   })),
+    /// )
+    /// ```
+    #[func(contextual)]
+    pub fn continues(context: Tracked<Context>) -> HintedStrResult<Smart<bool>> {
+        Ok(GridElem::continues_in(context.styles()?))
+    }
 }
 
 fn show_cell_html(tag: HtmlTag, cell: &Cell, styles: StyleChain) -> Content {
@@ -755,6 +824,28 @@ pub struct TableCell {
     /// unbreakable, while a cell spanning at least one `{auto}`-sized row is
     /// breakable.
     pub breakable: Smart<bool>,
+
+    /// The angle by which the cell's content is rotated. Functions
+    /// identically to the [`rotate`]($grid.cell.rotate) field in
+    /// [`grid.cell`]($grid.cell).
+    ///
+    /// This is useful for the headers of wide tables:
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 4,
+    ///   align: center + horizon,
+    ///   table.header(
+    ///     [Country],
+    ///     ..([Population], [Area], [GDP])
+    ///       .map(table.cell.with(rotate: -90deg)),
+    ///   ),
+    ///   [France], [68M], [644k], [3.0T],
+    ///   [Japan], [124M], [378k], [4.2T],
+    /// )
+    /// ```
+    #[default(Angle::zero())]
+    pub rotate: Angle,
 }
 
 cast! {
@@ -764,7 +855,12 @@ cast! {
 
 impl Show for Packed<TableCell> {
     fn show(&self, _engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
-        show_grid_cell(self.body.clone(), self.inset(styles), self.align(styles))
+        show_grid_cell(
+            self.body.clone(),
+            self.inset(styles),
+            self.align(styles),
+            self.rotate(styles),
+        )
     }
 }
 
@@ -4,9 +4,11 @@ pub mod color;
 
 mod book;
 mod exceptions;
+mod report;
 mod variant;
 
 pub use self::book::{Coverage, FontBook, FontFlags, FontInfo};
+pub use self::report::FontReport;
 pub use self::variant::{FontStretch, FontStyle, FontVariant, FontWeight};
 
 use std::cell::OnceCell;
@@ -14,7 +16,8 @@ use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use ttf_parser::GlyphId;
+use ttf_parser::{GlyphId, Tag};
+use typst_utils::Scalar;
 
 use self::book::find_name;
 use crate::foundations::{Bytes, Cast};
@@ -35,6 +38,11 @@ struct Repr {
     info: FontInfo,
     /// The font's metrics.
     metrics: FontMetrics,
+    /// The variation axis coordinates this font is instanced at. Empty for
+    /// the default instance.
+    variations: Vec<(Tag, Scalar)>,
+    /// The default instance this font was instanced from, if any.
+    base: Option<Font>,
     /// The underlying ttf-parser face.
     ttf: ttf_parser::Face<'static>,
     /// The underlying rustybuzz face.
@@ -66,7 +74,81 @@ impl Font {
         let metrics = FontMetrics::from_ttf(&ttf);
         let info = FontInfo::from_ttf(&ttf)?;
 
-        Some(Self(Arc::new(Repr { data, index, info, metrics, ttf, rusty })))
+        Some(Self(Arc::new(Repr {
+            data,
+            index,
+            info,
+            metrics,
+            variations: vec![],
+            base: None,
+            ttf,
+            rusty,
+        })))
+    }
+
+    /// Instance this font for the given variant and variation axis
+    /// coordinates.
+    ///
+    /// The `wght` and `wdth` axes are only set from the variant's weight and
+    /// stretch if the font's default instance doesn't already have them. The
+    /// given coordinates take precedence over these. Coordinates for axes the
+    /// font doesn't have are ignored and the others are clamped to the axes'
+    /// ranges. If no coordinate applies or all match the defaults, the default
+    /// instance is returned.
+    pub fn instance(&self, variant: FontVariant, variations: &[(Tag, Scalar)]) -> Font {
+        let base = self.base();
+        let axes = base.ttf().variation_axes();
+        if axes.is_empty() {
+            return base.clone();
+        }
+
+        let own = base.info().variant;
+        let mut requested = vec![];
+        if variant.weight != own.weight {
+            let weight = Scalar::new(variant.weight.to_number() as f64);
+            requested.push((Tag::from_bytes(b"wght"), weight));
+        }
+        if variant.stretch != own.stretch {
+            let stretch = Scalar::new(variant.stretch.to_ratio().get() * 100.0);
+            requested.push((Tag::from_bytes(b"wdth"), stretch));
+        }
+        requested.extend_from_slice(variations);
+
+        let mut coords: Vec<(Tag, Scalar)> = vec![];
+        for (tag, value) in requested {
+            let Some(axis) = axes.into_iter().find(|axis| axis.tag == tag) else {
+                continue;
+            };
+            let value = value.get().max(axis.min_value as f64).min(axis.max_value as f64);
+            coords.retain(|(t, _)| *t != tag);
+            if value != axis.def_value as f64 {
+                coords.push((tag, Scalar::new(value)));
+            }
+        }
+
+        coords.sort_by_key(|(tag, _)| *tag);
+        if coords.is_empty() {
+            return base.clone();
+        } else if coords == self.0.variations {
+            return self.clone();
+        }
+
+        instance_impl(base.clone(), coords)
+    }
+
+    /// The default instance of this font.
+    ///
+    /// This is the font itself unless it was created with
+    /// [`instance`](Self::instance).
+    pub fn base(&self) -> &Font {
+        self.0.base.as_ref().unwrap_or(self)
+    }
+
+    /// The variation axis coordinates this font is instanced at.
+    ///
+    /// Empty for fonts that are not variable or use the default instance.
+    pub fn variations(&self) -> &[(Tag, Scalar)] {
+        &self.0.variations
     }
 
     /// Parse all fonts in the given data.
@@ -175,10 +257,52 @@ impl Font {
     }
 }
 
+/// Instance a font at the given variation axis coordinates.
+#[comemo::memoize]
+fn instance_impl(font: Font, variations: Vec<(Tag, Scalar)>) -> Font {
+    let data = font.0.data.clone();
+    let index = font.0.index;
+
+    // Safety: See `Font::new`.
+    let slice: &'static [u8] =
+        unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) };
+
+    let (Ok(mut ttf), Some(mut rusty)) = (
+        ttf_parser::Face::parse(slice, index),
+        rustybuzz::Face::from_slice(slice, index),
+    ) else {
+        return font;
+    };
+
+    for &(tag, value) in &variations {
+        ttf.set_variation(tag, value.get() as f32);
+    }
+
+    let coords: Vec<_> = variations
+        .iter()
+        .map(|&(tag, value)| rustybuzz::Variation { tag, value: value.get() as f32 })
+        .collect();
+    rusty.set_variations(&coords);
+
+    let metrics = FontMetrics::from_ttf(&ttf);
+    let info = font.0.info.clone();
+    Font(Arc::new(Repr {
+        data,
+        index,
+        info,
+        metrics,
+        variations,
+        base: Some(font),
+        ttf,
+        rusty,
+    }))
+}
+
 impl Hash for Font {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.0.data.hash(state);
         self.0.index.hash(state);
+        self.0.variations.hash(state);
     }
 }
 
@@ -192,7 +316,9 @@ impl Eq for Font {}
 
 impl PartialEq for Font {
     fn eq(&self, other: &Self) -> bool {
-        self.0.data == other.0.data && self.0.index == other.0.index
+        self.0.data == other.0.data
+            && self.0.index == other.0.index
+            && self.0.variations == other.0.variations
     }
 }
 
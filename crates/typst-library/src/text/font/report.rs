@@ -0,0 +1,140 @@
+use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
+
+use ecow::{eco_format, EcoString, EcoVec};
+use indexmap::IndexMap;
+use typst_syntax::Spanned;
+
+use crate::diag::{warning, Warned};
+use crate::foundations::repr::separated_list;
+use crate::layout::{Frame, FrameItem, PagedDocument};
+use crate::text::{Font, TextItem};
+
+/// Which fonts were used for which characters of a laid-out document.
+///
+/// Produced after compilation to find out where font fallback kicked in and
+/// which text could not be displayed at all.
+#[derive(Debug, Clone, Default)]
+pub struct FontReport {
+    /// The fonts that were used, in order of first use, together with the
+    /// ranges of characters each of them displayed.
+    pub fonts: Vec<(Font, Vec<RangeInclusive<char>>)>,
+    /// Runs of characters that no font had a glyph for, together with the
+    /// span of the text they stem from.
+    pub missing_glyphs: Vec<Spanned<EcoString>>,
+    /// Requested font families that are not part of the font book, together
+    /// with the span of the font list that requested them.
+    pub missing_families: Vec<Spanned<EcoString>>,
+}
+
+impl FontReport {
+    /// Build a report for a document.
+    ///
+    /// The `unknown_families` are the requested families that were not
+    /// available during compilation. The returned warnings point at every
+    /// piece of text that could not be displayed.
+    pub fn new(
+        document: &PagedDocument,
+        unknown_families: EcoVec<Spanned<EcoString>>,
+    ) -> Warned<Self> {
+        let mut used = IndexMap::<Font, BTreeSet<char>>::new();
+        let mut missing = vec![];
+        for page in &document.pages {
+            collect(&page.frame, &mut used, &mut missing);
+        }
+
+        let fonts = used.into_iter().map(|(font, chars)| (font, ranges(chars))).collect();
+
+        let mut diags = EcoVec::new();
+        for Spanned { v: text, span } in &missing {
+            let mut chars: Vec<char> = text.chars().collect();
+            chars.sort_unstable();
+            chars.dedup();
+            let list: Vec<_> = chars
+                .iter()
+                .map(|c| eco_format!("{c:?} (U+{:04X})", *c as u32))
+                .collect();
+            diags.push(warning!(
+                *span,
+                "no font contains a glyph for {}",
+                separated_list(&list, "and");
+                hint: "add a font that covers these characters to the font list"
+            ));
+        }
+
+        Warned {
+            output: Self {
+                fonts,
+                missing_glyphs: missing,
+                missing_families: unknown_families.into_iter().collect(),
+            },
+            warnings: diags,
+        }
+    }
+}
+
+/// Collect the used characters per font and the runs of missing glyphs in a
+/// frame.
+fn collect(
+    frame: &Frame,
+    used: &mut IndexMap<Font, BTreeSet<char>>,
+    missing: &mut Vec<Spanned<EcoString>>,
+) {
+    for (_, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => collect(&group.frame, used, missing),
+            FrameItem::Text(text) => collect_text(text, used, missing),
+            _ => {}
+        }
+    }
+}
+
+/// Collect the used characters and missing glyphs of a single text item.
+fn collect_text(
+    text: &TextItem,
+    used: &mut IndexMap<Font, BTreeSet<char>>,
+    missing: &mut Vec<Spanned<EcoString>>,
+) {
+    let mut run: Option<Spanned<EcoString>> = None;
+    for glyph in &text.glyphs {
+        let chars = &text.text[glyph.range()];
+        if glyph.id == 0 {
+            let run =
+                run.get_or_insert_with(|| Spanned::new(EcoString::new(), glyph.span.0));
+            run.v.extend(chars.chars().filter(|c| !c.is_whitespace()));
+            continue;
+        }
+
+        missing.extend(run.take().filter(|run| !run.v.is_empty()));
+        used.entry(text.font.clone())
+            .or_default()
+            .extend(chars.chars().filter(|c| !c.is_control()));
+    }
+
+    missing.extend(run.filter(|run| !run.v.is_empty()));
+}
+
+/// Merge a set of characters into ranges of consecutive code points.
+fn ranges(chars: BTreeSet<char>) -> Vec<RangeInclusive<char>> {
+    let mut ranges: Vec<RangeInclusive<char>> = vec![];
+    for c in chars {
+        match ranges.last_mut() {
+            Some(range) if *range.end() as u32 + 1 == c as u32 => {
+                *range = *range.start()..=c;
+            }
+            _ => ranges.push(c..=c),
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_report_ranges() {
+        let chars = "abcxyz019".chars().collect();
+        assert_eq!(ranges(chars), ['0'..='1', '9'..='9', 'a'..='c', 'x'..='z']);
+    }
+}
@@ -0,0 +1,408 @@
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use comemo::Tracked;
+use ecow::{eco_format, EcoString};
+use typst_syntax::Spanned;
+use typst_utils::ManuallyHash;
+
+use crate::diag::{bail, At, FileError, HintedString, SourceResult, StrResult};
+use crate::foundations::{
+    cast, dict, Bytes, CastInfo, Derived, Dict, Fold, IntoValue, Reflect, Value,
+};
+use crate::loading::{DataSource, Load};
+use crate::text::Lang;
+use crate::World;
+
+/// Custom hyphenation patterns for specific languages.
+///
+/// Patterns are updated per language (prioritizing the later value) when
+/// folded.
+#[derive(Debug, Default, Clone, PartialEq, Hash)]
+pub struct LangPatterns(Vec<(Lang, Option<Derived<DataSource, HyphenationPatterns>>)>);
+
+impl LangPatterns {
+    /// Load the patterns for each language in a dictionary from language
+    /// codes to data sources.
+    pub fn load(
+        world: Tracked<dyn World + '_>,
+        dict: Spanned<Dict>,
+    ) -> SourceResult<Self> {
+        let mut list = vec![];
+        for (key, value) in dict.v {
+            let lang = Lang::from_str(&key).at(dict.span)?;
+            let source: Option<DataSource> = value.cast().at(dict.span)?;
+            let patterns = source
+                .map(|source| {
+                    HyphenationPatterns::load(world, Spanned::new(source, dict.span))
+                })
+                .transpose()?;
+            list.push((lang, patterns));
+        }
+        Ok(Self(list))
+    }
+
+    /// The custom patterns for a language, if any.
+    pub fn get(&self, lang: Lang) -> Option<&HyphenationPatterns> {
+        self.0
+            .iter()
+            .find(|(l, _)| *l == lang)
+            .and_then(|(_, patterns)| patterns.as_ref())
+            .map(|patterns| &patterns.derived)
+    }
+}
+
+impl Fold for LangPatterns {
+    fn fold(self, outer: Self) -> Self {
+        let mut list = outer.0;
+        list.retain(|(lang, _)| self.0.iter().all(|(l, _)| l != lang));
+        list.extend(self.0);
+        Self(list)
+    }
+}
+
+impl Reflect for LangPatterns {
+    fn input() -> CastInfo {
+        Dict::input()
+    }
+
+    fn output() -> CastInfo {
+        Dict::output()
+    }
+
+    fn castable(value: &Value) -> bool {
+        Dict::castable(value)
+    }
+
+    fn error(found: &Value) -> HintedString {
+        Dict::error(found)
+    }
+}
+
+impl IntoValue for LangPatterns {
+    fn into_value(self) -> Value {
+        self.0
+            .into_iter()
+            .map(|(lang, patterns)| (lang.as_str().into(), patterns.into_value()))
+            .collect::<Dict>()
+            .into_value()
+    }
+}
+
+/// Hyphenation patterns loaded from a TeX pattern file.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct HyphenationPatterns(Arc<ManuallyHash<PatternSet>>);
+
+/// The parsed contents of a pattern file.
+#[derive(Debug)]
+struct PatternSet {
+    /// Maps from the letters of a pattern to its levels. A pattern with `n`
+    /// letters has `n + 1` levels.
+    patterns: HashMap<EcoString, Vec<u8>>,
+    /// The number of letters in the longest pattern.
+    max_len: usize,
+    /// The hyphenated words from the file's `\hyphenation` list.
+    exceptions: Vec<EcoString>,
+}
+
+impl HyphenationPatterns {
+    /// Load patterns from a data source.
+    pub fn load(
+        world: Tracked<dyn World + '_>,
+        source: Spanned<DataSource>,
+    ) -> SourceResult<Derived<DataSource, Self>> {
+        let data = source.load(world)?;
+        let patterns = Self::decode(&source.v, &data).at(source.span)?;
+        Ok(Derived::new(source.v, patterns))
+    }
+
+    /// Decode patterns from a loaded source.
+    ///
+    /// Accepts a plain whitespace-separated list of patterns as well as TeX
+    /// files with `\patterns{...}` and `\hyphenation{...}` groups.
+    #[comemo::memoize]
+    #[typst_macros::time(name = "load hyphenation patterns")]
+    fn decode(source: &DataSource, data: &Bytes) -> StrResult<HyphenationPatterns> {
+        let failed = |err: EcoString| match source {
+            DataSource::Path(path) => {
+                eco_format!("failed to parse hyphenation patterns `{path}` ({err})")
+            }
+            DataSource::Bytes(_) => {
+                eco_format!("failed to parse hyphenation patterns ({err})")
+            }
+        };
+
+        let src = data.as_str().map_err(FileError::from)?;
+        let src: String = src
+            .lines()
+            .map(|line| line.split('%').next().unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut set = PatternSet {
+            patterns: HashMap::new(),
+            max_len: 0,
+            exceptions: vec![],
+        };
+
+        let (patterns, exceptions) = if src.contains('\\') {
+            (group(&src, "\\patterns"), group(&src, "\\hyphenation"))
+        } else {
+            (Some(src.as_str()), None)
+        };
+
+        // Nested braces only group patterns, so they are skipped like
+        // whitespace.
+        let is_separator = |c: char| c.is_whitespace() || c == '{' || c == '}';
+        let tokens = |group: Option<&str>| {
+            group
+                .unwrap_or_default()
+                .split(is_separator)
+                .filter(|token| !token.is_empty())
+                .map(EcoString::from)
+                .collect::<Vec<_>>()
+        };
+
+        for token in tokens(patterns) {
+            let mut letters = EcoString::new();
+            let mut levels = vec![0];
+            for c in token.chars() {
+                if let Some(digit) = c.to_digit(10) {
+                    *levels.last_mut().unwrap() = digit as u8;
+                } else if c.is_alphabetic() || c == '.' || c == '\'' {
+                    letters.push(c);
+                    levels.push(0);
+                } else {
+                    return Err(failed(eco_format!("invalid pattern `{token}`")));
+                }
+            }
+
+            if letters.is_empty() {
+                return Err(failed(eco_format!("invalid pattern `{token}`")));
+            }
+
+            set.max_len = set.max_len.max(letters.chars().count());
+            set.patterns.insert(letters, levels);
+        }
+
+        set.exceptions = tokens(exceptions);
+
+        Ok(HyphenationPatterns(Arc::new(ManuallyHash::new(
+            set,
+            typst_utils::hash128(data),
+        ))))
+    }
+
+    /// Determine the hyphenation opportunities in a word as byte offsets.
+    ///
+    /// At least `left` characters remain before and `right` characters after
+    /// each opportunity.
+    pub fn hyphenate(&self, word: &str, left: usize, right: usize) -> Vec<usize> {
+        let set: &PatternSet = &self.0;
+        if let Some(points) = set
+            .exceptions
+            .iter()
+            .find_map(|entry| hyphenation_exception(entry, word))
+        {
+            return points;
+        }
+
+        // Liang's algorithm: Apply the levels of all patterns that occur in
+        // the word delimited by dots. Odd levels allow a break.
+        let chars: Vec<char> = std::iter::once('.')
+            .chain(word.chars().flat_map(char::to_lowercase))
+            .chain(std::iter::once('.'))
+            .collect();
+
+        // Lowercasing may change the number of chars, in which case the
+        // levels can't be mapped back onto the word.
+        let count = word.chars().count();
+        if chars.len() != count + 2 {
+            return vec![];
+        }
+
+        let mut levels = vec![0; chars.len() + 1];
+        for start in 0..chars.len() {
+            let mut key = EcoString::new();
+            for &c in chars[start..].iter().take(set.max_len) {
+                key.push(c);
+                if let Some(pattern) = set.patterns.get(&key) {
+                    for (level, &value) in levels[start..].iter_mut().zip(pattern) {
+                        *level = (*level).max(value);
+                    }
+                }
+            }
+        }
+
+        // The opportunity after the `i`-th char of the word is at level
+        // index `i + 1` due to the leading dot.
+        word.char_indices()
+            .enumerate()
+            .skip(left.max(1))
+            .take_while(|&(i, _)| i + right.max(1) <= count)
+            .filter(|&(i, _)| levels[i + 1] % 2 == 1)
+            .map(|(_, (offset, _))| offset)
+            .collect()
+    }
+}
+
+/// Extract the contents of a TeX group like `\patterns{...}`, which may
+/// contain nested groups.
+fn group<'a>(src: &'a str, command: &str) -> Option<&'a str> {
+    let start = src.find(command)? + command.len();
+    let rest = src[start..].trim_start().strip_prefix('{')?;
+    let mut depth = 0;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(&rest[..i]),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Determine the hyphenation opportunities in a word as byte offsets if the
+/// given hyphenated exception entry (like `data-base`) matches it.
+///
+/// Matching ignores case. An entry without hyphens matches, too, and
+/// prevents the word from being hyphenated at all.
+pub fn hyphenation_exception(entry: &str, word: &str) -> Option<Vec<usize>> {
+    let mut chars = word.char_indices();
+    let mut points = vec![];
+    for c in entry.chars() {
+        if c == '-' {
+            points.push(chars.clone().next().map_or(word.len(), |(i, _)| i));
+            continue;
+        }
+
+        let (_, w) = chars.next()?;
+        if !w.to_lowercase().eq(c.to_lowercase()) {
+            return None;
+        }
+    }
+
+    if chars.next().is_some() {
+        return None;
+    }
+
+    points.retain(|&i| i > 0 && i < word.len());
+    points.dedup();
+    Some(points)
+}
+
+/// How many characters must at least remain before and after a hyphen, for
+/// all languages and for specific ones.
+///
+/// Limits are updated (prioritizing the later value) when folded.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct HyphenationLimits {
+    /// The limits for all languages.
+    all: Bounds,
+    /// The limits for specific languages, which take precedence.
+    langs: Vec<(Lang, Bounds)>,
+}
+
+impl HyphenationLimits {
+    /// Resolve the limits for a language, falling back to the given language
+    /// defaults.
+    #[must_use]
+    pub fn resolve(&self, lang: Lang, (left, right): (usize, usize)) -> (usize, usize) {
+        let specific = self
+            .langs
+            .iter()
+            .find(|(l, _)| *l == lang)
+            .map(|&(_, bounds)| bounds)
+            .unwrap_or_default();
+        let bounds = specific.fold(self.all);
+        (
+            bounds.left.map_or(left, NonZeroUsize::get),
+            bounds.right.map_or(right, NonZeroUsize::get),
+        )
+    }
+}
+
+impl Fold for HyphenationLimits {
+    fn fold(self, outer: Self) -> Self {
+        let mut langs = outer.langs;
+        for (lang, bounds) in self.langs {
+            match langs.iter_mut().find(|(l, _)| *l == lang) {
+                Some((_, outer)) => *outer = bounds.fold(*outer),
+                None => langs.push((lang, bounds)),
+            }
+        }
+        Self { all: self.all.fold(outer.all), langs }
+    }
+}
+
+cast! {
+    HyphenationLimits,
+    self => {
+        let mut dict = self.all.into_dict();
+        for (lang, bounds) in self.langs {
+            dict.insert(lang.as_str().into(), bounds.into_dict().into_value());
+        }
+        dict.into_value()
+    },
+    mut v: Dict => {
+        let all = Bounds {
+            left: v.take("left").ok().map(|v| v.cast()).transpose()?,
+            right: v.take("right").ok().map(|v| v.cast()).transpose()?,
+        };
+        let mut langs = vec![];
+        for (key, value) in v {
+            let Ok(lang) = Lang::from_str(&key) else {
+                bail!(
+                    "unexpected key \"{}\", valid keys are \"left\", \"right\", \
+                     and language codes",
+                    key.as_str(),
+                );
+            };
+            langs.push((lang, value.cast()?));
+        }
+        Self { all, langs }
+    },
+}
+
+/// How many characters must at least remain before and after a hyphen.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+struct Bounds {
+    left: Option<NonZeroUsize>,
+    right: Option<NonZeroUsize>,
+}
+
+impl Bounds {
+    /// Turn the bounds into a dictionary with `left` and `right` keys.
+    fn into_dict(self) -> Dict {
+        dict! {
+            "left" => self.left,
+            "right" => self.right,
+        }
+    }
+}
+
+impl Fold for Bounds {
+    #[inline]
+    fn fold(self, outer: Self) -> Self {
+        Self {
+            left: self.left.or(outer.left),
+            right: self.right.or(outer.right),
+        }
+    }
+}
+
+cast! {
+    Bounds,
+    self => self.into_dict().into_value(),
+    mut v: Dict => {
+        let ret = Self {
+            left: v.take("left").ok().map(|v| v.cast()).transpose()?,
+            right: v.take("right").ok().map(|v| v.cast()).transpose()?,
+        };
+        v.finish(&["left", "right"])?;
+        ret
+    },
+}
@@ -3,12 +3,14 @@
 mod case;
 mod deco;
 mod font;
+mod hyphenation;
 mod item;
 mod lang;
 mod linebreak;
 #[path = "lorem.rs"]
 mod lorem_;
 mod raw;
+mod ruby;
 mod shift;
 #[path = "smallcaps.rs"]
 mod smallcaps_;
@@ -18,11 +20,13 @@ mod space;
 pub use self::case::*;
 pub use self::deco::*;
 pub use self::font::*;
+pub use self::hyphenation::*;
 pub use self::item::*;
 pub use self::lang::*;
 pub use self::linebreak::*;
 pub use self::lorem_::*;
 pub use self::raw::*;
+pub use self::ruby::*;
 pub use self::shift::*;
 pub use self::smallcaps_::*;
 pub use self::smartquote::*;
@@ -30,6 +34,7 @@ pub use self::space::*;
 
 use std::fmt::{self, Debug, Formatter};
 use std::hash::Hash;
+use std::num::NonZeroUsize;
 use std::sync::LazyLock;
 
 use ecow::{eco_format, EcoString};
@@ -40,16 +45,16 @@ use rustybuzz::Feature;
 use smallvec::SmallVec;
 use ttf_parser::Tag;
 use typst_syntax::Spanned;
-use typst_utils::singleton;
+use typst_utils::{singleton, Scalar};
 
 use crate::diag::{bail, warning, HintedStrResult, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, dict, elem, Args, Array, Cast, Construct, Content, Dict, Fold, IntoValue,
     NativeElement, Never, NoneValue, Packed, PlainText, Regex, Repr, Resolve, Scope, Set,
-    Smart, StyleChain,
+    Smart, StyleChain, Value,
 };
-use crate::layout::{Abs, Axis, Dir, Em, Length, Ratio, Rel};
+use crate::layout::{Abs, Dir, Em, Length, Ratio, Rel};
 use crate::math::{EquationElem, MathSize};
 use crate::visualize::{Color, Paint, RelativeTo, Stroke};
 use crate::World;
@@ -68,6 +73,7 @@ pub(super) fn define(global: &mut Scope) {
     global.define_elem::<HighlightElem>();
     global.define_elem::<SmallcapsElem>();
     global.define_elem::<RawElem>();
+    global.define_elem::<RubyElem>();
     global.define_func::<lower>();
     global.define_func::<upper>();
     global.define_func::<lorem>();
@@ -364,6 +370,59 @@ pub struct TextElem {
     #[ghost]
     pub overhang: bool,
 
+    /// How far specific characters protrude into the margins at the start and
+    /// end of lines, relative to their width.
+    ///
+    /// Takes a dictionary that maps single characters to either a ratio,
+    /// which applies to both margins, or a dictionary with `left` and `right`
+    /// ratios. Characters without an entry for the end margin of a line fall
+    /// back to Typst's built-in hanging punctuation. Protrusion only applies if
+    /// [`overhang`]($text.overhang) is enabled. Entries from multiple set
+    /// rules are combined, with later entries taking precedence.
+    ///
+    /// ```example
+    /// #set par(justify: true)
+    /// #set text(protrusion: (
+    ///   "“": (left: 50%),
+    ///   "”": (right: 50%),
+    ///   "-": 70%,
+    /// ))
+    /// “Protruding quotes and hyphens
+    /// give the paragraph a visually
+    /// smoother edge,” they said.
+    /// ```
+    #[fold]
+    #[ghost]
+    pub protrusion: Protrusion,
+
+    /// How much glyphs may be stretched or shrunk horizontally to improve
+    /// justification.
+    ///
+    /// When justifying, part of the extra space is then distributed onto the
+    /// glyphs instead of the spaces between words. This reduces gaps and the
+    /// need for hyphenation, particularly in narrow columns. Small values of
+    /// up to a few percent are typically imperceptible.
+    ///
+    /// ```example
+    /// #set page(width: 120pt)
+    /// #set par(justify: true)
+    /// #lorem(12)
+    ///
+    /// #set text(expansion: 3%)
+    /// #lorem(12)
+    /// ```
+    #[parse({
+        let expansion: Option<Spanned<Ratio>> = args.named("expansion")?;
+        if let Some(Spanned { v, span }) = expansion {
+            if !(0.0..=0.1).contains(&v.get()) {
+                bail!(span, "expansion must be between 0% and 10%");
+            }
+        }
+        expansion.map(|expansion| expansion.v)
+    })]
+    #[ghost]
+    pub expansion: Ratio,
+
     /// The top end of the conceptual frame around the text used for layout and
     /// positioning. This affects the size of containers that hold text.
     ///
@@ -460,6 +519,15 @@ pub struct TextElem {
     /// - `{auto}`: Automatically infer the direction from the `lang` property.
     /// - `{ltr}`: Layout text from left to right.
     /// - `{rtl}`: Layout text from right to left.
+    /// - `{ttb}`: Layout text vertically from top to bottom, with lines
+    ///   progressing from right to left, as is common for Chinese and
+    ///   Japanese. When set for a whole page, its body is laid out in this
+    ///   vertical writing mode while the page's header, footer, background,
+    ///   and foreground stay horizontal. A vertical paragraph within
+    ///   horizontal content is as tall as its longest line and vertical text
+    ///   within a horizontal line stands on the baseline. The glyph
+    ///   orientation is controlled by the [`orientation`]($text.orientation)
+    ///   property.
     ///
     /// When writing in right-to-left scripts like Arabic or Hebrew, you should
     /// set the [text language]($text.lang) or direction. While individual runs
@@ -484,6 +552,36 @@ pub struct TextElem {
     #[ghost]
     pub dir: TextDir,
 
+    /// How glyphs are oriented in vertical text.
+    ///
+    /// Only has an effect if the [direction]($text.dir) is `{ttb}`.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #set text(dir: ttb, lang: "ja")
+    /// 縦書きの Typst
+    /// ```
+    #[ghost]
+    pub orientation: TextOrientation,
+
+    /// The maximum number of digits that are combined horizontally into a
+    /// single upright character in vertical text (_tate-chu-yoko_).
+    ///
+    /// Runs of ASCII digits that are at most this long are set horizontally
+    /// and squeezed into the width of one em if necessary. When `{none}`,
+    /// digits are oriented like other text.
+    ///
+    /// Only has an effect if the [direction]($text.dir) is `{ttb}`.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #set text(dir: ttb, lang: "ja")
+    /// 令和7年12月
+    /// ```
+    #[ghost]
+    #[default(NonZeroUsize::new(2))]
+    pub combine_upright: Option<NonZeroUsize>,
+
     /// Whether to hyphenate text to improve line breaking. When `{auto}`, text
     /// will be hyphenated if and only if justification is enabled.
     ///
@@ -506,6 +604,86 @@ pub struct TextElem {
     #[ghost]
     pub hyphenate: Smart<bool>,
 
+    /// Words with explicitly marked hyphenation opportunities.
+    ///
+    /// Each entry is a word in which hyphens mark where it may be hyphenated.
+    /// An entry without hyphens prevents the word from being hyphenated at
+    /// all. Entries match regardless of case and take precedence over the
+    /// hyphenation patterns. Exceptions from multiple set rules are combined.
+    ///
+    /// ```example
+    /// #set page(width: 80pt)
+    /// #set par(justify: true)
+    /// #set text(
+    ///   hyphenate: true,
+    ///   hyphenation-exceptions: ("data-base", "Typst"),
+    /// )
+    /// A database of Typst documents.
+    /// ```
+    #[fold]
+    #[ghost]
+    pub hyphenation_exceptions: Vec<EcoString>,
+
+    /// Custom hyphenation patterns to use instead of the built-in ones, keyed
+    /// by [language]($text.lang).
+    ///
+    /// This is useful for languages that Typst has no built-in patterns for.
+    /// The patterns only apply to text in their language. Patterns for other
+    /// languages from earlier set rules are kept.
+    ///
+    /// For each language code, you can pass any of the following values:
+    ///
+    /// - `{none}`: Uses the built-in patterns for the language.
+    /// - A path string to load a pattern file from the given path. For more
+    ///   details about paths, see the [Paths section]($syntax/#paths).
+    /// - Raw bytes from which the patterns should be decoded.
+    ///
+    /// The file should contain
+    /// [TeX hyphenation patterns](https://hyphenation.org/#languages), either
+    /// wrapped in a `\patterns{...}` group or as a plain list. Words in an
+    /// optional `\hyphenation{...}` group are used as exceptions.
+    ///
+    /// ```example
+    /// #set par(justify: true)
+    /// #set text(
+    ///   lang: "fy",
+    ///   hyphenate: true,
+    ///   hyphenation-patterns: (fy: bytes("1ba 1ca 1da")),
+    /// )
+    /// Abracadabra abracadabra abracadabra abracadabra.
+    /// ```
+    #[parse(match args.named::<Spanned<Dict>>("hyphenation-patterns")? {
+        Some(dict) => Some(LangPatterns::load(engine.world, dict)?),
+        None => None,
+    })]
+    #[fold]
+    #[ghost]
+    pub hyphenation_patterns: LangPatterns,
+
+    /// How many characters must at least remain before (`left`) and after
+    /// (`right`) a hyphen.
+    ///
+    /// Unset limits fall back to the defaults of the text language, which are
+    /// two characters before and three after a hyphen for most languages.
+    /// Besides `left` and `right`, the dictionary may map language codes to
+    /// limits that only apply to text in that language and take precedence.
+    ///
+    /// ```example
+    /// #set page(width: 80pt)
+    /// #set par(justify: true)
+    /// #set text(hyphenate: true)
+    /// Extraordinarily long words.
+    ///
+    /// #set text(hyphenation-limits: (left: 4, right: 4))
+    /// Extraordinarily long words.
+    ///
+    /// #set text(hyphenation-limits: (en: (left: 2)))
+    /// Extraordinarily long words.
+    /// ```
+    #[fold]
+    #[ghost]
+    pub hyphenation_limits: HyphenationLimits,
+
     /// The "cost" of various choices when laying out text. A higher cost means
     /// the layout engine will make the choice less often. Costs are specified
     /// as a ratio of the default cost, so `{50%}` will make text layout twice
@@ -709,6 +887,26 @@ pub struct TextElem {
     #[ghost]
     pub features: FontFeatures,
 
+    /// Coordinates on the design axes of variable fonts.
+    ///
+    /// A dictionary mapping four-letter axis tags to numbers. Coordinates are
+    /// clamped to the range supported by the font and ignored for axes the
+    /// font doesn't have.
+    ///
+    /// When a variable font is selected for a [`weight`]($text.weight) or
+    /// [`stretch`]($text.stretch) that its default instance doesn't have, Typst
+    /// automatically sets its `wght` or `wdth` axis accordingly. Explicitly
+    /// given coordinates take precedence over these. Other axes, like `opsz`,
+    /// are only set when given explicitly.
+    ///
+    /// ```example
+    /// #set text(variations: ("wght": 550, "opsz": 12))
+    /// Variable fonts
+    /// ```
+    #[fold]
+    #[ghost]
+    pub variations: FontVariations,
+
     /// Content in which all text is styled according to the other arguments.
     #[external]
     #[required]
@@ -752,6 +950,12 @@ pub struct TextElem {
     #[internal]
     #[ghost]
     pub smallcaps: Option<Smallcaps>,
+
+    /// Whether text is laid out in vertical writing mode, i.e. with swapped
+    /// axes that are turned afterwards.
+    #[internal]
+    #[ghost]
+    pub vertical: bool,
 }
 
 impl TextElem {
@@ -1090,8 +1294,8 @@ cast! {
     TextDir,
     self => self.0.into_value(),
     v: Smart<Dir> => {
-        if v.is_custom_and(|dir| dir.axis() == Axis::Y) {
-            bail!("text direction must be horizontal");
+        if v.is_custom_and(|dir| dir == Dir::BTT) {
+            bail!("text direction must be horizontal or top-to-bottom");
         }
         Self(v)
     },
@@ -1103,11 +1307,29 @@ impl Resolve for TextDir {
     fn resolve(self, styles: StyleChain) -> Self::Output {
         match self.0 {
             Smart::Auto => TextElem::lang_in(styles).dir(),
+            // In vertical writing mode, lines are laid out from left to right
+            // and turned afterwards. Anywhere else, the direction stays
+            // top-to-bottom, which makes inline layout switch to vertical
+            // writing mode.
+            Smart::Custom(Dir::TTB) if TextElem::vertical_in(styles) => Dir::LTR,
             Smart::Custom(dir) => dir,
         }
     }
 }
 
+/// How glyphs are oriented in vertical text.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum TextOrientation {
+    /// Chinese, Japanese, and Korean characters as well as some symbols stand
+    /// upright, while other text is turned sideways.
+    #[default]
+    Mixed,
+    /// All characters stand upright.
+    Upright,
+    /// All characters are turned sideways.
+    Sideways,
+}
+
 /// A set of stylistic sets to enable.
 #[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
 pub struct StylisticSets(u32);
@@ -1207,6 +1429,44 @@ impl Fold for FontFeatures {
     }
 }
 
+/// Variable font axis coordinates.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct FontVariations(pub Vec<(Tag, Scalar)>);
+
+cast! {
+    FontVariations,
+    self => self.0
+        .into_iter()
+        .map(|(tag, value)| {
+            let bytes = tag.to_bytes();
+            let key = std::str::from_utf8(&bytes).unwrap_or_default();
+            (key.into(), value.get().into_value())
+        })
+        .collect::<Dict>()
+        .into_value(),
+    values: Dict => Self(values
+        .into_iter()
+        .map(|(k, v)| -> HintedStrResult<_> {
+            let Ok(bytes) = <[u8; 4]>::try_from(k.as_bytes()) else {
+                bail!("axis tag must be exactly four bytes long");
+            };
+            let value = v.cast::<f64>()?;
+            Ok((Tag::from_bytes(&bytes), Scalar::new(value)))
+        })
+        .collect::<HintedStrResult<_>>()?),
+}
+
+impl Fold for FontVariations {
+    fn fold(self, outer: Self) -> Self {
+        Self(self.0.fold(outer.0))
+    }
+}
+
+/// Collect the explicitly set variable font axis coordinates.
+pub fn variations(styles: StyleChain) -> Vec<(Tag, Scalar)> {
+    TextElem::variations_in(styles).0
+}
+
 /// Collect the OpenType features to apply.
 pub fn features(styles: StyleChain) -> Vec<Feature> {
     let mut tags = vec![];
@@ -1362,6 +1622,60 @@ cast! {
     },
 }
 
+/// Protrusion factors for characters at the left and right edges of lines.
+///
+/// Factors are updated (prioritizing the later value) when folded.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Protrusion(Vec<(char, Option<Ratio>, Option<Ratio>)>);
+
+impl Protrusion {
+    /// The custom left and right protrusion factors for a character, if any.
+    pub fn get(&self, c: char) -> (Option<Ratio>, Option<Ratio>) {
+        let mut entries = self.0.iter().rev().filter(|(k, ..)| *k == c);
+        let left = entries.clone().find_map(|&(_, left, _)| left);
+        let right = entries.find_map(|&(.., right)| right);
+        (left, right)
+    }
+}
+
+impl Fold for Protrusion {
+    fn fold(self, mut outer: Self) -> Self {
+        outer.0.extend(self.0);
+        outer
+    }
+}
+
+cast! {
+    Protrusion,
+    self => self
+        .0
+        .iter()
+        .map(|&(c, ..)| {
+            let (left, right) = self.get(c);
+            let factors = dict!["left" => left, "right" => right].into_value();
+            (eco_format!("{c}").into(), factors)
+        })
+        .collect::<Dict>()
+        .into_value(),
+    v: Dict => Self(v.into_iter().map(|(key, value)| -> HintedStrResult<_> {
+        let mut chars = key.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            bail!("expected a single character as key, found \"{key}\"");
+        };
+        let (left, right) = match value {
+            Value::Ratio(ratio) => (Some(ratio), Some(ratio)),
+            Value::Dict(mut dict) => {
+                let left = dict.take("left").ok().map(|v| v.cast()).transpose()?;
+                let right = dict.take("right").ok().map(|v| v.cast()).transpose()?;
+                dict.finish(&["left", "right"])?;
+                (left, right)
+            }
+            value => bail!("expected ratio or dictionary, found {}", value.ty()),
+        };
+        Ok((c, left, right))
+    }).collect::<HintedStrResult<_>>()?),
+}
+
 /// Whether a codepoint is Unicode `Default_Ignorable`.
 pub fn is_default_ignorable(c: char) -> bool {
     /// The set of Unicode default ignorables.
@@ -1377,7 +1691,7 @@ pub fn is_default_ignorable(c: char) -> bool {
 }
 
 /// Checks for font families that are not available.
-fn check_font_list(engine: &mut Engine, list: &Spanned<FontList>) {
+pub(crate) fn check_font_list(engine: &mut Engine, list: &Spanned<FontList>) {
     let book = engine.world.book();
     for family in &list.v {
         if !book.contains_family(family.as_str()) {
@@ -1386,6 +1700,9 @@ fn check_font_list(engine: &mut Engine, list: &Spanned<FontList>) {
                 "unknown font family: {}",
                 family.as_str(),
             ));
+            engine
+                .sink
+                .unknown_family(Spanned::new(family.as_str().into(), list.span));
         }
     }
 }
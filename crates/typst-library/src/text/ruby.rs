@@ -0,0 +1,183 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::diag::{bail, HintedStrResult};
+use crate::foundations::{
+    elem, Cast, Content, Packed, SequenceElem, Smart, StyleChain, StyledElem,
+};
+use crate::layout::{Em, Length};
+use crate::text::{TextElem, TextSize};
+
+/// Annotates text with small ruby characters, e.g. furigana.
+///
+/// Ruby annotations are set in a smaller size above the base text (or to its
+/// right in vertical text). Each annotated piece of base text is laid out
+/// together with its annotation as one unit of the paragraph. Lines may break
+/// between such units, but never within one.
+///
+/// # Example
+/// ```example
+/// #set text(lang: "ja")
+/// #ruby[東京][とう][きょう]に#ruby[行][い]く。
+/// #ruby[明日][あした]は晴れ。
+/// ```
+///
+/// # Distribution
+/// When a single annotation is given, it spans the whole base text as _group
+/// ruby_, which is never broken across lines. When multiple annotations are
+/// given, each of them belongs to one character of the base text as _mono
+/// ruby_. Empty annotations leave their character unannotated.
+#[elem]
+pub struct RubyElem {
+    /// How the annotations are distributed over the base text.
+    ///
+    /// - `{"mono"}`: Each annotation belongs to one base character.
+    /// - `{"group"}`: The annotations are joined and span the whole base
+    ///   text.
+    /// - `{"jukugo"}`: Like `{"mono"}` as long as every annotation fits above
+    ///   its character, and like `{"group"}` otherwise. Whether an annotation
+    ///   fits is decided by its laid out width.
+    /// - `{auto}`: `{"group"}` for a single annotation and `{"mono"}` for
+    ///   multiple ones.
+    ///
+    /// ```example
+    /// #set text(lang: "ja")
+    /// #ruby(mode: "jukugo")[境界][きょう][かい] \
+    /// #ruby(mode: "jukugo")[承る][うけたまわ][]
+    /// ```
+    pub mode: Smart<RubyMode>,
+
+    /// On which side of the base text the annotations are placed.
+    ///
+    /// ```example
+    /// #set text(lang: "ja")
+    /// #ruby(position: "under")[漢字][かんじ]
+    /// ```
+    pub position: RubyPosition,
+
+    /// The size of the annotations.
+    #[default(TextSize(Em::new(0.5).into()))]
+    pub size: TextSize,
+
+    /// The gap between the base text and its annotations.
+    pub gap: Length,
+
+    /// Whether annotations that are wider than their base text may overhang
+    /// adjacent kana by up to one annotation character instead of spacing out
+    /// the base text.
+    #[default(true)]
+    pub overhang: bool,
+
+    /// The base text. For mono ruby, it is split into its characters, keeping
+    /// their styles. Other elements within it, e.g. boxes or emphasis, count
+    /// as a single character.
+    #[required]
+    pub body: Content,
+
+    /// The annotations.
+    #[variadic]
+    pub annotations: Vec<Content>,
+}
+
+impl Packed<RubyElem> {
+    /// How the annotations are distributed over the base text.
+    pub fn distribution(&self, styles: StyleChain) -> RubyMode {
+        self.mode(styles).unwrap_or(if self.annotations.len() == 1 {
+            RubyMode::Group
+        } else {
+            RubyMode::Mono
+        })
+    }
+
+    /// The whole base text with the joined annotations.
+    pub fn group(&self) -> (Content, Content) {
+        let annotation = if self.annotations.iter().all(Content::is_empty) {
+            Content::empty()
+        } else {
+            Content::sequence(self.annotations.iter().cloned())
+        };
+        (self.body.clone(), annotation)
+    }
+
+    /// Split the base text into pieces with their annotations, keeping their
+    /// styles. Pieces with an empty annotation are unannotated.
+    ///
+    /// Jukugo ruby is split like mono ruby here. Whether it falls back to
+    /// group ruby depends on the size of the laid out annotations.
+    pub fn units(&self, styles: StyleChain) -> HintedStrResult<Vec<(Content, Content)>> {
+        let mode = self.distribution(styles);
+        if mode == RubyMode::Group {
+            return Ok(vec![self.group()]);
+        }
+
+        let mut chars = vec![];
+        graphemes(&self.body, &mut chars);
+        if chars.len() != self.annotations.len() {
+            bail!(
+                "expected {} annotations, found {}",
+                chars.len(),
+                self.annotations.len();
+                hint: "{} ruby needs one annotation per base character",
+                mode.as_str()
+            );
+        }
+
+        Ok(chars.into_iter().zip(self.annotations.iter().cloned()).collect())
+    }
+}
+
+/// Split content into one piece per grapheme of its text, keeping the styles
+/// applied to it. Other elements form a single piece each.
+fn graphemes(content: &Content, pieces: &mut Vec<Content>) {
+    if let Some(sequence) = content.to_packed::<SequenceElem>() {
+        for child in &sequence.children {
+            graphemes(child, pieces);
+        }
+    } else if let Some(styled) = content.to_packed::<StyledElem>() {
+        let start = pieces.len();
+        graphemes(&styled.child, pieces);
+        for piece in &mut pieces[start..] {
+            piece.style_in_place(styled.styles.clone());
+        }
+    } else if let Some(elem) = content.to_packed::<TextElem>() {
+        pieces.extend(
+            elem.text
+                .graphemes(true)
+                .map(|grapheme| TextElem::packed(grapheme).spanned(elem.span())),
+        );
+    } else {
+        pieces.push(content.clone());
+    }
+}
+
+/// How ruby annotations are distributed over the base text.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum RubyMode {
+    /// Each annotation belongs to one base character.
+    Mono,
+    /// The annotations span the whole base text.
+    Group,
+    /// Mono ruby that falls back to group ruby if an annotation is wider than
+    /// its base character.
+    Jukugo,
+}
+
+impl RubyMode {
+    /// The name of the mode.
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Mono => "mono",
+            Self::Group => "group",
+            Self::Jukugo => "jukugo",
+        }
+    }
+}
+
+/// On which side of the base text ruby annotations are placed.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum RubyPosition {
+    /// Above horizontal and to the right of vertical text.
+    #[default]
+    Over,
+    /// Below horizontal and to the left of vertical text.
+    Under,
+}
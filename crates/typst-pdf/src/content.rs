@@ -10,6 +10,7 @@ use pdf_writer::types::{
 };
 use pdf_writer::writers::PositionedItems;
 use pdf_writer::{Content, Finish, Name, Rect, Str};
+use ttf_parser::{GlyphId, OutlineBuilder};
 use typst_library::diag::{bail, error, SourceDiagnostic, SourceResult};
 use typst_library::foundations::Repr;
 use typst_library::layout::{
@@ -468,12 +469,21 @@ fn write_normal_text(
     pos: Point,
     text: TextItemView,
 ) -> SourceResult<()> {
+    // Instances of variable fonts can't be embedded, so we draw the instanced
+    // outlines of their glyphs as paths instead. On top of them, we write the
+    // text invisibly with the default instance to keep it extractable.
+    let instanced = !text.item.font.variations().is_empty();
+    if instanced {
+        write_instanced_glyphs(ctx, pos, &text)?;
+    }
+
+    let font = text.item.font.base();
     let x = pos.x.to_f32();
     let y = pos.y.to_f32();
 
     *ctx.resources.languages.entry(text.item.lang).or_insert(0) += text.glyph_range.len();
 
-    let glyph_set = ctx.resources.glyph_sets.entry(text.item.font.clone()).or_default();
+    let glyph_set = ctx.resources.glyph_sets.entry(font.clone()).or_default();
     for g in text.glyphs() {
         glyph_set.entry(g.id).or_insert_with(|| text.glyph_text(g));
     }
@@ -489,14 +499,16 @@ fn write_normal_text(
         }
     });
 
-    if let Some(stroke) = stroke {
+    if instanced {
+        ctx.set_text_rendering_mode(TextRenderingMode::Invisible);
+    } else if let Some(stroke) = stroke {
         ctx.set_stroke(stroke, true, fill_transform)?;
         ctx.set_text_rendering_mode(TextRenderingMode::FillStroke);
     } else {
         ctx.set_text_rendering_mode(TextRenderingMode::Fill);
     }
 
-    ctx.set_font(&text.item.font, text.item.size);
+    ctx.set_font(font, text.item.size);
     ctx.set_opacities(text.item.stroke.as_ref(), Some(&text.item.fill));
     ctx.content.begin_text();
 
@@ -508,11 +520,7 @@ fn write_normal_text(
     let mut adjustment = Em::zero();
     let mut encoded = vec![];
 
-    let glyph_remapper = ctx
-        .resources
-        .glyph_remappers
-        .entry(text.item.font.clone())
-        .or_default();
+    let glyph_remapper = ctx.resources.glyph_remappers.entry(font.clone()).or_default();
 
     // Write the glyphs with kerning adjustments.
     for glyph in text.glyphs() {
@@ -555,7 +563,7 @@ fn write_normal_text(
         encoded.push((cid >> 8) as u8);
         encoded.push((cid & 0xff) as u8);
 
-        if let Some(advance) = text.item.font.advance(glyph.id) {
+        if let Some(advance) = font.advance(glyph.id) {
             adjustment += glyph.x_advance - advance;
         }
 
@@ -581,6 +589,94 @@ fn show_text(items: &mut PositionedItems, encoded: &[u8]) {
     }
 }
 
+/// Encodes a text run of a variable font instance into the content stream by
+/// drawing the glyph outlines as paths.
+fn write_instanced_glyphs(
+    ctx: &mut Builder,
+    pos: Point,
+    text: &TextItemView,
+) -> SourceResult<()> {
+    let font = &text.item.font;
+    let size = text.item.size;
+    let scale = size.to_pt() / font.units_per_em();
+
+    let mut x = Abs::zero();
+    for glyph in text.glyphs() {
+        if ctx.options.standards.pdfa && glyph.id == 0 {
+            bail!(tofu(text, glyph));
+        }
+
+        let offset = Point::new(x + glyph.x_offset.at(size), -glyph.y_offset.at(size));
+        x += glyph.x_advance.at(size);
+
+        let mut builder = CurveBuilder { curve: Curve::new(), scale };
+        if font.ttf().outline_glyph(GlyphId(glyph.id), &mut builder).is_none() {
+            continue;
+        }
+
+        let shape = Shape {
+            geometry: Geometry::Curve(builder.curve),
+            fill: Some(text.item.fill.clone()),
+            fill_rule: FillRule::NonZero,
+            stroke: text.item.stroke.clone(),
+        };
+        write_shape(ctx, pos + offset, &shape)?;
+    }
+
+    Ok(())
+}
+
+/// Builds a curve from a glyph outline, scaled from font units and with the
+/// y-axis pointing downwards.
+struct CurveBuilder {
+    curve: Curve,
+    scale: f64,
+}
+
+impl CurveBuilder {
+    fn point(&self, x: f32, y: f32) -> Point {
+        Point::new(Abs::pt(x as f64 * self.scale), Abs::pt(-y as f64 * self.scale))
+    }
+}
+
+impl OutlineBuilder for CurveBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let p = self.point(x, y);
+        self.curve.move_(p);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let p = self.point(x, y);
+        self.curve.line(p);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        // Elevate the quadratic to a cubic curve.
+        let start = match self.curve.0.last() {
+            Some(CurveItem::Move(p) | CurveItem::Line(p) | CurveItem::Cubic(_, _, p)) => {
+                *p
+            }
+            _ => Point::zero(),
+        };
+        let ctrl = self.point(x1, y1);
+        let end = self.point(x, y);
+        let p1 = start + (ctrl - start) * (2.0 / 3.0);
+        let p2 = end + (ctrl - end) * (2.0 / 3.0);
+        self.curve.cubic(p1, p2, end);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let p1 = self.point(x1, y1);
+        let p2 = self.point(x2, y2);
+        let p = self.point(x, y);
+        self.curve.cubic(p1, p2, p);
+    }
+
+    fn close(&mut self) {
+        self.curve.close();
+    }
+}
+
 /// Encodes a text run made only of color glyphs into the content stream
 fn write_complex_glyphs(
     ctx: &mut Builder,
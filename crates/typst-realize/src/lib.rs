@@ -26,10 +26,10 @@ use typst_library::layout::{
 use typst_library::math::{EquationElem, Mathy};
 use typst_library::model::{
     CiteElem, CiteGroup, DocumentElem, EnumElem, ListElem, ListItemLike, ListLike,
-    ParElem, ParbreakElem, TermsElem,
+    MarginNoteElem, ParElem, ParbreakElem, TermsElem,
 };
 use typst_library::routines::{Arenas, FragmentKind, Pair, RealizationKind};
-use typst_library::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TextElem};
+use typst_library::text::{LinebreakElem, RubyElem, SmartQuoteElem, SpaceElem, TextElem};
 use typst_syntax::Span;
 use typst_utils::{SliceExt, SmallBitSet};
 
@@ -785,9 +785,15 @@ fn finish_innermost_grouping(s: &mut State) -> SourceResult<()> {
     // The grouping we are interrupting.
     let Grouping { start, rule, .. } = s.groupings.pop().unwrap();
 
-    // Trim trailing non-trigger elements.
-    let trimmed = s.sink[start..].trim_end_matches(|(c, _)| !(rule.trigger)(c, &s.kind));
-    let end = start + trimmed.len();
+    // Trim trailing non-trigger elements. A margin note at the very end of a
+    // paragraph still belongs to its last line, so its tags are kept.
+    let mut end = s.sink.len();
+    while end > start
+        && !(rule.trigger)(s.sink[end - 1].0, &s.kind)
+        && !(std::ptr::eq(rule, &PAR) && is_margin_note(&s.sink[start..end]))
+    {
+        end -= 1;
+    }
     let tail = s.store_slice(&s.sink[end..]);
     s.sink.truncate(end);
 
@@ -873,6 +879,7 @@ static PAR: GroupingRule = GroupingRule {
             || elem == SmartQuoteElem::elem()
             || elem == InlineElem::elem()
             || elem == BoxElem::elem()
+            || elem == RubyElem::elem()
             || (kind.is_html()
                 && content
                     .to_packed::<HtmlElem>()
@@ -1313,6 +1320,18 @@ fn collapse_spaces(buf: &mut Vec<Pair>, start: usize) {
     buf.truncate(k);
 }
 
+/// Whether the elements end with the start and end tag of a margin note.
+fn is_margin_note(buf: &[Pair]) -> bool {
+    let [.., (start, _), (end, _)] = buf else { return false };
+    let tag = |c: &Content| c.to_packed::<TagElem>().map(|elem| &elem.tag);
+    match (tag(start), tag(end)) {
+        (Some(Tag::Start(elem)), Some(Tag::End(loc, _))) => {
+            elem.is::<MarginNoteElem>() && elem.location() == Some(*loc)
+        }
+        _ => false,
+    }
+}
+
 /// Deletes a preceding space if any.
 fn destruct_space(buf: &mut [Pair], end: &mut usize, state: &mut SpaceState) {
     if let SpaceState::Space(s) = *state {
@@ -318,6 +318,10 @@ pub enum Expr<'a> {
     WhileLoop(WhileLoop<'a>),
     /// A for loop: `for x in y { z }`.
     ForLoop(ForLoop<'a>),
+    /// A match expression: `match x { 0 => "zero", _ => "other" }`.
+    MatchExpr(MatchExpr<'a>),
+    /// A try-catch expression: `try { x } catch err { y }`.
+    TryCatch(TryCatch<'a>),
     /// A module import: `import "utils.typ": a, b, c`.
     ModuleImport(ModuleImport<'a>),
     /// A module include: `include "chapter1.typ"`.
@@ -400,6 +404,8 @@ impl<'a> AstNode<'a> for Expr<'a> {
             SyntaxKind::Conditional => Some(Self::Conditional(Conditional(node))),
             SyntaxKind::WhileLoop => Some(Self::WhileLoop(WhileLoop(node))),
             SyntaxKind::ForLoop => Some(Self::ForLoop(ForLoop(node))),
+            SyntaxKind::MatchExpr => Some(Self::MatchExpr(MatchExpr(node))),
+            SyntaxKind::TryCatch => Some(Self::TryCatch(TryCatch(node))),
             SyntaxKind::ModuleImport => Some(Self::ModuleImport(ModuleImport(node))),
             SyntaxKind::ModuleInclude => Some(Self::ModuleInclude(ModuleInclude(node))),
             SyntaxKind::LoopBreak => Some(Self::LoopBreak(LoopBreak(node))),
@@ -465,6 +471,8 @@ impl<'a> AstNode<'a> for Expr<'a> {
             Self::Conditional(v) => v.to_untyped(),
             Self::WhileLoop(v) => v.to_untyped(),
             Self::ForLoop(v) => v.to_untyped(),
+            Self::MatchExpr(v) => v.to_untyped(),
+            Self::TryCatch(v) => v.to_untyped(),
             Self::ModuleImport(v) => v.to_untyped(),
             Self::ModuleInclude(v) => v.to_untyped(),
             Self::LoopBreak(v) => v.to_untyped(),
@@ -501,6 +509,8 @@ impl Expr<'_> {
                 | Self::Conditional(_)
                 | Self::WhileLoop(_)
                 | Self::ForLoop(_)
+                | Self::MatchExpr(_)
+                | Self::TryCatch(_)
                 | Self::ModuleImport(_)
                 | Self::ModuleInclude(_)
                 | Self::LoopBreak(_)
@@ -1884,6 +1894,8 @@ pub enum Pattern<'a> {
     Parenthesized(Parenthesized<'a>),
     /// A destructuring pattern: `(x, _, ..y)`.
     Destructuring(Destructuring<'a>),
+    /// A typed pattern in a match arm: `int(n)`.
+    Typed(TypedPattern<'a>),
 }
 
 impl<'a> AstNode<'a> for Pattern<'a> {
@@ -1892,6 +1904,7 @@ impl<'a> AstNode<'a> for Pattern<'a> {
             SyntaxKind::Underscore => Some(Self::Placeholder(Underscore(node))),
             SyntaxKind::Parenthesized => Some(Self::Parenthesized(Parenthesized(node))),
             SyntaxKind::Destructuring => Some(Self::Destructuring(Destructuring(node))),
+            SyntaxKind::TypedPattern => Some(Self::Typed(TypedPattern(node))),
             _ => node.cast().map(Self::Normal),
         }
     }
@@ -1902,6 +1915,7 @@ impl<'a> AstNode<'a> for Pattern<'a> {
             Self::Placeholder(v) => v.to_untyped(),
             Self::Parenthesized(v) => v.to_untyped(),
             Self::Destructuring(v) => v.to_untyped(),
+            Self::Typed(v) => v.to_untyped(),
         }
     }
 }
@@ -1913,6 +1927,7 @@ impl<'a> Pattern<'a> {
             Self::Normal(Expr::Ident(ident)) => vec![ident],
             Self::Parenthesized(v) => v.pattern().bindings(),
             Self::Destructuring(v) => v.bindings(),
+            Self::Typed(v) => v.pattern().map(Pattern::bindings).unwrap_or_default(),
             _ => vec![],
         }
     }
@@ -1983,6 +1998,26 @@ impl<'a> AstNode<'a> for DestructuringItem<'a> {
     }
 }
 
+node! {
+    /// A typed pattern in a match arm: `int(n)` or `heading(_)`.
+    struct TypedPattern
+}
+
+impl<'a> TypedPattern<'a> {
+    /// The type or element function to match against.
+    pub fn ty(self) -> Expr<'a> {
+        self.0.cast_first()
+    }
+
+    /// The pattern for the matched value, if any.
+    pub fn pattern(self) -> Option<Pattern<'a>> {
+        self.0
+            .children()
+            .skip_while(|child| child.kind() != SyntaxKind::LeftParen)
+            .find_map(SyntaxNode::cast)
+    }
+}
+
 node! {
     /// A let binding: `let x = 1`.
     struct LetBinding
@@ -2174,6 +2209,79 @@ impl<'a> ForLoop<'a> {
     }
 }
 
+node! {
+    /// A match expression: `match x { 0 => "zero", _ => "other" }`.
+    struct MatchExpr
+}
+
+impl<'a> MatchExpr<'a> {
+    /// The value to match.
+    pub fn value(self) -> Expr<'a> {
+        self.0.cast_first()
+    }
+
+    /// The arms, in the order in which they are tried.
+    pub fn arms(self) -> impl DoubleEndedIterator<Item = MatchArm<'a>> {
+        self.0.children().filter_map(SyntaxNode::cast)
+    }
+}
+
+node! {
+    /// An arm of a match expression: `(x, y) if x < y => y`.
+    struct MatchArm
+}
+
+impl<'a> MatchArm<'a> {
+    /// The pattern the value must match.
+    pub fn pattern(self) -> Pattern<'a> {
+        self.0.cast_first()
+    }
+
+    /// An additional condition that must hold for the arm to be selected.
+    pub fn guard(self) -> Option<Expr<'a>> {
+        self.0
+            .children()
+            .skip_while(|child| child.kind() != SyntaxKind::If)
+            .find_map(SyntaxNode::cast)
+    }
+
+    /// The expression to evaluate if the arm is selected.
+    pub fn body(self) -> Expr<'a> {
+        self.0
+            .children()
+            .skip_while(|child| child.kind() != SyntaxKind::Arrow)
+            .find_map(SyntaxNode::cast)
+            .unwrap_or_default()
+    }
+}
+
+node! {
+    /// A try-catch expression: `try { x } catch err { y }`.
+    struct TryCatch
+}
+
+impl<'a> TryCatch<'a> {
+    /// The expression whose errors are caught.
+    pub fn body(self) -> Expr<'a> {
+        self.0.cast_first()
+    }
+
+    /// The name the caught error is bound to in the handler, if any.
+    pub fn binding(self) -> Option<Ident<'a>> {
+        self.0
+            .children()
+            .skip_while(|child| child.kind() != SyntaxKind::Catch)
+            .skip(1)
+            .find(|child| !child.kind().is_trivia())
+            .and_then(SyntaxNode::cast)
+    }
+
+    /// The expression to evaluate if the body fails.
+    pub fn handler(self) -> Expr<'a> {
+        self.0.cast_last()
+    }
+}
+
 node! {
     /// A module import: `import "utils.typ": a, b, c`.
     struct ModuleImport
@@ -241,6 +241,9 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::For => Some(Tag::Keyword),
         SyntaxKind::In => Some(Tag::Keyword),
         SyntaxKind::While => Some(Tag::Keyword),
+        SyntaxKind::Match => Some(Tag::Keyword),
+        SyntaxKind::Try => Some(Tag::Keyword),
+        SyntaxKind::Catch => Some(Tag::Keyword),
         SyntaxKind::Break => Some(Tag::Keyword),
         SyntaxKind::Continue => Some(Tag::Keyword),
         SyntaxKind::Return => Some(Tag::Keyword),
@@ -277,6 +280,10 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::Conditional => None,
         SyntaxKind::WhileLoop => None,
         SyntaxKind::ForLoop => None,
+        SyntaxKind::MatchExpr => None,
+        SyntaxKind::MatchArm => None,
+        SyntaxKind::TypedPattern => None,
+        SyntaxKind::TryCatch => None,
         SyntaxKind::ModuleImport => None,
         SyntaxKind::ImportItems => None,
         SyntaxKind::ImportItemPath => None,
@@ -305,7 +312,9 @@ fn highlight_ident(node: &LinkedNode) -> Option<Tag> {
             && ((next.kind() == SyntaxKind::LeftParen
                 && matches!(
                     next.parent_kind(),
-                    Some(SyntaxKind::Args | SyntaxKind::Params)
+                    Some(
+                        SyntaxKind::Args | SyntaxKind::Params | SyntaxKind::TypedPattern
+                    )
                 ))
                 || (next.kind() == SyntaxKind::LeftBracket
                     && next.parent_kind() == Some(SyntaxKind::ContentBlock)))
@@ -193,6 +193,12 @@ pub enum SyntaxKind {
     In,
     /// The `while` keyword.
     While,
+    /// The `match` keyword.
+    Match,
+    /// The `try` keyword.
+    Try,
+    /// The `catch` keyword.
+    Catch,
     /// The `break` keyword.
     Break,
     /// The `continue` keyword.
@@ -264,6 +270,14 @@ pub enum SyntaxKind {
     WhileLoop,
     /// A for loop: `for x in y { z }`.
     ForLoop,
+    /// A match expression: `match x { 0 => "zero", _ => "other" }`.
+    MatchExpr,
+    /// An arm of a match expression: `(x, y) if x < y => y`.
+    MatchArm,
+    /// A typed pattern in a match arm: `int(n)` or `heading(_)`.
+    TypedPattern,
+    /// A try-catch expression: `try { x } catch err { y }`.
+    TryCatch,
     /// A module import: `import "utils.typ": a, b, c`.
     ModuleImport,
     /// Items to import from a module: `a, b, c`.
@@ -347,6 +361,9 @@ impl SyntaxKind {
                 | Self::For
                 | Self::In
                 | Self::While
+                | Self::Match
+                | Self::Try
+                | Self::Catch
                 | Self::Break
                 | Self::Continue
                 | Self::Return
@@ -466,6 +483,9 @@ impl SyntaxKind {
             Self::For => "keyword `for`",
             Self::In => "keyword `in`",
             Self::While => "keyword `while`",
+            Self::Match => "keyword `match`",
+            Self::Try => "keyword `try`",
+            Self::Catch => "keyword `catch`",
             Self::Break => "keyword `break`",
             Self::Continue => "keyword `continue`",
             Self::Return => "keyword `return`",
@@ -501,6 +521,10 @@ impl SyntaxKind {
             Self::Conditional => "`if` expression",
             Self::WhileLoop => "while-loop expression",
             Self::ForLoop => "for-loop expression",
+            Self::MatchExpr => "match expression",
+            Self::MatchArm => "match arm",
+            Self::TypedPattern => "typed pattern",
+            Self::TryCatch => "try-catch expression",
             Self::ModuleImport => "`import` expression",
             Self::ImportItems => "import items",
             Self::ImportItemPath => "imported item path",
@@ -721,9 +721,11 @@ fn code_expr_prec(p: &mut Parser, atomic: bool, min_prec: usize) {
 fn code_primary(p: &mut Parser, atomic: bool) {
     let m = p.marker();
     match p.current() {
+        SyntaxKind::Ident if p.current_text() == "match" && match_expr(p) => {}
+        SyntaxKind::Ident if p.current_text() == "try" && try_catch(p) => {}
         SyntaxKind::Ident => {
             p.eat();
-            if !atomic && p.at(SyntaxKind::Arrow) {
+            if !atomic && p.closures && p.at(SyntaxKind::Arrow) {
                 p.wrap(m, SyntaxKind::Params);
                 p.assert(SyntaxKind::Arrow);
                 code_expr(p);
@@ -832,7 +834,7 @@ fn let_binding(p: &mut Parser) {
             closure = true;
         }
     } else {
-        pattern(p, false, &mut HashSet::new(), None);
+        pattern(p, PatternKind::Binding, &mut HashSet::new(), None);
         other = true;
     }
 
@@ -925,14 +927,14 @@ fn for_loop(p: &mut Parser) {
     p.assert(SyntaxKind::For);
 
     let mut seen = HashSet::new();
-    pattern(p, false, &mut seen, None);
+    pattern(p, PatternKind::Binding, &mut seen, None);
 
     if p.at(SyntaxKind::Comma) {
         let node = p.eat_and_get();
         node.unexpected();
         node.hint("destructuring patterns must be wrapped in parentheses");
         if p.at_set(set::PATTERN) {
-            pattern(p, false, &mut seen, None);
+            pattern(p, PatternKind::Binding, &mut seen, None);
         }
     }
 
@@ -942,6 +944,120 @@ fn for_loop(p: &mut Parser) {
     p.wrap(m, SyntaxKind::ForLoop);
 }
 
+/// Parses a try-catch expression: `try { x } catch err { y }`.
+///
+/// Like `match`, neither `try` nor `catch` are reserved keywords. The
+/// expression is only parsed if `try` is followed by whitespace and a block
+/// and the block by `catch`. Otherwise, nothing is parsed and `false` is
+/// returned.
+fn try_catch(p: &mut Parser) -> bool {
+    let checkpoint = p.checkpoint();
+    let balanced = p.balanced;
+    let m = p.marker();
+    p.convert_and_eat(SyntaxKind::Try);
+
+    if p.had_trivia() && p.at_set(syntax_set!(LeftBrace, LeftBracket)) {
+        block(p);
+        if at_catch(p) {
+            p.convert_and_eat(SyntaxKind::Catch);
+            p.eat_if(SyntaxKind::Ident);
+            block(p);
+            p.wrap(m, SyntaxKind::TryCatch);
+            return true;
+        }
+    }
+
+    p.restore(checkpoint);
+    p.balanced = balanced;
+    false
+}
+
+/// Whether the parser is at the `catch` of a try-catch expression. Just like
+/// `else`, it may also be on the next line.
+fn at_catch(p: &mut Parser) -> bool {
+    let node = &p.token.node;
+    if node.kind() != SyntaxKind::Ident || node.text() != "catch" {
+        return false;
+    }
+
+    // Undo the temporary end inserted at the newline before `catch`.
+    if p.at(SyntaxKind::End) && p.nl_mode == AtNewline::ContextualContinue {
+        p.token.kind = SyntaxKind::Ident;
+    }
+
+    p.at(SyntaxKind::Ident)
+}
+
+/// Parses a match expression: `match x { 0 => "zero", _ => "other" }`.
+///
+/// `match` is not a reserved keyword, so that it stays usable as an
+/// identifier. It only starts a match expression if it is followed by
+/// whitespace, an expression, and the opening brace of the arms. Otherwise,
+/// nothing is parsed and `false` is returned.
+fn match_expr(p: &mut Parser) -> bool {
+    let checkpoint = p.checkpoint();
+    let balanced = p.balanced;
+    let m = p.marker();
+    p.convert_and_eat(SyntaxKind::Match);
+
+    if p.had_trivia() && !p.had_newline() && p.at_set(set::MATCH_SUBJECT) {
+        code_expr(p);
+        if p.at(SyntaxKind::LeftBrace) {
+            match_arms(p);
+            p.wrap(m, SyntaxKind::MatchExpr);
+            return true;
+        }
+    }
+
+    p.restore(checkpoint);
+    p.balanced = balanced;
+    false
+}
+
+/// Parses the braced arms of a match expression. Arms are separated by commas
+/// or line breaks.
+fn match_arms(p: &mut Parser) {
+    let m = p.marker();
+    p.enter_modes(LexMode::Code, AtNewline::Continue, |p| {
+        p.assert(SyntaxKind::LeftBrace);
+        while !p.current().is_terminator() {
+            p.with_nl_mode(AtNewline::ContextualContinue, |p| {
+                if !p.at_set(set::MATCH_PATTERN) {
+                    p.unexpected();
+                    return;
+                }
+                let arm = p.marker();
+                match_arm(p);
+                if !p.current().is_terminator()
+                    && !p.eat_if(SyntaxKind::Comma)
+                    && !p[arm].erroneous()
+                {
+                    p.expected("comma or line break");
+                }
+            });
+        }
+        p.expect_closing_delimiter(m, SyntaxKind::RightBrace);
+    });
+}
+
+/// Parses a single arm of a match expression: `(x, y) if x < y => y`.
+fn match_arm(p: &mut Parser) {
+    let m = p.marker();
+    pattern(p, PatternKind::Match, &mut HashSet::new(), None);
+
+    // In the guard, an arrow introduces the arm's body rather than turning
+    // a trailing identifier or parenthesized expression into a closure.
+    if p.eat_if(SyntaxKind::If) {
+        p.without_closures(code_expr);
+    }
+
+    if p.expect(SyntaxKind::Arrow) {
+        code_expr(p);
+    }
+
+    p.wrap(m, SyntaxKind::MatchArm);
+}
+
 /// Parses a module import: `import "utils.typ": a, b, c`.
 fn module_import(p: &mut Parser) {
     let m = p.marker();
@@ -1073,7 +1189,7 @@ fn expr_with_paren(p: &mut Parser, atomic: bool) {
     // again, we can then just restore this result. In this way, no
     // parenthesized expression is parsed more than twice, leading to a worst
     // case running time of O(2n).
-    if p.at(SyntaxKind::Arrow) {
+    if p.closures && p.at(SyntaxKind::Arrow) {
         p.restore(checkpoint);
         let m = p.marker();
         params(p);
@@ -1085,7 +1201,7 @@ fn expr_with_paren(p: &mut Parser, atomic: bool) {
     } else if p.at(SyntaxKind::Eq) && kind != SyntaxKind::Parenthesized {
         p.restore(checkpoint);
         let m = p.marker();
-        destructuring_or_parenthesized(p, true, &mut HashSet::new());
+        destructuring_or_parenthesized(p, PatternKind::Reassignment, &mut HashSet::new());
         if !p.expect(SyntaxKind::Eq) {
             return;
         }
@@ -1331,7 +1447,7 @@ fn param<'s>(p: &mut Parser<'s>, seen: &mut HashSet<&'s str>, sink: &mut bool) {
     // Parses argument sink: `..sink`.
     if p.eat_if(SyntaxKind::Dots) {
         if p.at_set(set::PATTERN_LEAF) {
-            pattern_leaf(p, false, seen, Some("parameter"));
+            pattern_leaf(p, PatternKind::Binding, seen, Some("parameter"));
         }
         p.wrap(m, SyntaxKind::Spread);
         if mem::replace(sink, true) {
@@ -1342,7 +1458,7 @@ fn param<'s>(p: &mut Parser<'s>, seen: &mut HashSet<&'s str>, sink: &mut bool) {
 
     // Parses a normal positional parameter or a parameter name.
     let was_at_pat = p.at_set(set::PATTERN);
-    pattern(p, false, seen, Some("parameter"));
+    pattern(p, PatternKind::Binding, seen, Some("parameter"));
 
     // Parses a named parameter: `thickness: 12pt`.
     if p.eat_if(SyntaxKind::Colon) {
@@ -1356,41 +1472,59 @@ fn param<'s>(p: &mut Parser<'s>, seen: &mut HashSet<&'s str>, sink: &mut bool) {
     }
 }
 
-/// Parses a binding or reassignment pattern.
+/// The different kinds of patterns.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum PatternKind {
+    /// A binding pattern in a let binding, for loop, or parameter list.
+    Binding,
+    /// A reassignment pattern in a destructuring assignment.
+    Reassignment,
+    /// A pattern in a match arm, which may additionally contain literals and
+    /// typed patterns.
+    Match,
+}
+
+/// Parses a binding, reassignment, or match pattern.
 fn pattern<'s>(
     p: &mut Parser<'s>,
-    reassignment: bool,
+    kind: PatternKind,
     seen: &mut HashSet<&'s str>,
     dupe: Option<&'s str>,
 ) {
     match p.current() {
         SyntaxKind::Underscore => p.eat(),
-        SyntaxKind::LeftParen => destructuring_or_parenthesized(p, reassignment, seen),
-        _ => pattern_leaf(p, reassignment, seen, dupe),
+        SyntaxKind::LeftParen => destructuring_or_parenthesized(p, kind, seen),
+        _ if kind == PatternKind::Match => match_pattern_leaf(p, seen),
+        _ => pattern_leaf(p, kind, seen, dupe),
     }
 }
 
 /// Parses a destructuring pattern or just a parenthesized pattern.
 fn destructuring_or_parenthesized<'s>(
     p: &mut Parser<'s>,
-    reassignment: bool,
+    kind: PatternKind,
     seen: &mut HashSet<&'s str>,
 ) {
     let mut sink = false;
     let mut count = 0;
     let mut maybe_just_parens = true;
 
+    let item_set = match kind {
+        PatternKind::Match => set::MATCH_DESTRUCTURING_ITEM,
+        _ => set::DESTRUCTURING_ITEM,
+    };
+
     let m = p.marker();
     p.with_nl_mode(AtNewline::Continue, |p| {
         p.assert(SyntaxKind::LeftParen);
 
         while !p.current().is_terminator() {
-            if !p.at_set(set::DESTRUCTURING_ITEM) {
+            if !p.at_set(item_set) {
                 p.unexpected();
                 continue;
             }
 
-            destructuring_item(p, reassignment, seen, &mut maybe_just_parens, &mut sink);
+            destructuring_item(p, kind, seen, &mut maybe_just_parens, &mut sink);
             count += 1;
 
             if !p.current().is_terminator() && p.expect(SyntaxKind::Comma) {
@@ -1411,7 +1545,7 @@ fn destructuring_or_parenthesized<'s>(
 /// Parses an item in a destructuring pattern.
 fn destructuring_item<'s>(
     p: &mut Parser<'s>,
-    reassignment: bool,
+    kind: PatternKind,
     seen: &mut HashSet<&'s str>,
     maybe_just_parens: &mut bool,
     sink: &mut bool,
@@ -1421,7 +1555,7 @@ fn destructuring_item<'s>(
     // Parse destructuring sink: `..rest`.
     if p.eat_if(SyntaxKind::Dots) {
         if p.at_set(set::PATTERN_LEAF) {
-            pattern_leaf(p, reassignment, seen, None);
+            pattern_leaf(p, kind, seen, None);
         }
         p.wrap(m, SyntaxKind::Spread);
         if mem::replace(sink, true) {
@@ -1438,7 +1572,7 @@ fn destructuring_item<'s>(
     let checkpoint = p.checkpoint();
     if !(p.eat_if(SyntaxKind::Ident) && p.at(SyntaxKind::Colon)) {
         p.restore(checkpoint);
-        pattern(p, reassignment, seen, None);
+        pattern(p, kind, seen, None);
     }
 
     // Parse named destructuring item.
@@ -1448,7 +1582,7 @@ fn destructuring_item<'s>(
             p[m].expected("identifier");
         }
 
-        pattern(p, reassignment, seen, None);
+        pattern(p, kind, seen, None);
         p.wrap(m, SyntaxKind::Named);
         *maybe_just_parens = false;
     }
@@ -1458,7 +1592,7 @@ fn destructuring_item<'s>(
 /// depending on whether it's a binding or reassignment pattern.
 fn pattern_leaf<'s>(
     p: &mut Parser<'s>,
-    reassignment: bool,
+    kind: PatternKind,
     seen: &mut HashSet<&'s str>,
     dupe: Option<&'s str>,
 ) {
@@ -1478,7 +1612,7 @@ fn pattern_leaf<'s>(
     // instead of going through its pieces one by one.
     code_expr_prec(p, true, 0);
 
-    if !reassignment {
+    if kind != PatternKind::Reassignment {
         let node = &mut p[m];
         if node.kind() == SyntaxKind::Ident {
             if !seen.insert(text) {
@@ -1493,6 +1627,66 @@ fn pattern_leaf<'s>(
     }
 }
 
+/// Parses a leaf in a match pattern - either a literal, a typed pattern like
+/// `int(n)`, a constant like `alignment.left`, or a binding.
+fn match_pattern_leaf<'s>(p: &mut Parser<'s>, seen: &mut HashSet<&'s str>) {
+    let m = p.marker();
+    match p.current() {
+        SyntaxKind::None
+        | SyntaxKind::Auto
+        | SyntaxKind::Bool
+        | SyntaxKind::Int
+        | SyntaxKind::Float
+        | SyntaxKind::Numeric
+        | SyntaxKind::Str => p.eat(),
+        SyntaxKind::Minus => {
+            p.eat();
+            if p.at_set(syntax_set!(Int, Float, Numeric)) {
+                p.eat();
+            } else {
+                p.expected("number");
+            }
+            p.wrap(m, SyntaxKind::Unary);
+        }
+        SyntaxKind::Ident => {
+            let text = p.current_text();
+            p.eat();
+
+            while p.directly_at(SyntaxKind::Dot)
+                && p.lexer.clone().next().0 == SyntaxKind::Ident
+            {
+                p.eat();
+                p.eat();
+                p.wrap(m, SyntaxKind::FieldAccess);
+            }
+
+            if p.directly_at(SyntaxKind::LeftParen) {
+                typed_pattern(p, m, seen);
+            } else if p[m].kind() == SyntaxKind::Ident && !seen.insert(text) {
+                p[m].convert_to_error(eco_format!("duplicate binding: {text}"));
+            }
+        }
+        _ => pattern_leaf(p, PatternKind::Match, seen, None),
+    }
+}
+
+/// Parses the parenthesized part of a typed pattern: `int(n)` or `str()`. The
+/// type was already parsed starting at the marker `m`.
+fn typed_pattern<'s>(p: &mut Parser<'s>, m: Marker, seen: &mut HashSet<&'s str>) {
+    let m2 = p.marker();
+    p.with_nl_mode(AtNewline::Continue, |p| {
+        p.assert(SyntaxKind::LeftParen);
+        if p.at_set(set::MATCH_PATTERN) {
+            pattern(p, PatternKind::Match, seen, None);
+        }
+        while !p.current().is_terminator() {
+            p.unexpected();
+        }
+        p.expect_closing_delimiter(m2, SyntaxKind::RightParen);
+    });
+    p.wrap(m, SyntaxKind::TypedPattern);
+}
+
 /// Manages parsing a stream of tokens into a tree of [`SyntaxNode`]s.
 ///
 /// The implementation presents an interface that investigates a current `token`
@@ -1551,6 +1745,10 @@ struct Parser<'s> {
     /// backtracking similar to packrat parsing. See comments above in
     /// [`expr_with_paren`].
     memo: MemoArena,
+    /// Whether an identifier or parenthesized expression followed by an arrow
+    /// starts a closure. This is disabled for match guards, where the arrow
+    /// instead introduces the arm's body, and re-enabled within delimiters.
+    closures: bool,
 }
 
 /// A single token returned from the lexer with a cached [`SyntaxKind`] and a
@@ -1659,6 +1857,7 @@ impl<'s> Parser<'s> {
             balanced: true,
             nodes,
             memo: Default::default(),
+            closures: true,
         }
     }
 
@@ -1853,9 +2052,11 @@ impl<'s> Parser<'s> {
     /// This function effectively repurposes the call stack as a stack of modes.
     fn with_nl_mode(&mut self, mode: AtNewline, func: impl FnOnce(&mut Parser<'s>)) {
         let previous = self.nl_mode;
+        let closures = mem::replace(&mut self.closures, true);
         self.nl_mode = mode;
         func(self);
         self.nl_mode = previous;
+        self.closures = closures;
         if let Some(newline) = self.token.newline {
             if mode != previous {
                 // Restore our actual token's kind or insert a fake end.
@@ -1869,6 +2070,14 @@ impl<'s> Parser<'s> {
         }
     }
 
+    /// Parse without starting closures at arrows that follow an identifier or
+    /// a parenthesized expression (outside of nested delimiters).
+    fn without_closures(&mut self, func: impl FnOnce(&mut Parser<'s>)) {
+        let previous = mem::replace(&mut self.closures, false);
+        func(self);
+        self.closures = previous;
+    }
+
     /// Move the lexer forward and prepare the current token. In Code, this
     /// might insert a temporary [`SyntaxKind::End`] based on our newline mode.
     ///
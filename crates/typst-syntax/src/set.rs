@@ -27,6 +27,11 @@ impl SyntaxSet {
         Self(self.0 | other.0)
     }
 
+    /// Remove a syntax kind from the set.
+    pub const fn remove(self, kind: SyntaxKind) -> Self {
+        Self(self.0 & !bit(kind))
+    }
+
     /// Whether the set contains the given syntax kind.
     pub const fn contains(&self, kind: SyntaxKind) -> bool {
         (kind as u8) < BITS && (self.0 & bit(kind)) != 0
@@ -138,6 +143,18 @@ pub const DESTRUCTURING_ITEM: SyntaxSet = PATTERN.add(SyntaxKind::Dots);
 pub const PATTERN: SyntaxSet =
     PATTERN_LEAF.add(SyntaxKind::LeftParen).add(SyntaxKind::Underscore);
 
+/// Syntax kinds that can start the matched value of a match expression. Code
+/// blocks are excluded so that `if match {..}` keeps working with a variable
+/// named `match`.
+pub const MATCH_SUBJECT: SyntaxSet =
+    ATOMIC_CODE_PRIMARY.union(UNARY_OP).remove(SyntaxKind::LeftBrace);
+
+/// Syntax kinds that can start a pattern in a match arm.
+pub const MATCH_PATTERN: SyntaxSet = PATTERN.add(SyntaxKind::Minus);
+
+/// Syntax kinds that can start a destructuring item in a match arm.
+pub const MATCH_DESTRUCTURING_ITEM: SyntaxSet = MATCH_PATTERN.add(SyntaxKind::Dots);
+
 /// Syntax kinds that can start a pattern leaf.
 pub const PATTERN_LEAF: SyntaxSet = ATOMIC_CODE_EXPR;
 
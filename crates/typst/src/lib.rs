@@ -52,7 +52,7 @@ use typst_library::html::HtmlDocument;
 use typst_library::introspection::Introspector;
 use typst_library::layout::PagedDocument;
 use typst_library::routines::Routines;
-use typst_syntax::{FileId, Span};
+use typst_syntax::{FileId, Span, Spanned};
 use typst_timing::{timed, TimingScope};
 
 use crate::foundations::{Target, TargetElem};
@@ -64,13 +64,27 @@ use crate::model::DocumentInfo;
 /// - Returns `Err(errors)` if there were fatal errors.
 #[typst_macros::time]
 pub fn compile<D>(world: &dyn World) -> Warned<SourceResult<D>>
+where
+    D: Document,
+{
+    compile_with_unknown_families(world).0
+}
+
+/// Compile sources like [`compile`], but also return the requested font
+/// families that are not available, together with the span of the font list
+/// that requested them.
+#[typst_macros::time]
+pub fn compile_with_unknown_families<D>(
+    world: &dyn World,
+) -> (Warned<SourceResult<D>>, EcoVec<Spanned<EcoString>>)
 where
     D: Document,
 {
     let mut sink = Sink::new();
     let output = compile_impl::<D>(world.track(), Traced::default().track(), &mut sink)
         .map_err(deduplicate);
-    Warned { output, warnings: sink.warnings() }
+    let families = sink.unknown_families();
+    (Warned { output, warnings: sink.warnings() }, families)
 }
 
 /// Compiles sources and returns all values and styles observed at the given
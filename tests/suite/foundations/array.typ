@@ -531,3 +531,59 @@
 --- array-reduce-unexpected-argument ---
 // Error: 19-21 unexpected argument
 #(1, 2, 3).reduce(() => none)
+
+--- array-scan ---
+// Test the `scan` method.
+#test(().scan(0, (s, x) => s + x), ())
+#test((1, 2, 3, 4).scan(0, (s, x) => s + x), (1, 3, 6, 10))
+
+--- array-flat-map ---
+// Test the `flat-map` method.
+#test(().flat-map(x => (x, x)), ())
+#test((1, 2, 3).flat-map(x => (x,) * x), (1, 2, 2, 3, 3, 3))
+#test((1, 2).flat-map(x => ((x,),)), ((1,), (2,)))
+#test((1, 2).flat-map(x => x * 10), (10, 20))
+
+--- array-partition ---
+// Test the `partition` method.
+#test(().partition(calc.even), ((), ()))
+#test(range(6).partition(calc.even), ((0, 2, 4), (1, 3, 5)))
+
+--- array-partition-bad-type ---
+// Error: 19-20 expected boolean, found integer
+#(1, 2).partition(x => x)
+
+--- array-group-by ---
+// Test the `group-by` method.
+#test(().group-by(str), (:))
+#test(
+  ("apple", "avocado", "banana", "blueberry", "cherry").group-by(w => w.first()),
+  (a: ("apple", "avocado"), b: ("banana", "blueberry"), c: ("cherry",)),
+)
+#test(range(5).group-by(x => if calc.even(x) { "even" } else { "odd" }).keys(), ("even", "odd"))
+
+--- array-group-by-bad-key ---
+// Error: 18-19 expected string, found integer
+#(1, 2).group-by(x => x)
+
+--- array-unique-by ---
+// Test the `unique-by` method.
+#test(().unique-by(x => x), ())
+#test((1, -1, 2, -2, 1).unique-by(calc.abs), (1, 2))
+#test(((a: 1, b: 2), (a: 1, b: 3), (a: 2, b: 4)).unique-by(it => it.a), ((a: 1, b: 2), (a: 2, b: 4)))
+
+--- array-max-by-min-by ---
+// Test the `max-by` and `min-by` methods.
+#test(("fig", "banana", "kiwi").max-by(str.len), "banana")
+#test(("fig", "banana", "kiwi").min-by(str.len), "fig")
+#test(("ab", "cd").max-by(str.len), "ab")
+#test(("ab", "cd").min-by(str.len), "ab")
+#test(().max-by(x => x, default: none), none)
+
+--- array-max-by-empty ---
+// Error: 2-19 cannot find extremum of empty array with no default
+#().min-by(x => x)
+
+--- array-max-by-uncomparable ---
+// Error: 2-25 cannot compare string and integer
+#(1, "a").max-by(x => x)
@@ -264,3 +264,51 @@
 --- issue-3232-dict-empty ---
 #block(outset: (:), [Hi]) // Ok
 #box(radius: (:), [Hi]) // Ok
+
+--- dict-map ---
+// Test the `map` method.
+#test((:).map((k, v) => v), (:))
+#test((a: 1, b: 2).map((k, v) => v * 2), (a: 2, b: 4))
+#test((a: 1, b: 2).map((k, v) => k + str(v)), (a: "a1", b: "b2"))
+
+--- dict-filter ---
+// Test the `filter` method.
+#test((:).filter((k, v) => true), (:))
+#test((apple: 0, pear: 3, plum: 5).filter((k, v) => v > 0), (pear: 3, plum: 5))
+#test((apple: 0, pear: 3).filter((k, v) => k.starts-with("a")), (apple: 0))
+
+--- dict-filter-bad-type ---
+// Error: 16-22 expected boolean, found integer
+#(a: 1).filter((k, v) => v)
+
+--- dict-merge ---
+// Test the `merge` method.
+#test((:).merge(), (:))
+#test((a: 1).merge((b: 2), (a: 3)), (a: 3, b: 2))
+#test(
+  (font: (size: 11pt, family: "Libertinus Serif"), lang: "en").merge((font: (size: 12pt))),
+  (font: (size: 12pt, family: "Libertinus Serif"), lang: "en"),
+)
+#test((a: (b: 1)).merge((a: 2)), (a: 2))
+#test((a: 1).merge((a: (b: 2))), (a: (b: 2)))
+#test((a: (b: (c: 1, d: 2))).merge((a: (b: (d: 3)))), (a: (b: (c: 1, d: 3))))
+
+--- dict-get-path ---
+// Test the `get-path` method.
+#let config = (authors: ((name: "Ada"), (name: "Grace")), title: "Notes")
+#test(config.get-path("title"), "Notes")
+#test(config.get-path(("authors", 1, "name")), "Grace")
+#test(config.get-path(("authors", -1, "name")), "Grace")
+#test(config.get-path("authors.0.name"), "Ada")
+#test(config.get-path(()), config)
+#test(config.get-path("publisher.name", default: none), none)
+#test(config.get-path(("authors", 5), default: 0), 0)
+#test(config.get-path(("title", "x"), default: 0), 0)
+
+--- dict-get-path-missing ---
+// Error: 2-32 path does not exist at key "publisher" and no default value was specified
+#(a: 1).get-path("publisher.x")
+
+--- dict-get-path-missing-index ---
+// Error: 2-30 path does not exist at index 2 and no default value was specified
+#(a: (1,)).get-path(("a", 2))
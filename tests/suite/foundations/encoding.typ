@@ -0,0 +1,50 @@
+// Test the `encoding` module.
+
+--- encoding-base64 ---
+#test(encoding.base64(""), "")
+#test(encoding.base64("Typst"), "VHlwc3Q=")
+#test(encoding.base64("Typst", padding: false), "VHlwc3Q")
+#test(encoding.base64(bytes((251, 255))), "+/8=")
+#test(encoding.base64(bytes((251, 255)), url-safe: true), "-_8=")
+#test(str(encoding.base64-decode("VHlwc3Q=")), "Typst")
+#test(str(encoding.base64-decode("VHlwc3Q")), "Typst")
+#test(encoding.base64-decode("-_8", url-safe: true), bytes((251, 255)))
+
+--- encoding-base64-decode-invalid ---
+// Error: 2-32 failed to decode base64 (Invalid symbol 33, offset 1.)
+#encoding.base64-decode("V!==")
+
+--- encoding-hex ---
+#test(encoding.hex(""), "")
+#test(encoding.hex("Hi"), "4869")
+#test(encoding.hex(bytes((0, 171, 205, 255))), "00abcdff")
+#test(encoding.hex(bytes((171, 205)), upper: true), "ABCD")
+#test(encoding.hex-decode("00abCDff"), bytes((0, 171, 205, 255)))
+#test(encoding.hex-decode(""), bytes(()))
+
+--- encoding-hex-decode-odd ---
+// Error: 2-28 hexadecimal text must have an even number of digits
+#encoding.hex-decode("abc")
+
+--- encoding-hex-decode-invalid ---
+// Error: 2-29 invalid hexadecimal digit "g"
+#encoding.hex-decode("0g00")
+
+--- encoding-hex-decode-non-ascii ---
+// Error: 2-27 invalid hexadecimal digit "é"
+#encoding.hex-decode("0é")
+
+--- encoding-digests ---
+#test(
+  encoding.hex(encoding.sha256("abc")),
+  "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+)
+#test(encoding.hex(encoding.sha1("abc")), "a9993e364706816aba3e25717850c26c9cd0d89d")
+#test(encoding.hex(encoding.md5("abc")), "900150983cd24fb0d6963f7d28e17f72")
+#test(encoding.sha256(bytes("abc")), encoding.sha256("abc"))
+#test(encoding.md5("").len(), 16)
+
+--- encoding-crc32 ---
+#test(encoding.crc32(""), 0)
+#test(encoding.crc32("123456789"), 3421780262)
+#test(encoding.crc32(bytes("123456789")), 0xCBF43926)
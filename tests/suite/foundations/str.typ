@@ -335,6 +335,110 @@
 #test("abc".rev(), "cba")
 #test("ax̂e".rev(), "ex̂a")
 
+--- string-pad ---
+// Test the `pad-start` and `pad-end` methods.
+#test("7".pad-start(3, fill: "0"), "007")
+#test("ab".pad-end(4, fill: "."), "ab..")
+#test("ab".pad-start(4), "  ab")
+#test("Typst".pad-start(3), "Typst")
+#test("Typst".pad-end(5), "Typst")
+#test("ax̂".pad-start(3, fill: "-"), "-ax̂")
+
+--- string-pad-bad-fill ---
+// Error: 25-29 expected exactly one character
+#"a".pad-start(3, fill: "ab")
+
+--- string-pad-width-too-large ---
+// Error: 2-28 width 10000000000 is too large
+#"a".pad-start(10000000000)
+
+--- string-pad-end-width-too-large ---
+// Error: 2-20 width 70000 is too large
+#"a".pad-end(70000)
+
+--- string-format ---
+// Test the `format` method.
+#test("".format(), "")
+#test("plain".format(1, 2), "plain")
+#test("{} has {} items".format("Cart", 3), "Cart has 3 items")
+#test("{0}, {0}, {1}!".format("Hip", "hooray"), "Hip, Hip, hooray!")
+#test("{name} is {age}".format(name: "Ada", age: 36), "Ada is 36")
+#test("{{{}}}".format(1), "{1}")
+#test("{}".format(<intro>), "intro")
+#test("{}".format(str), "string")
+
+--- string-format-numbers ---
+#test("{}".format(-5), "−5")
+#test("{:+}".format(5), "+5")
+#test("{:+}".format(-5), "−5")
+#test("{:05}".format(42), "00042")
+#test("{:05}".format(-42), "−0042")
+#test("{:+05}".format(42), "+0042")
+#test("{:.2}".format(3.14159), "3.14")
+#test("{:+.1}".format(2.0), "+2.0")
+#test("{}".format(2.5), "2.5")
+#test("{:.2}".format(-0.5), "−0.50")
+#test("{:.3}".format(decimal("1.5")), "1.500")
+#test("{}".format(decimal("-2.25")), "−2.25")
+#test("{:08.2}".format(3.14159), "00003.14")
+
+--- string-format-lengths ---
+#test("{}".format(12pt), "12pt")
+#test("{:.1}".format(12.26pt), "12.3pt")
+#test("{:.2}".format(-1.5em), "−1.50em")
+#test("{:.1}".format(1em + 2pt), "1.0em + 2.0pt")
+#test("{:.1}".format(-1em + 2pt), "−1.0em + 2.0pt")
+#test("{}".format(1.5em - 2pt), "1.5em + −2pt")
+
+--- string-format-alignment ---
+#test("{:5}|".format("ab"), "ab   |")
+#test("{:5}|".format(12), "   12|")
+#test("{:<5}|".format(12), "12   |")
+#test("{:>5}|".format("ab"), "   ab|")
+#test("{:^6}|".format("ab"), "  ab  |")
+#test("{:*^7}|".format("ab"), "**ab***|")
+#test("{:-<4}|".format(1), "1---|")
+#test("{name:>8}|".format(name: "Typst"), "   Typst|")
+#test("{:.3}".format("Typst"), "Typ")
+#test("{:>6.3}".format("Typst"), "   Typ")
+
+--- string-format-missing-positional ---
+// Error: 2-19 missing positional argument 1
+#"{} {}".format(1)
+
+--- string-format-missing-named ---
+// Error: 2-20 missing named argument "x"
+#"{x}".format(y: 1)
+
+--- string-format-unclosed ---
+// Error: 2-17 unclosed placeholder in format string
+#"{:5".format(1)
+
+--- string-format-unmatched ---
+// Error: 2-15 unmatched closing brace in format string
+// Hint: 2-15 use `}}` to insert a literal closing brace
+#"}".format(1)
+
+--- string-format-bad-spec ---
+// Error: 2-19 invalid format specification "5x"
+#"{:5x}".format(1)
+
+--- string-format-width-too-large ---
+// Error: 2-22 number 70000 in format specification is too large
+#"{:70000}".format(1)
+
+--- string-format-precision-too-large ---
+// Error: 2-30 number 1000000000 in format specification is too large
+#"{:.1000000000}".format(1.0)
+
+--- string-format-int-precision ---
+// Error: 2-19 precision is not supported for integers
+#"{:.2}".format(1)
+
+--- string-format-bad-value ---
+// Error: 2-19 cannot format content
+#"{}".format([Hi])
+
 --- string-unclosed ---
 // Error: 2-2:1 unclosed string
 #"hello\"
@@ -129,3 +129,76 @@ Hallo
 A
 #colbreak(weak: true)
 B
+
+--- columns-balance ---
+#let filled(breakable) = columns(2, gutter: 0pt, balance: true, {
+  set block(width: 100%, spacing: 0pt, breakable: breakable)
+  for _ in range(5) { block(height: 10pt) }
+})
+#context test(measure(filled(false), width: 100pt).height, 30pt)
+#context test(measure(filled(true), width: 100pt).height, 25pt)
+
+--- columns-balance-float ---
+// The float stays at the top of the first column and the remaining blocks are
+// balanced around it.
+#place(hide(block(width: 100pt, columns(2, gutter: 0pt, balance: true)[
+  #set block(width: 100%, height: 10pt, spacing: 0pt, breakable: false)
+  #place(top, float: true, clearance: 0pt)[#block[] <f>]
+  #block[] <a>
+  #block[] <b>
+  #block[] <c>
+  #block[] <d>
+])))
+#context {
+  test(locate(<f>).position(), (page: 1, x: 10pt, y: 10pt))
+  test(locate(<a>).position(), (page: 1, x: 10pt, y: 20pt))
+  test(locate(<b>).position(), (page: 1, x: 10pt, y: 30pt))
+  test(locate(<c>).position(), (page: 1, x: 60pt, y: 10pt))
+  test(locate(<d>).position(), (page: 1, x: 60pt, y: 20pt))
+}
+
+--- columns-balance-text ---
+#set page(height: 100pt, columns: 2)
+#set columns(balance: true)
+#lorem(30)
+
+--- columns-balance-all-text ---
+// Only the first page ends in an explicit page break, so it is balanced along
+// with the last one. The second page breaks across into the third one.
+#set page(height: 80pt, columns: 2)
+#set columns(balance: "all")
+#lorem(12)
+#pagebreak()
+#lorem(60)
+
+--- columns-balance-parent-float ---
+// A parent-scoped float requests a relayout while the columns are balanced.
+#set page(height: 120pt, columns: 2)
+#set columns(balance: true)
+#lorem(10)
+#place(
+  top + center,
+  scope: "parent",
+  float: true,
+  rect(width: 80%, height: 20pt, fill: aqua),
+)
+#lorem(10)
+
+--- columns-balance-off ---
+#let filled = columns(2, gutter: 0pt, {
+  set block(width: 100%, spacing: 0pt)
+  for _ in range(5) { block(height: 10pt) }
+})
+#context test(measure(filled, width: 100pt).height, 50pt)
+
+--- columns-balance-fixed-height ---
+#let filled = columns(2, balance: true, block(height: 30pt, breakable: false))
+#context test(measure(filled, width: 100pt, height: 100pt).height, 30pt)
+
+--- columns-balance-value ---
+#test(columns(balance: "all")[].balance, "all")
+#test(columns(balance: true)[].balance, true)
+
+--- columns-balance-invalid ---
+// Error: 19-25 expected boolean or "all"
+#columns(balance: "some")[]
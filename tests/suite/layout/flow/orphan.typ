@@ -39,3 +39,93 @@ A
 // to another page.
 #set page(width: 16cm)
 #block(height: 30pt, fill: aqua, columns(2, lorem(19)))
+
+--- flow-par-orphans-count ---
+// The two lines that would fit into the first column are not enough.
+#place(hide(block(height: 35pt, columns(2, gutter: 0pt, {
+  set text(top-edge: 10pt, bottom-edge: 0pt)
+  set par(leading: 0pt, spacing: 0pt, orphans: 3)
+  block(height: 10pt, above: 0pt, below: 0pt)
+  [#metadata(none) <m>A \ B \ C \ D \ E]
+}))))
+#context test(locate(<m>).position().x, 60pt)
+
+--- flow-par-widows-count ---
+// Three lines would fit into the first column, but then too few would be
+// left for the second one.
+#place(hide(block(height: 35pt, columns(2, gutter: 0pt, {
+  set text(top-edge: 10pt, bottom-edge: 0pt)
+  set par(leading: 0pt, widows: 3)
+  [A \ B \ #metadata(none) <m>C \ D \ E]
+}))))
+#context test(locate(<m>).position().x, 60pt)
+
+--- flow-par-orphans-widows-value ---
+#set par(orphans: 3, widows: 4)
+#context test((par.orphans, par.widows), (3, 4))
+
+--- flow-par-orphans-invalid ---
+// Error: 19-20 number must be positive
+#set par(orphans: 0)
+
+--- flow-block-attached ---
+// The attached block pulls the one before it into the next column.
+#place(hide(block(height: 100pt, columns(2, gutter: 0pt, {
+  set block(spacing: 0pt, breakable: false)
+  block(height: 40pt)
+  block(height: 40pt)[#metadata(none) <m>]
+  block(height: 40pt, attached: true)
+}))))
+#context test(locate(<m>).position().x, 60pt)
+
+--- flow-list-keep ---
+// The short list moves to the next column as a whole.
+#place(hide(block(height: 35pt, columns(2, gutter: 0pt, {
+  set text(top-edge: 10pt, bottom-edge: 0pt)
+  set par(leading: 0pt)
+  set list(keep: 3)
+  block(height: 10pt, above: 0pt, below: 0pt)
+  [- #metadata(none) <m>A
+   - B
+   - C]
+}))))
+#context test(locate(<m>).position().x > 60pt, true)
+
+--- flow-par-orphans-widows-rendered ---
+#set page(width: 60pt, height: 110pt)
+#set par(orphans: 3, widows: 3)
+
+// Fits fully onto the first page.
+#set text(blue)
+#lines(5)
+
+// Two lines would fit, but three are required, so all move on.
+#set text(maroon)
+#lines(6)
+
+// Three lines fit, but then only two would be left for the next page.
+#set text(olive)
+#lines(5)
+
+--- flow-block-attached-rendered ---
+// The caption-like block stays with the block before it.
+#set page(width: 100pt, height: 100pt)
+#set block(breakable: false)
+#block(width: 100%, height: 40pt, fill: aqua)
+#block(width: 100%, height: 30pt, fill: teal)
+#block(attached: true)[Attached to teal]
+
+--- flow-list-keep-rendered ---
+// Lists with at most three items move to the next page as a whole.
+#set page(width: 100pt, height: 70pt)
+#set list(keep: 3)
+#set enum(keep: 3)
+#block(height: 30pt, width: 100%, fill: aqua)
+- First
+- Second
+- Third
+
++ One
++ Two
++ Three
++ Four
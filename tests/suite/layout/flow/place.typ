@@ -321,6 +321,126 @@ C
 // Error: 2-34 vertical floating placement must be `auto`, `top`, or `bottom`
 #place(right, float: true)[Hello]
 
+--- place-side-not-float ---
+// Error: 2-26 side placement is only available for floating placement
+// Hint: 2-26 you can enable floating placement with `place(float: true, ..)`
+#place(side: left)[Hello]
+
+--- place-side-parent ---
+// Error: 2-56 side floats cannot be parent-scoped
+#place(float: true, side: left, scope: "parent")[Hello]
+
+--- place-side-invalid ---
+// Error: 14-17 must be `start`, `end`, `left`, or `right`
+#place(side: top, float: true)[Hello]
+
+--- place-side-value ---
+#test(place(float: true, side: "inside")[A].side, "inside")
+#test(place(float: true, side: end)[A].side, end)
+
+--- place-float-policy-value ---
+#test(place(float: true, defer: true)[A].defer, true)
+#test(place(float: true, float-page: true)[A].float-page, true)
+#test(place(float: true, max-count: 2)[A].max-count, 2)
+#test(place(float: true, max-fraction: 70%)[A].max-fraction, 70%)
+
+--- place-float-max-count-zero ---
+// Error: 32-33 number must be positive
+#place(float: true, max-count: 0)[A]
+
+--- place-float-page-parent ---
+// Error: 2-62 float pages cannot be parent-scoped
+#place(float: true, float-page: true, scope: "parent")[Hello]
+
+--- place-float-max-count ---
+// The second float exceeds the count and moves to the next column.
+#set page(height: 20pt, margin: (x: 10pt, top: 0pt, bottom: -40pt), columns: 2)
+#set columns(gutter: 0pt)
+#set place(clearance: 0pt, max-count: 1)
+#hide[
+  #place(top, float: true, block(height: 10pt)[#metadata(none) <a>])
+  #place(top, float: true, block(height: 10pt)[#metadata(none) <b>])
+]
+#context {
+  test(locate(<a>).position(), (page: 1, x: 10pt, y: 0pt))
+  test(locate(<b>).position(), (page: 1, x: 60pt, y: 0pt))
+}
+
+--- place-float-max-fraction ---
+// Together, the two floats would take up more than half of the column.
+#set page(height: 20pt, margin: (x: 10pt, top: 0pt, bottom: -40pt), columns: 2)
+#set columns(gutter: 0pt)
+#set place(clearance: 0pt, max-fraction: 50%)
+#hide[
+  #place(top, float: true, block(height: 20pt)[#metadata(none) <a>])
+  #place(top, float: true, block(height: 20pt)[#metadata(none) <b>])
+]
+#context {
+  test(locate(<a>).position(), (page: 1, x: 10pt, y: 0pt))
+  test(locate(<b>).position(), (page: 1, x: 60pt, y: 0pt))
+}
+
+--- place-float-defer-top ---
+// A deferred top float doesn't move above the preceding block.
+#set page(height: 20pt, margin: (x: 10pt, top: 0pt, bottom: -40pt), columns: 2)
+#set columns(gutter: 0pt)
+#set place(clearance: 0pt, defer: true)
+#hide[
+  #block(height: 10pt)
+  #place(top, float: true, block(height: 10pt)[#metadata(none) <a>])
+]
+#context test(locate(<a>).position(), (page: 1, x: 60pt, y: 0pt))
+
+--- place-float-defer-auto ---
+// A deferred float with automatic alignment goes to the bottom.
+#set page(height: 20pt, margin: (x: 10pt, top: 0pt, bottom: -40pt), columns: 2)
+#set columns(gutter: 0pt)
+#set place(clearance: 0pt, defer: true)
+#hide[
+  #block(height: 10pt)
+  #place(auto, float: true, block(height: 10pt)[#metadata(none) <a>])
+]
+#context test(locate(<a>).position(), (page: 1, x: 10pt, y: 50pt))
+
+--- place-float-page ---
+// The float gets the next column to itself.
+#set page(height: 20pt, margin: (x: 10pt, top: 0pt, bottom: -40pt), columns: 2)
+#set columns(gutter: 0pt)
+#set place(clearance: 0pt)
+#set block(spacing: 0pt)
+#hide[
+  #block(height: 10pt)[#metadata(none) <a>]
+  #place(float: true, float-page: true, block(height: 10pt)[#metadata(none) <b>])
+  #block(height: 10pt)[#metadata(none) <c>]
+]
+#context {
+  test(locate(<a>).position(), (page: 1, x: 10pt, y: 0pt))
+  test(locate(<b>).position(), (page: 1, x: 60pt, y: 0pt))
+  test(locate(<c>).position(), (page: 1, x: 10pt, y: 10pt))
+}
+
+--- place-float-policy-rendered ---
+// The second figure exceeds the maximum count and moves to the next page. The
+// deferred float stays below the text before it and the float page collects
+// the large figure.
+#set page(width: 150pt, height: 160pt)
+#show figure: set place(max-count: 1)
+#figure(placement: top, rect(width: 60%, fill: aqua)[A], caption: [First])
+#figure(placement: top, rect(width: 60%, fill: teal)[B], caption: [Second])
+#lorem(10)
+#place(auto, float: true, defer: true, rect(width: 100%, fill: eastern)[Deferred])
+#lorem(10)
+#place(float: true, float-page: true, rect(width: 100%, height: 100pt, fill: olive)[Page])
+#lorem(10)
+
+--- place-float-max-fraction-rendered ---
+// The floats may take up at most half of the page together.
+#set page(width: 150pt, height: 120pt)
+#set place(max-fraction: 50%)
+#place(top, float: true, rect(width: 100%, height: 40pt, fill: aqua))
+#place(top, float: true, rect(width: 100%, height: 40pt, fill: teal))
+#lorem(20)
+
 --- place-flush ---
 #set page(height: 120pt)
 #let floater(align, height) = place(
@@ -387,3 +507,91 @@ Paragraph after place.
 #place(auto, float: true, block(height: 100%, width: 100%, fill: aqua))
 #place(auto, float: true, block(height: 100%, width: 100%, fill: red))
 #lines(7)
+
+--- place-side-wrap ---
+// Next to the float, only 60pt of the 100pt remain, so the second box doesn't
+// fit onto the first line anymore. The third line is below the float and
+// starts at the left edge again.
+#place(hide(block(width: 100pt, {
+  set text(top-edge: 10pt, bottom-edge: 0pt)
+  set par(leading: 0pt)
+  place(float: true, side: left, clearance: 10pt, block(width: 30pt, height: 5pt))
+  [#box(width: 45pt)<a> #box(width: 45pt)<b> \ #box(width: 45pt)<c>]
+})))
+#context {
+  test(locate(<a>).position().x, 50pt)
+  test(locate(<a>).position().y, 20pt)
+  test(locate(<b>).position().x, 50pt)
+  test(locate(<b>).position().y, 30pt)
+  test(locate(<c>).position().x, 10pt)
+  test(locate(<c>).position().y, 40pt)
+}
+
+--- place-side-wrap-right ---
+// A float at the right only shortens the lines, so they still start at the
+// left edge.
+#place(hide(block(width: 100pt, {
+  set text(top-edge: 10pt, bottom-edge: 0pt)
+  set par(leading: 0pt)
+  place(float: true, side: right, clearance: 10pt, block(width: 30pt, height: 5pt))
+  [#box(width: 45pt)<a> #box(width: 45pt)<b>]
+})))
+#context {
+  test(locate(<a>).position().x, 10pt)
+  test(locate(<b>).position().x, 10pt)
+  test(locate(<b>).position().y, 30pt)
+}
+
+--- place-side-wrap-offset ---
+// The text wraps around the float at its offset position.
+#place(hide(block(width: 100pt, {
+  set text(top-edge: 10pt, bottom-edge: 0pt)
+  set par(leading: 0pt)
+  place(
+    float: true,
+    side: left,
+    clearance: 10pt,
+    dx: 10pt,
+    block(width: 30pt, height: 5pt),
+  )
+  [#box(width: 35pt)<a> #box(width: 35pt)<b>]
+})))
+#context {
+  test(locate(<a>).position().x, 60pt)
+  test(locate(<b>).position().x, 60pt)
+  test(locate(<b>).position().y, 30pt)
+}
+
+--- place-side-wrap-rendered ---
+#set page(width: 160pt, height: auto)
+#place(float: true, side: left, clearance: 6pt, rect(width: 40pt, height: 40pt, fill: aqua))
+#lorem(30)
+
+--- place-side-wrap-rendered-justify ---
+// Justified text uses the optimized line breaker, which takes the shorter
+// lines next to the float into account.
+#set page(width: 160pt, height: auto)
+#set par(justify: true)
+#place(float: true, side: right, clearance: 6pt, rect(width: 50pt, height: 50pt, fill: aqua))
+#lorem(40)
+
+--- place-side-wrap-rendered-both ---
+// Floats at both sides narrow the lines from both ends and floats at the same
+// side are stacked.
+#set page(width: 160pt, height: auto)
+#place(float: true, side: left, clearance: 6pt, rect(width: 30pt, height: 20pt, fill: aqua))
+#place(float: true, side: left, clearance: 6pt, rect(width: 20pt, height: 20pt, fill: teal))
+#place(float: true, side: right, clearance: 6pt, circle(radius: 15pt, fill: eastern))
+#lorem(50)
+
+--- place-side-wrap-rendered-offset ---
+#set page(width: 160pt, height: auto)
+#place(
+  float: true,
+  side: left,
+  clearance: 6pt,
+  dx: 10pt,
+  dy: 10pt,
+  rect(width: 40pt, height: 30pt, fill: aqua),
+)
+#lorem(30)
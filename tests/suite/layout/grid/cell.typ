@@ -131,3 +131,72 @@
 // Error: 7-19 cannot use `table.cell` as a grid cell
 // Hint: 7-19 use `grid.cell` instead
 #grid(table.cell[])
+
+--- grid-cell-rotate-value ---
+#test(grid.cell(rotate: -90deg)[A].rotate, -90deg)
+#test(table.cell(rotate: 45deg)[A].rotate, 45deg)
+
+--- grid-cell-rotate-invalid ---
+// Error: 21-24 expected angle, found length
+#table.cell(rotate: 1cm)[A]
+
+--- grid-cell-rotate-size ---
+// The cell takes the size of its rotated content.
+#let content = box(width: 30pt, height: 10pt)
+#context {
+  test(measure(grid(grid.cell(rotate: -90deg, content))), (width: 10pt, height: 30pt))
+  test(measure(grid(grid.cell(rotate: 180deg, content))), (width: 30pt, height: 10pt))
+  test(measure(table(table.cell(rotate: 90deg, content))), (width: 20pt, height: 40pt))
+}
+
+--- grid-cell-rotate-header-repeat ---
+// The repeated header has the same height in both columns, so the rows after
+// it start at the same position.
+#place(hide(block(width: 100pt, height: 50pt, columns(2, gutter: 0pt, grid(
+  grid.header(grid.cell(rotate: -90deg, box(width: 30pt, height: 10pt))),
+  ..range(4).map(_ => block(height: 10pt)[#metadata(none) <r>]),
+)))))
+#context test(
+  query(<r>).map(it => it.location().position()),
+  (
+    (page: 1, x: 10pt, y: 40pt),
+    (page: 1, x: 10pt, y: 50pt),
+    (page: 1, x: 60pt, y: 40pt),
+    (page: 1, x: 60pt, y: 50pt),
+  ),
+)
+
+--- grid-cell-rotate-rendered ---
+#set page(width: auto)
+#table(
+  columns: 4,
+  align: center + horizon,
+  table.header(
+    [Name],
+    ..([Monday], [Tuesday], [Wednesday]).map(day => table.cell(rotate: -90deg, day)),
+  ),
+  [Alice], [x], [], [x],
+  [Bob], [], [x], [],
+)
+
+--- grid-cell-rotate-rendered-angles ---
+#set page(width: auto)
+#grid(
+  columns: 4,
+  gutter: 4pt,
+  ..(0deg, 45deg, 90deg, 180deg).map(angle => grid.cell(
+    rotate: angle,
+    fill: aqua,
+    inset: 3pt,
+    [Text],
+  )),
+)
+
+--- grid-cell-rotate-rendered-repeat ---
+// The rotated header is repeated on the second page.
+#set page(width: 120pt, height: 100pt)
+#table(
+  columns: 3,
+  table.header(..([First], [Second], [Third]).map(it => table.cell(rotate: -90deg, it))),
+  ..range(12).map(str),
+)
@@ -214,6 +214,43 @@
   [Hello there, my friends! Hi! What is going on right now?],
 )
 
+--- grid-column-fit-value ---
+#test(grid(column-fit: "content").column-fit, "content")
+#test(table(column-min: 1cm).column-min, 1cm)
+#test(grid(column-max: (auto, 50%)).column-max, (auto, 50%))
+
+--- grid-column-fit-content ---
+// The first column can't be narrower than its box, so the second one gets the
+// rest. By default, both are shrunk to half of the width.
+#let cells = (box(width: 60pt), [#metadata(none) <b>#box(width: 30pt)#h(0pt)#box(width: 30pt)])
+#place(hide(block(width: 100pt, grid(columns: 2, column-fit: "content", ..cells))))
+#context test(locate(<b>).position().x, 70pt)
+
+--- grid-column-fit-fair ---
+#let cells = (box(width: 60pt), [#metadata(none) <b>#box(width: 30pt)#h(0pt)#box(width: 30pt)])
+#place(hide(block(width: 100pt, grid(columns: 2, ..cells))))
+#context test(locate(<b>).position().x, 60pt)
+
+--- grid-column-bounds ---
+#let width(..args) = measure(grid(
+  columns: 2,
+  ..args,
+  box(width: 10pt),
+  box(width: 50pt),
+)).width
+#context {
+  test(width(), 60pt)
+  test(width(column-min: 30pt), 80pt)
+  test(width(column-max: 20pt), 30pt)
+  test(width(column-min: (auto, 60pt)), 70pt)
+  // The minimum takes precedence over the maximum.
+  test(width(column-min: 30pt, column-max: 20pt), 60pt)
+}
+
+--- grid-column-fit-invalid ---
+// Error: 19-26 expected "fair" or "content"
+#grid(column-fit: "tight")[A]
+
 --- issue-grid-base-auto-row ---
 // Test that grid base for auto rows makes sense.
 #set page(height: 150pt)
@@ -283,3 +320,23 @@ The following:
   // Error: 3-50 cannot create grid with infinite width
   grid(gutter: infinite-length, columns: 2)[A][B]
 })
+
+--- grid-column-fit-rendered ---
+// With content-based fitting, the column with the long words is shrunk less.
+#set page(width: 160pt)
+#set table(stroke: 0.5pt)
+#table(
+  columns: 2,
+  [Incomprehensibilities], [A short description with many small words.],
+)
+#table(
+  columns: 2,
+  column-fit: "content",
+  [Incomprehensibilities], [A short description with many small words.],
+)
+
+--- grid-column-bounds-rendered ---
+#set page(width: 160pt)
+#set table(stroke: 0.5pt)
+#table(columns: 3, column-min: 30pt, [A], [B], [Longer text])
+#table(columns: 3, column-max: (auto, auto, 40pt), [A], [B], [Longer text that wraps])
@@ -470,3 +470,36 @@
     table.cell(x: 1)[D],
   ),
 )
+
+--- grid-continued-outside ---
+#context test(grid.continued(), false)
+#context test(table.continued(), false)
+#context test(grid.continues(), auto)
+#context test(table.continues(), auto)
+
+--- grid-continued-regions ---
+// The header knows that the grid was broken before the second column and the
+// footer knows that it continues after the first one.
+#place(hide(block(width: 100pt, height: 50pt, columns(2, gutter: 0pt, grid(
+  grid.header(context [#metadata(grid.continued()) <h>]),
+  ..range(6).map(_ => block(height: 10pt)),
+  grid.footer(context [#metadata(grid.continues()) <f>]),
+)))))
+#context {
+  test(query(<h>).map(it => it.value), (false, true))
+  test(query(<f>).map(it => it.value), (true, false))
+}
+
+--- grid-continued-rendered ---
+#set page(width: 120pt, height: 110pt)
+#table(
+  columns: 2,
+  table.header(
+    [*Key*],
+    [*Value* #context if table.continued() [(cont.)]],
+  ),
+  ..range(10).map(n => (str(n), str(n * n))).flatten(),
+  table.footer(table.cell(colspan: 2, context {
+    if table.continues() == true [_Continued on next page_] else [_End_]
+  })),
+)
@@ -66,8 +66,92 @@ Lריווח #h(1cm) R
 
 --- bidi-vertical ---
 // Test setting a vertical direction.
-// Error: 16-19 text direction must be horizontal
 #set text(dir: ttb)
+#context test(text.dir, ttb)
+#context test(text.orientation, "mixed")
+#context test(text.combine-upright, 2)
+
+--- bidi-vertical-page-lines ---
+// Lines of a vertical page run from top to bottom and progress from right to
+// left. The header stays horizontal.
+#set page(width: 120pt, height: 20pt, margin: 0pt, header: hide[Header])
+#set text(dir: ttb, top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 0pt)
+#hide[#metadata(none)<a>A#linebreak()#metadata(none)<b>B]
+#context test(locate(<a>).position(), (page: 1, x: 110pt, y: 0pt))
+#context test(locate(<b>).position(), (page: 1, x: 100pt, y: 0pt))
+
+--- bidi-vertical-block-lines ---
+// Lines of a vertical paragraph in a block run from top to bottom and progress
+// from right to left.
+#set page(width: 120pt, height: auto, margin: 0pt)
+#hide(block(height: 20pt, {
+  set text(dir: ttb, top-edge: 10pt, bottom-edge: 0pt)
+  set par(leading: 0pt)
+  [#metadata(none)<a>A#linebreak()#metadata(none)<b>B]
+}))
+#context test(locate(<a>).position(), (page: 1, x: 10pt, y: 0pt))
+#context test(locate(<b>).position(), (page: 1, x: 0pt, y: 0pt))
+
+--- bidi-vertical-paragraph ---
+// A vertical paragraph breaks into columns at the height of its block.
+#set page(width: auto, height: auto)
+#set text(lang: "ja", font: "Noto Serif CJK JP")
+#block(stroke: 0.5pt + gray, height: 60pt)[
+  #set text(dir: ttb)
+  吾輩は猫である。名前はまだ無い。どこで生れたかとんと見当がつかぬ。
+]
+
+--- bidi-vertical-upright-cjk ---
+// CJK characters stand upright, punctuation and brackets use vertical
+// alternates from the `vert` and `vrt2` features.
+#set page(width: auto, height: 90pt)
+#set text(lang: "ja", font: "Noto Serif CJK JP", dir: ttb)
+「縦書き」、ですね。ー……
+#linebreak()
+#text(features: (vert: 0, vrt2: 0))[「縦書き」、ですね。ー……]
+
+--- bidi-vertical-sideways-latin ---
+// Latin runs are turned sideways by default and stand upright with the
+// `upright` orientation.
+#set page(width: auto, height: 110pt)
+#set text(lang: "ja", font: ("Libertinus Serif", "Noto Serif CJK JP"), dir: ttb)
+縦書きの Typst 文書
+#linebreak()
+#text(orientation: "upright")[縦書きの Typst 文書]
+#linebreak()
+#text(orientation: "sideways")[縦書きの Typst 文書]
+
+--- bidi-vertical-tate-chu-yoko ---
+// Short runs of digits are combined horizontally into one upright character.
+#set page(width: auto, height: 120pt)
+#set text(lang: "ja", font: ("Libertinus Serif", "Noto Serif CJK JP"), dir: ttb)
+令和5年12月31日と2024年
+#linebreak()
+#text(combine-upright: none)[令和5年12月31日と2024年]
+
+--- bidi-vertical-columns ---
+// Columns of a vertical page progress from right to left.
+#set page(width: 100pt, height: 60pt)
+#set text(lang: "ja", font: "Noto Serif CJK JP", dir: ttb)
+一行目の文章です。二行目の文章です。三行目の文章です。
+
+--- bidi-vertical-inline ---
+// Vertical text within a horizontal line stands on the baseline.
+#set text(lang: "ja", font: ("Libertinus Serif", "Noto Serif CJK JP"))
+横書きの中に#text(dir: ttb)[縦書き]があります。
+
+--- bidi-vertical-bottom-to-top ---
+// Error: 16-19 text direction must be horizontal or top-to-bottom
+#set text(dir: btt)
+
+--- bidi-vertical-orientation-invalid ---
+// Error: 24-34 expected "mixed", "upright", or "sideways"
+#set text(orientation: "diagonal")
+
+--- bidi-vertical-combine-upright-zero ---
+// Error: 28-29 number must be positive
+#set text(combine-upright: 0)
 
 --- issue-1373-bidi-tofus ---
 // Test that shaping missing characters in both left-to-right and
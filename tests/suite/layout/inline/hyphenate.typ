@@ -112,6 +112,130 @@ Tras el estallido de la contienda Ruiz-Giménez fue detenido junto a sus
 dos hermanos y puesto bajo custodia por las autoridades republicanas, con
 el objetivo de protegerle de las patrullas de milicianos.
 
+--- hyphenate-exceptions-access ---
+#set text(hyphenation-exceptions: ("data-base",))
+#set text(hyphenation-exceptions: ("Typst",))
+#context test(text.hyphenation-exceptions, ("data-base", "Typst"))
+
+--- hyphenate-patterns-invalid ---
+// Error: 33-54 failed to parse hyphenation patterns (invalid pattern `1!`)
+#set text(hyphenation-patterns: (fy: bytes("a1b 1!")))
+
+--- hyphenate-patterns-not-dict ---
+// Error: 33-45 expected dictionary, found bytes
+#set text(hyphenation-patterns: bytes("1ba"))
+
+--- hyphenate-patterns-bad-lang ---
+// Error: 33-48 expected two or three letter language code (ISO 639-1/2/3)
+#set text(hyphenation-patterns: (frisian: none))
+
+--- hyphenate-limits-access ---
+#set text(hyphenation-limits: (left: 3, right: 4))
+#set text(hyphenation-limits: (right: 2))
+#context test(text.hyphenation-limits, (left: 3, right: 2))
+
+--- hyphenate-limits-invalid-key ---
+// Error: 31-51 unexpected key "middle", valid keys are "left", "right", and language codes
+#set text(hyphenation-limits: (left: 2, middle: 1))
+
+--- hyphenate-limits-lang-invalid-key ---
+// Error: 31-48 unexpected key "middle", valid keys are "left" and "right"
+#set text(hyphenation-limits: (de: (middle: 1)))
+
+--- hyphenate-limits-lang-access ---
+#set text(hyphenation-limits: (left: 3, de: (right: 4)))
+#set text(hyphenation-limits: (de: (left: 2)))
+#context test(
+  text.hyphenation-limits,
+  (left: 3, right: none, de: (left: 2, right: 4)),
+)
+
+--- hyphenate-limits-zero ---
+// Error: 31-40 number must be positive
+#set text(hyphenation-limits: (left: 0))
+
+--- hyphenate-exceptions-breaks ---
+// In a tiny block, the word is broken at every hyphenation opportunity.
+#let lines(..args) = {
+  let body = block(width: 1pt, {
+    set par(leading: 0pt)
+    set text(hyphenate: true, top-edge: 10pt, bottom-edge: 0pt, ..args)
+    [database]
+  })
+  measure(body).height / 10pt
+}
+#context {
+  test(lines(hyphenation-exceptions: ("data-base",)), 2)
+  test(lines(hyphenation-exceptions: ("database",)), 1)
+  test(lines(hyphenation-exceptions: ("DA-TA-BASE",)), 3)
+}
+
+--- hyphenate-patterns-breaks ---
+// The patterns allow breaks before "ba", "ca", and "da", but the limits
+// exclude some of them.
+#let lines(..args) = {
+  let body = block(width: 1pt, {
+    set par(leading: 0pt)
+    set text(hyphenate: true, top-edge: 10pt, bottom-edge: 0pt, ..args)
+    [abracadabra]
+  })
+  measure(body).height / 10pt
+}
+#let patterns = (fy: bytes("1ba 1ca 1da"))
+#let limits = (left: 4, right: 4)
+#context {
+  test(lines(lang: "fy", hyphenation-patterns: patterns), 4)
+  test(lines(lang: "fy", hyphenation-patterns: patterns, hyphenation-limits: limits), 3)
+  test(lines(lang: "fy", hyphenation-patterns: patterns, hyphenation-limits: (fy: limits)), 3)
+  test(lines(lang: "fy", hyphenation-patterns: patterns, hyphenation-limits: (de: limits)), 4)
+}
+
+--- hyphenate-patterns-other-lang ---
+// Patterns only apply to text in their language.
+#let lines(..args) = {
+  let body = block(width: 1pt, {
+    set par(leading: 0pt)
+    set text(hyphenate: true, top-edge: 10pt, bottom-edge: 0pt, ..args)
+    [abracadabra]
+  })
+  measure(body).height / 10pt
+}
+#let patterns = bytes("1ba 1ca 1da")
+#context {
+  test(lines(lang: "fy", hyphenation-patterns: (nl: patterns)), 1)
+  test(lines(lang: "fy", hyphenation-patterns: (nl: patterns, fy: patterns)), 4)
+  test(lines(lang: "fy", hyphenation-patterns: (fy: patterns, nl: none)), 4)
+}
+
+--- hyphenate-patterns-fold ---
+// Patterns for other languages from earlier set rules are kept.
+#set text(hyphenation-patterns: (fy: bytes("1ba")))
+#set text(hyphenation-patterns: (nl: none))
+#context test(text.hyphenation-patterns.keys(), ("fy", "nl"))
+
+--- hyphenate-patterns-nested-groups ---
+// Nested groups in a TeX pattern file are part of the patterns.
+#let lines(..args) = {
+  let body = block(width: 1pt, {
+    set par(leading: 0pt)
+    set text(hyphenate: true, top-edge: 10pt, bottom-edge: 0pt, ..args)
+    [abracadabra]
+  })
+  measure(body).height / 10pt
+}
+#let patterns = bytes("\\patterns{1ba {1ca} 1da}\n\\hyphenation{}")
+#context test(lines(lang: "fy", hyphenation-patterns: (fy: patterns)), 4)
+
+--- hyphenate-patterns-rendered ---
+#set page(width: 80pt)
+#set par(justify: true)
+#set text(
+  lang: "fy",
+  hyphenate: true,
+  hyphenation-patterns: (fy: bytes("1ba 1ca 1da")),
+)
+Abracadabra abracadabra abracadabra abracadabra.
+
 --- costs-widow-orphan ---
 #set page(height: 60pt)
 
@@ -161,6 +161,134 @@ int main() {
 #set par(justify: true)
 #block(width: 1cm, fill: aqua, lorem(2))
 
+--- justify-protrusion-access ---
+#set text(protrusion: ("-": 50%, "“": (left: 40%)))
+#set text(protrusion: ("-": (left: 20%)))
+#context test(
+  text.protrusion,
+  ("-": (left: 20%, right: 50%), "“": (left: 40%, right: none)),
+)
+
+--- justify-protrusion-invalid-key ---
+// Error: 23-34 expected a single character as key, found "ab"
+#set text(protrusion: ("ab": 10%))
+
+--- justify-protrusion-invalid-value ---
+// Error: 23-33 expected ratio or dictionary, found length
+#set text(protrusion: ("-": 1pt))
+
+--- justify-expansion-out-of-range ---
+// Error: 22-25 expansion must be between 0% and 10%
+#set text(expansion: 20%)
+
+--- justify-kashida-access ---
+#set par(justify: true, kashida: true)
+#context test(par.kashida, true)
+
+--- justify-protrusion-left ---
+// The quote protrudes fully into the left margin, so the text after it starts
+// at the edge.
+#place(hide(block(width: 100pt)[
+  #set text(protrusion: ("“": (left: 100%)))
+  “#metadata(none)<a>x
+]))
+#context test(locate(<a>).position().x, 10pt)
+
+--- justify-expansion-layout ---
+// With expansion, the glyphs take part of the extra space of the justified
+// line, so the first word gets wider.
+#let line(expansion) = place(hide(block(width: 100pt, {
+  set par(justify: true)
+  set text(expansion: expansion)
+  [aaaa#metadata(expansion)<end> bbbb#linebreak(justify: true)]
+})))
+#line(0%)
+#line(10%)
+#context {
+  let width = measure[aaaa].width
+  let (normal, expanded) = query(<end>).map(it => it.location().position().x - 10pt)
+  test(normal, width)
+  assert(expanded > width)
+  assert(expanded <= 1.1 * width)
+}
+
+--- justify-expansion-line-width ---
+// The expanded glyphs and the justified spaces together fill the line exactly.
+#place(hide(block(width: 100pt, {
+  set par(justify: true)
+  set text(expansion: 10%)
+  [aaaa bbbb#metadata(none)<end>#linebreak(justify: true)]
+})))
+#context test(calc.abs((locate(<end>).position().x - 110pt).pt()) < 0.001, true)
+
+--- justify-protrusion-rendered ---
+// Quotes and punctuation at the line edges protrude into the margins.
+#set page(width: 150pt, height: auto)
+#set par(justify: true)
+#set text(protrusion: (
+  "“": (left: 100%),
+  "”": (right: 100%),
+  ",": (right: 50%),
+  ".": (right: 50%),
+  "-": (right: 50%),
+))
+#rect(width: 100%, inset: 0pt, stroke: (x: 0.25pt + red))[
+  “#lorem(20)” And, as it happens, this is another sentence, with commas.
+]
+
+--- justify-expansion-rendered ---
+// Expanded glyphs take part of the adjustment of justified lines.
+#set page(width: 150pt, height: auto)
+#set par(justify: true)
+#lorem(20)
+
+#set text(expansion: 3%)
+#lorem(20)
+
+--- justify-kashida-layout ---
+// Kashidas elongate the first word, so the tag after it moves further to the
+// left than with stretched spaces alone.
+#set text(lang: "ar", font: "Noto Sans Arabic")
+#set par(justify: true)
+#place(hide(block(width: 100pt)[
+  سلام#metadata(none)<plain> سلام#linebreak(justify: true)
+]))
+#place(hide(block(width: 100pt, {
+  set par(kashida: true)
+  [سلام#metadata(none)<kashida> سلام#linebreak(justify: true)]
+})))
+#context {
+  let plain = locate(<plain>).position()
+  let kashida = locate(<kashida>).position()
+  test(plain.y, kashida.y)
+  test(kashida.x < plain.x, true)
+}
+
+--- justify-kashida-rendered ---
+// Justified Arabic text with and without kashidas.
+#set page(width: 150pt, height: auto)
+#set text(lang: "ar", font: "Noto Sans Arabic")
+#set par(justify: true)
+#let body = [
+  لا تتحرك الأجرام السماوية في مسارات عشوائية بل تتبع قوانين دقيقة
+  اكتشفها العلماء عبر قرون من الرصد والتأمل والحساب.
+]
+#body
+
+#set par(kashida: true)
+#body
+
+--- justify-kashida-rendered-marks ---
+// Words with marks are shaped again with the kashidas, so the marks stay in
+// place.
+#set page(width: 120pt, height: auto)
+#set text(lang: "ar", font: "Noto Sans Arabic")
+#set par(justify: true, kashida: true)
+بِسْمِ اللَّهِ الرَّحْمَٰنِ الرَّحِيمِ
+#linebreak(justify: true)
+سَلَامٌ عَلَيْكُمْ
+#linebreak(justify: true)
+
 --- issue-2419-justify-hanging-indent ---
 // Test that combination of justification and hanging indent doesn't result in
 // an underfull first line.
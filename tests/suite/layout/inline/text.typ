@@ -63,6 +63,31 @@ fi vs. #text(features: (liga: 0))[No fi]
 // Error: 21-35 expected string, found boolean
 #set text(features: ("tag", false))
 
+--- text-variations ---
+// Test folding of variable font axis coordinates.
+#set text(variations: (wght: 550))
+#set text(variations: (opsz: 12, wght: 600))
+#context test(text.variations, (wght: 600.0, opsz: 12.0))
+
+--- text-variations-bad ---
+// Error: 23-26 expected dictionary, found integer
+#set text(variations: 550)
+
+--- text-variations-bad-nested-type ---
+// Error: 23-39 expected float, found string
+#set text(variations: ("wght": "bold"))
+
+--- text-variations-bad-tag ---
+// Error: 23-37 axis tag must be exactly four bytes long
+#set text(variations: ("wght2": 550))
+
+--- text-variations-static-font ---
+// Coordinates don't affect fonts without variation axes.
+#context test(
+  measure(text(variations: (wght: 900, opsz: 72))[Hello]).width,
+  measure[Hello].width,
+)
+
 --- text-tracking-negative ---
 // Test tracking.
 #set text(tracking: -0.01em)
@@ -92,3 +117,12 @@ My text has spaces.
 // Test word spacing relative to the font's space width.
 #set text(spacing: 50% + 1pt)
 This is tight.
+
+--- text-shaping-cache ---
+// Shaped runs are reused across layouts, but only for identical inputs.
+#context {
+  test(measure[fi AV].width, measure[fi AV].width)
+  test(measure[AV].width == measure(text(kerning: false)[AV]).width, false)
+  test(measure[fi].width == measure(text(features: (liga: 0))[fi]).width, false)
+  test(measure(text(kerning: false)[AV]).width, measure(text(kerning: false)[AV]).width)
+}
@@ -394,3 +394,177 @@ text
 #metadata(none)
 #set page(fill: red)
 2
+
+--- page-baseline-grid-default ---
+#context test(page.baseline-grid, none)
+
+--- page-baseline-grid-value ---
+#set page(baseline-grid: 12pt)
+#context test(page.baseline-grid, (step: 12pt, offset: 0pt, stroke: none))
+
+--- page-baseline-grid-invalid ---
+// Error: 26-39 dictionary does not contain key "step"
+#set page(baseline-grid: (offset: 2pt))
+
+--- page-vertical-justify-default ---
+#context test(page.vertical-justify, false)
+
+--- page-vertical-justify-value ---
+#set page(vertical-justify: (limit: 50%))
+#context test(page.vertical-justify, (limit: 50%))
+
+--- page-vertical-justify-invalid ---
+// Error: 29-41 dictionary does not contain key "limit"
+#set page(vertical-justify: (stretch: 1))
+
+--- page-baseline-grid-lines ---
+// The baselines of the lines are moved down onto the grid.
+#set page(height: 20pt, margin: (x: 10pt, top: 0pt, bottom: -100pt), baseline-grid: 12pt)
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 0pt, spacing: 0pt)
+#hide[A #metadata(none) <a> B \ C #metadata(none) <b> D]
+#context {
+  test(locate(<a>).position().y, 12pt)
+  test(locate(<b>).position().y, 24pt)
+}
+
+--- page-baseline-grid-exact ---
+// Baselines that are already on the grid stay where they are.
+#set page(height: 20pt, margin: (x: 10pt, top: 0pt, bottom: -100pt), baseline-grid: 12pt)
+#set text(top-edge: 12pt, bottom-edge: 0pt)
+#set par(leading: 0pt, spacing: 0pt)
+#hide[A #metadata(none) <a> B \ C #metadata(none) <b> D]
+#context {
+  test(locate(<a>).position().y, 12pt)
+  test(locate(<b>).position().y, 24pt)
+}
+
+--- page-baseline-grid-offset ---
+#set page(
+  height: 20pt,
+  margin: (x: 10pt, top: 0pt, bottom: -100pt),
+  baseline-grid: (step: 12pt, offset: 3pt),
+)
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 0pt, spacing: 0pt)
+#hide[A #metadata(none) <a> B \ C #metadata(none) <b> D]
+#context {
+  test(locate(<a>).position().y, 15pt)
+  test(locate(<b>).position().y, 27pt)
+}
+
+--- page-baseline-grid-block ---
+// Blocks are padded at the bottom up to the next grid line.
+#set page(height: 20pt, margin: (x: 10pt, top: 0pt, bottom: -100pt), baseline-grid: 12pt)
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 0pt, spacing: 0pt)
+#set block(spacing: 0pt)
+#hide[
+  #block(height: 5pt)
+  #block[#metadata(none) <a>]
+  A #metadata(none) <b> B
+]
+#context {
+  test(locate(<a>).position().y, 12pt)
+  test(locate(<b>).position().y, 24pt)
+}
+
+--- page-vertical-justify-layout ---
+// The first column ends early because the third block doesn't fit anymore, so
+// its leftover space goes into the gap between the first two blocks. The last
+// column isn't justified.
+#set page(
+  height: 20pt,
+  margin: (x: 10pt, top: 0pt, bottom: -40pt),
+  columns: 2,
+  vertical-justify: true,
+)
+#set columns(gutter: 0pt)
+#set block(height: 20pt, spacing: 5pt, breakable: false)
+#hide[
+  #block[#metadata(none) <a>]
+  #block[#metadata(none) <b>]
+  #block[#metadata(none) <c>]
+  #block[#metadata(none) <d>]
+]
+#context {
+  test(locate(<a>).position().y, 0pt)
+  test(locate(<b>).position().y, 40pt)
+  test(locate(<c>).position(), (page: 1, x: 60pt, y: 0pt))
+  test(locate(<d>).position(), (page: 1, x: 60pt, y: 25pt))
+}
+
+--- page-vertical-justify-limit ---
+// With a limit of 100%, the gap grows from 5pt to at most 10pt.
+#set page(
+  height: 20pt,
+  margin: (x: 10pt, top: 0pt, bottom: -40pt),
+  columns: 2,
+  vertical-justify: (limit: 100%),
+)
+#set columns(gutter: 0pt)
+#set block(height: 20pt, spacing: 5pt, breakable: false)
+#hide[
+  #block[#metadata(none) <a>]
+  #block[#metadata(none) <b>]
+  #block[#metadata(none) <c>]
+]
+#context test(locate(<b>).position().y, 30pt)
+
+--- page-baseline-grid-stroke ---
+// The grid lines are drawn on top of the page's body.
+#set page(
+  width: 150pt,
+  height: 100pt,
+  baseline-grid: (step: 12pt, offset: 2pt, stroke: 0.25pt + aqua),
+)
+#set par(leading: 0.5em)
+Text on the grid.
+#text(1.3em)[Bigger text.]
+More text on the grid.
+
+--- page-baseline-grid-heading-columns ---
+// The lines after a heading and an equation move down to the next grid line,
+// also in the second column.
+#set page(
+  width: 200pt,
+  height: 120pt,
+  columns: 2,
+  baseline-grid: (step: 12pt, stroke: 0.25pt + aqua),
+)
+#set par(leading: 0.5em)
+= Heading
+#lorem(6)
+$ sum_(k=1)^n k = (n(n+1)) / 2 $
+#lorem(4)
+#colbreak()
+== Second
+#lorem(4)
+$ a^2 + b^2 = c^2 $
+#lorem(4)
+
+--- page-vertical-justify-rendered ---
+// The figure doesn't fit onto the first page, so the leftover space is
+// distributed across the gaps between the paragraphs, the heading and the
+// equation. The last page isn't justified.
+#set page(width: 150pt, height: 200pt, vertical-justify: true)
+= Results
+#lorem(12)
+
+$ a^2 + b^2 = c^2 $
+
+#lorem(10)
+
+#rect(width: 100%, height: 80pt, fill: aqua)
+#lorem(5)
+
+--- page-vertical-justify-rendered-limit ---
+// With a limit, the gaps stretch to at most twice their size and the remaining
+// space stays free at the bottom.
+#set page(width: 150pt, height: 160pt, vertical-justify: (limit: 100%))
+= Results
+#lorem(8)
+
+#lorem(6)
+
+#rect(width: 100%, height: 80pt, fill: aqua)
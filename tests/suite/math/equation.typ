@@ -16,6 +16,65 @@ $ F_n = round(1 / sqrt(5) phi.alt^n) $ <fib>
 #show math.equation: set text(font: "Fira Math")
 $ v := vec(1 + 2, 2 - 4, sqrt(3), arrow(x)) + 1 $
 
+--- math-equation-font-field ---
+#set math.equation(font: ("Fira Math", "New Computer Modern Math"))
+#context test(math.equation.font, ("fira math", "new computer modern math"))
+
+--- math-equation-font-no-math-table ---
+// Warning: 26-44 font family libertinus serif has no MATH table
+// Hint: 26-44 only fonts with a MATH table can be used to typeset math
+#set math.equation(font: "Libertinus Serif")
+
+--- math-equation-font-fallback ---
+// The first family with a MATH table is used for math.
+// Warning: 26-59 font family libertinus serif has no MATH table
+// Hint: 26-59 only fonts with a MATH table can be used to typeset math
+#set math.equation(font: ("Libertinus Serif", "Fira Math"))
+#context {
+  let chain = measure($x + y$).width
+  let fira = measure({ set math.equation(font: "Fira Math"); $x + y$ }).width
+  let default = measure[$x + y$].width
+  test(chain, fira)
+  test(chain == default, false)
+}
+
+--- math-equation-font-fallback-rendered ---
+#set math.equation(font: ("Fira Math", "New Computer Modern Math"))
+$ x + y = sqrt(z) $
+#set math.equation(font: "New Computer Modern Math")
+$ x + y = sqrt(z) $
+
+--- math-equation-font-text-family ---
+// A text font with a MATH table takes precedence over the equation's fonts.
+#context {
+  let shown = measure({
+    show math.equation: set text(font: "Fira Math")
+    $x + y$
+  })
+  let fira = measure({
+    set math.equation(font: "Fira Math")
+    $x + y$
+  })
+  test(shown.width, fira.width)
+  test(shown.width == measure($x + y$).width, false)
+}
+
+--- math-equation-font-text-family-no-math-table ---
+// Falling back from a text font without a MATH table warns once per family.
+// Warning: font family dejavu sans mono has no MATH table
+// Hint: using New Computer Modern Math for math instead
+#set text(font: "DejaVu Sans Mono")
+#hide[$a$ $b$ $ c $]
+
+--- math-stretch-unstretchable ---
+// Warning: 23-24 math font New Computer Modern Math cannot stretch 1
+// Hint: 23-24 the font has no larger variants or assembly for this glyph
+// Hint: 23-24 try a different font with `math.equation(font: ..)`
+// Warning: 47-48 math font New Computer Modern Math cannot stretch 1
+// Hint: 47-48 the font has no larger variants or assembly for this glyph
+// Hint: 47-48 try a different font with `math.equation(font: ..)`
+$ lr(class("opening", 1) x/y class("closing", 1)) $
+
 --- math-equation-show-rule ---
 This is small: $sum_(i=0)^n$
 
@@ -196,6 +196,8 @@ $ mat(#1, #(foo: "bar")) $
 
 --- issue-2268-mat-augment-color ---
 // The augment line should be of the same color as the text
+// Warning: font family new computer modern has no MATH table
+// Hint: using New Computer Modern Math for math instead
 #set text(
   font: "New Computer Modern",
   lang: "en",
@@ -11,6 +11,8 @@ $  &sin x + log_2 x \
 
 --- math-op-scripts-vs-limits ---
 // Test scripts vs limits.
+// Warning: font family new computer modern has no MATH table
+// Hint: using New Computer Modern Math for math instead
 #set page(width: auto)
 #set text(font: "New Computer Modern")
 Discuss $lim_(n->oo) 1/n$ now.
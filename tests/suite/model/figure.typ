@@ -300,3 +300,50 @@ HI#footnote.entry(clearance: 2.5em)[There]
 )
 
 #c
+
+--- figure-caption-repeat-value ---
+#test(figure.caption(repeat: true)[A].repeat, true)
+#test(figure.caption(continuation: [(cont.)])[A].continuation, [(cont.)])
+
+--- figure-caption-repeat-continuation ---
+// The caption is repeated in the second column, with the continuation instead
+// of its body.
+#show figure: set block(breakable: true)
+#set table(inset: 0pt)
+#place(hide(block(width: 100pt, height: 60pt, columns(2, gutter: 0pt, figure(
+  table(..range(6).map(_ => block(height: 10pt))),
+  caption: figure.caption(
+    position: top,
+    repeat: true,
+    continuation: [#metadata("continued") <cap>],
+  )[#metadata("first") <cap>],
+)))))
+#context test(query(<cap>).map(it => it.value), ("first", "continued"))
+
+--- figure-caption-repeat-rendered ---
+#set page(width: 120pt, height: 120pt)
+#show figure: set block(breakable: true)
+#show figure.where(kind: table): set figure.caption(
+  position: top,
+  repeat: true,
+  continuation: [(continued)],
+)
+#figure(
+  table(
+    columns: 2,
+    ..range(10).map(n => (str(n), str(n * n))).flatten(),
+  ),
+  caption: [Squares],
+)
+
+--- figure-caption-repeat-rendered-bottom ---
+// A repeated bottom caption ends each part of the table.
+#set page(width: 120pt, height: 120pt)
+#show figure: set block(breakable: true)
+#figure(
+  table(
+    columns: 2,
+    ..range(10).map(n => (str(n), str(n * n))).flatten(),
+  ),
+  caption: figure.caption(repeat: true)[Squares],
+)
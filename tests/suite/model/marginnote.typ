@@ -0,0 +1,95 @@
+// Test margin notes.
+
+--- marginnote-query ---
+#marginnote[]
+#marginnote[]
+#context test(query(marginnote).len(), 2)
+
+--- marginnote-overflow ---
+#set page(height: 20pt)
+#set marginnote(spacing: 0pt)
+#marginnote(block(height: 5pt))
+// Warning: 2-32 margin note does not fit into the margin of the last page
+// Hint: 2-32 the note was not placed
+#marginnote(block(height: 5pt))
+
+--- marginnote-anchor ---
+// Notes are aligned with the baseline of their line, also at the start and at
+// the end of a paragraph.
+#set page(height: 20pt, margin: (x: 30pt, top: 0pt, bottom: -100pt))
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 0pt, spacing: 0pt)
+#set marginnote(side: right, gap: 5pt, spacing: 0pt)
+#hide[
+  #marginnote[#metadata(none) <a>] A \
+  B #marginnote[#metadata(none) <b>] \
+  C #marginnote[#metadata(none) <c>]
+
+  D
+]
+#context {
+  test(locate(<a>).position(), (page: 1, x: 95pt, y: 10pt))
+  test(locate(<b>).position(), (page: 1, x: 95pt, y: 20pt))
+  test(locate(<c>).position(), (page: 1, x: 95pt, y: 30pt))
+}
+
+--- marginnote-stack ---
+// A note that would overlap the previous one on the same side moves down.
+#set page(height: 20pt, margin: (x: 30pt, top: 0pt, bottom: -100pt))
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set marginnote(side: left, gap: 5pt, width: 20pt, spacing: 5pt)
+#hide[
+  A #marginnote[#metadata(none) <a> #block(height: 15pt)]
+  #marginnote[#metadata(none) <b>]
+]
+#context {
+  test(locate(<a>).position(), (page: 1, x: 5pt, y: 10pt))
+  test(locate(<b>).position(), (page: 1, x: 5pt, y: 30pt))
+}
+
+--- marginnote-html html ---
+A #marginnote[Note] B.
+
+--- marginnote-side ---
+#test(marginnote[].side, "outside")
+#test(marginnote(side: left)[].side, left)
+#test(marginnote(side: "inside")[].side, "inside")
+
+--- marginnote-side-invalid ---
+// Error: 19-22 must be `start`, `end`, `left`, or `right`
+#marginnote(side: top)[]
+
+--- marginnote-rendered ---
+#set page(width: 200pt, height: auto, margin: (left: 20pt, right: 80pt, y: 20pt))
+#set marginnote(side: right)
+#show marginnote: set text(0.8em, fill: eastern)
+The first draft of the treaty #marginnote[Signed in 1648.] was written in
+Latin and later translated into French. #marginnote[Only parts survive.]
+#lorem(20)
+
+--- marginnote-rendered-stacked ---
+// Notes that would overlap are stacked below each other.
+#set page(width: 200pt, height: auto, margin: (left: 20pt, right: 80pt, y: 20pt))
+#set marginnote(side: right)
+#show marginnote: set text(0.8em)
+A #marginnote[First note with a bit more text.]
+B #marginnote[Second note.]
+C #marginnote(dy: 10pt)[Shifted note.]
+
+--- marginnote-rendered-sides ---
+// Outside notes alternate between the margins of a two-sided document.
+#set page(width: 160pt, height: 80pt, margin: (inside: 20pt, outside: 60pt, y: 10pt))
+#show marginnote: set text(0.8em)
+Odd page #marginnote[Outside] and #marginnote(side: "inside", width: 10pt)[In]
+#pagebreak()
+Even page #marginnote[Outside]
+
+--- marginnote-rendered-next-page ---
+// A note that doesn't fit into the margin moves to the next page.
+#set page(width: 160pt, height: 60pt, margin: (left: 10pt, right: 60pt, y: 10pt))
+#set marginnote(side: right)
+#show marginnote: set text(0.8em)
+Text #marginnote[#lorem(6)]
+#marginnote[Moved]
+#pagebreak()
+More text
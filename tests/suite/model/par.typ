@@ -129,6 +129,51 @@ It is the east, and Juliet is the sun.
 #set par(spacing: 10pt)
 #context test(par.spacing, 10pt)
 
+--- par-order-and-positions ---
+// Paragraphs are laid out independently, but must end up in document order
+// and at the right positions.
+#set page(height: 20pt, margin: (x: 10pt, top: 0pt, bottom: -100pt))
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(spacing: 5pt, leading: 0pt)
+#set block(spacing: 5pt)
+#hide[
+  A#metadata(0)<p>A
+
+  B#metadata(1)<p>B
+
+  #block(height: 7pt)
+
+  C#metadata(2)<p>C
+
+  D#metadata(3)<p>D
+]
+
+#context {
+  let tags = query(<p>)
+  test(tags.map(it => it.value), (0, 1, 2, 3))
+  test(
+    tags.map(it => it.location().position().y),
+    (10pt, 25pt, 52pt, 67pt),
+  )
+}
+
+--- par-order-in-container ---
+#set page(height: 20pt, margin: (x: 10pt, top: 0pt, bottom: -100pt))
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(spacing: 5pt, leading: 0pt)
+#hide(block(inset: 5pt)[
+  A#metadata(0)<p>A
+
+  B#metadata(1)<p>B
+])
+
+#context {
+  let tags = query(<p>)
+  test(tags.map(it => it.value), (0, 1))
+  test(tags.map(it => it.location().position().y), (15pt, 30pt))
+  test(tags.map(it => it.location().position().x).all(x => x > 15pt), true)
+}
+
 --- par-first-line-indent ---
 #set par(first-line-indent: 12pt, spacing: 5pt, leading: 5pt)
 #show heading: set text(size: 10pt)
@@ -341,3 +386,26 @@ A
 // Hint: 2-36 this is specific to paragraphs as they are not considered blocks anymore
 // Hint: 2-36 write `set par(spacing: ..)` instead
 #show par: set block(spacing: 12pt)
+
+--- par-parallel-rendered ---
+// Many paragraphs with different styles, laid out in parallel, must appear in
+// document order with their own styles.
+#set page(width: 150pt, height: auto)
+#for i in range(8) [
+  #set text(fill: (red, blue, olive, maroon).at(calc.rem(i, 4)))
+  #set par(justify: calc.even(i))
+  Paragraph #i: #lorem(8 + i)
+
+]
+
+--- par-parallel-rendered-containers ---
+// Paragraphs in nested containers and next to each other in a grid.
+#set page(width: 160pt, height: auto)
+#block(inset: 5pt, stroke: 0.5pt)[
+  #lorem(8)
+
+  #block(inset: 5pt, fill: aqua)[#lorem(6)]
+
+  #lorem(5)
+]
+#grid(columns: 2, gutter: 6pt, lorem(7), text(blue, lorem(9)))
@@ -0,0 +1,238 @@
+// Test match expressions.
+
+--- match-literals ---
+#let describe(x) = match x {
+  none => "nothing"
+  0 => "zero"
+  -1 => "minus one"
+  1.5 => "one and a half"
+  12pt => "twelve points"
+  "hi" => "greeting"
+  true => "yes"
+  _ => "other"
+}
+
+#test(describe(none), "nothing")
+#test(describe(0), "zero")
+#test(describe(-1), "minus one")
+#test(describe(1.5), "one and a half")
+#test(describe(12pt), "twelve points")
+#test(describe("hi"), "greeting")
+#test(describe(true), "yes")
+#test(describe(false), "other")
+#test(describe(auto), "other")
+
+--- match-binding ---
+#test(match 1 + 2 { x => x * 2 }, 6)
+#test(match "a" { "b" => 1, x => x + "c" }, "ac")
+
+--- match-constant ---
+#test(match left { alignment.right => 1, alignment.left => 2, _ => 3 }, 2)
+
+--- match-types ---
+#let kind(x) = match x {
+  int(n) => "int " + str(n)
+  str(s) => "str " + s
+  array(()) => "empty array"
+  array(_) => "array"
+  dictionary() => "dictionary"
+  _ => "other"
+}
+
+#test(kind(5), "int 5")
+#test(kind("a"), "str a")
+#test(kind(()), "empty array")
+#test(kind((1, 2)), "array")
+#test(kind((a: 1)), "dictionary")
+#test(kind(1.0), "other")
+
+--- match-elements ---
+#let level(it) = match it {
+  heading(h) => h.depth
+  strong() => "strong"
+  _ => none
+}
+
+#test(level(heading(depth: 2)[A]), 2)
+#test(level(strong[A]), "strong")
+#test(level(emph[A]), none)
+#test(level(1), none)
+
+--- match-array ---
+#let shape(x) = match x {
+  () => "empty"
+  (a,) => "one: " + str(a)
+  (0, b) => "zero and " + str(b)
+  (a, b) => "pair " + str(a + b)
+  (a, .., z) => "many " + str(a) + str(z)
+}
+
+#test(shape(()), "empty")
+#test(shape((1,)), "one: 1")
+#test(shape((0, 5)), "zero and 5")
+#test(shape((2, 5)), "pair 7")
+#test(shape((1, 2, 3, 4)), "many 14")
+
+--- match-array-sink ---
+#test(match (1, 2, 3) { (first, ..rest) => rest }, (2, 3))
+#test(match (1, 2, 3) { (.., (a, b)) => 0, (..rest, 3) => rest }, (1, 2))
+
+--- match-dict ---
+#let area(shape) = match shape {
+  (kind: "square", size: s) => s * s
+  (kind: "rect", width, height) => width * height
+  (kind: "circle", ..) => "round"
+  () => "empty"
+  _ => "unknown"
+}
+
+#test(area((kind: "square", size: 3)), 9)
+#test(area((kind: "rect", width: 2, height: 4)), 8)
+#test(area((kind: "rect", width: 2)), "unknown")
+#test(area((kind: "circle", radius: 1)), "round")
+#test(area((:)), "empty")
+#test(area((kind: "triangle")), "unknown")
+
+--- match-dict-sink ---
+#test(match (a: 1, b: 2, c: 3) { (a: 1, ..rest) => rest }, (b: 2, c: 3))
+
+--- match-nested ---
+#test(
+  match (1, (a: (2, 3))) {
+    (x, (a: (y, int(z)))) => x + y + z
+    _ => 0
+  },
+  6,
+)
+
+--- match-guard ---
+#let sign(x) = match x {
+  0 => "zero"
+  n if n > 0 => "positive"
+  n => "negative"
+}
+
+#test(sign(0), "zero")
+#test(sign(5), "positive")
+#test(sign(-5), "negative")
+
+--- match-guard-ident ---
+#let flag = false
+#test(match 1 { x if flag => "a", _ => "b" }, "b")
+#test(match 1 { x if x > 0 and not flag => "a", _ => "b" }, "a")
+#test(match (1, 2) { (a, b) if (a < b) => "asc", _ => "desc" }, "asc")
+
+--- match-guard-closure ---
+#test(match (1, 2, 3) { xs if xs.any(x => x > 2) => "big", _ => "small" }, "big")
+
+--- match-scope ---
+// Bindings of arms that didn't match don't leak.
+#let x = "outer"
+#test(match (1, 2) { (x, 3) => x, _ => x }, "outer")
+
+--- match-content ---
+#let value = 2
+#test(match value { 1 => [One], 2 => [Two], _ => [Many] }, [Two])
+
+--- match-block-bodies ---
+#let out = match "b" {
+  "a" => {
+    let y = 1
+    y
+  }
+  "b" => {
+    let y = 2
+    y * 10
+  }
+}
+#test(out, 20)
+
+--- match-return ---
+#let f(x) = {
+  match x {
+    0 => return "early"
+    _ => none
+  }
+  "late"
+}
+
+#test(f(0), "early")
+#test(f(1), "late")
+
+--- match-in-loop ---
+#let out = ()
+#for i in range(5) {
+  match calc.rem(i, 2) {
+    0 => continue
+    _ => out.push(i)
+  }
+}
+#test(out, (1, 3))
+
+--- match-captured ---
+#let offset = 10
+#let f(x) = match x { int(n) => n + offset, _ => offset }
+#test(f(1), 11)
+#test(f("a"), 10)
+
+--- match-as-identifier ---
+// `match` is only a keyword at the start of a match expression.
+#let match = 1
+#test(match, 1)
+#test(match + 1, 2)
+#test(match - 1, 0)
+#test((match: 3).match, 3)
+#let double(match) = match * 2
+#test(double(2), 4)
+#let matched = for match in (1, 2) { (match,) }
+#test(matched, (1, 2))
+#let check(match) = if match { "yes" } else { "no" }
+#test(check(true), "yes")
+#test(match (1, 2) { (a, b) => a + b }, 3)
+
+--- match-as-function ---
+#let match(x) = x + 1
+#test(match(1), 2)
+#test(match (1) { 1 => "one", _ => "other" }, "one")
+
+--- match-not-exhaustive ---
+// Error: 8-9 no match arm matches 3
+// Hint: 8-9 add a final `_` arm to handle all remaining values
+#match 3 { 1 => "one", 2 => "two" }
+
+--- match-no-arms ---
+// Error: 8-12 no match arm matches none
+// Hint: 8-12 add a final `_` arm to handle all remaining values
+#match none {}
+
+--- match-guard-not-bool ---
+// Error: 17-18 expected boolean, found integer
+#match 1 { x if 1 => x }
+
+--- match-typed-not-type ---
+#let one = 1
+// Error: 12-15 expected type or element function, found integer
+#match 1 { one(x) => x }
+
+--- match-unreachable-arm ---
+// Warning: 28-34 unreachable match arm
+// Hint: 28-34 the previous arm already matches all values
+#let v = match 1 { x => x, _ => 0 }
+
+--- match-duplicate-binding ---
+// Error: 21-22 duplicate binding: a
+#match (1, 2) { (a, a) => a }
+
+--- match-missing-arrow ---
+// Error: 13 expected arrow
+// Error: 15 expected arrow
+#match 1 { x x }
+
+--- match-missing-arms ---
+// Without arms, `match` is just an identifier.
+// Error: 2-7 unknown variable: match
+#match 1
+
+--- match-missing-separator ---
+// Error: 18 expected comma or line break
+#match 1 { 1 => 1 2 => 2 }
@@ -0,0 +1,103 @@
+// Test try-catch expressions.
+
+--- try-catch-panic ---
+#test(try { panic("oh no") } catch err { err.message }, "panicked with: \"oh no\"")
+
+--- try-catch-assert ---
+#let out = try {
+  assert.eq(1, 2)
+  "unreachable"
+} catch err {
+  err.message
+}
+#test(out, "equality assertion failed: value 1 was not equal to 2")
+
+--- try-catch-decode ---
+#let parse(text) = try { json(bytes(text)) } catch { "invalid" }
+#test(parse("[1, 2]"), (1, 2))
+#test(parse("[1, "), "invalid")
+
+--- try-catch-hints ---
+#let err = try { calc.foo } catch err { err }
+#test(type(err), dictionary)
+#test(err.keys(), ("message", "hints", "errors"))
+#test(type(err.hints), array)
+#test(err.errors.len(), 1)
+#test(err.errors.first(), (message: err.message, hints: err.hints))
+
+--- try-success ---
+#test(try { 1 + 2 } catch { 0 }, 3)
+#test(try { "fine" } catch { "bad" }, "fine")
+
+--- try-without-catch ---
+// A `try` without `catch` is just an identifier.
+// Error: 2-5 unknown variable: try
+#try { panic() }
+
+--- try-catch-without-binding ---
+#test(try { 1 / 0 } catch { "division" }, "division")
+
+--- try-catch-next-line ---
+#{
+  let out = try {
+    int("x")
+  }
+  catch err {
+    "bad"
+  }
+  test(out, "bad")
+}
+
+--- try-catch-scope ---
+#let x = "outer"
+#let out = try {
+  let x = "inner"
+  for i in range(3) {
+    let x = i
+    if i == 1 { panic() }
+  }
+} catch {
+  x
+}
+#test(out, "outer")
+
+--- try-catch-binding-scope ---
+#let err = "outer"
+#let v = try { panic() } catch err { none }
+#test(err, "outer")
+
+--- try-catch-rethrow ---
+// Error: 34-48 panicked with: "again"
+#let v = try { panic() } catch { panic("again") }
+
+--- try-catch-captured ---
+#let fallback = 5
+#let safe(f) = try { f() } catch { fallback }
+#test(safe(() => 1), 1)
+#test(safe(() => panic()), 5)
+
+--- try-missing-body ---
+// Error: 2-5 unknown variable: try
+#try
+
+--- try-catch-as-identifiers ---
+// `try` and `catch` are only keywords within a try-catch expression.
+#let try = true
+#let catch(x) = x
+#test(try, true)
+#test(catch(1), 1)
+#test(if try { "yes" } else { "no" }, "yes")
+#test((try: 1, catch: 2).catch, 2)
+#let out = ()
+#for try in (1, 2) { out.push(try) }
+#test(out, (1, 2))
+#{
+  let catch = 1
+  let value = try
+  catch += 1
+  test((value, catch), (true, 2))
+}
+
+--- try-catch-missing-handler ---
+// Error: 19 expected block
+#try { 1 } catch e
@@ -0,0 +1,123 @@
+--- ruby-mono-mismatch ---
+// Error: 2-27 expected 2 annotations, found 1
+// Hint: 2-27 mono ruby needs one annotation per base character
+#ruby(mode: "mono")[ab][x]
+
+--- ruby-jukugo-mismatch ---
+// Error: 2-33 expected 3 annotations, found 2
+// Hint: 2-33 jukugo ruby needs one annotation per base character
+#ruby(mode: "jukugo")[abc][x][y]
+
+--- ruby-bad-position ---
+// Error: 17-25 expected "over" or "under"
+#ruby(position: "middle")[a][b]
+
+--- ruby-over ---
+// The annotation sits above the base text and raises the baseline of the line.
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#place(hide(block(width: 100pt)[#ruby[a][b]#metadata(none)<over>]))
+#place(hide(block(width: 100pt)[#ruby(gap: 2pt)[a][b]#metadata(none)<gap>]))
+#context test(locate(<over>).position().y, 30pt)
+#context test(locate(<gap>).position().y, 32pt)
+
+--- ruby-under ---
+// The annotation sits below the base text and leaves the baseline alone.
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#place(hide(block(width: 100pt)[#ruby(position: "under")[a][b]#metadata(none)<l>]))
+#context test(locate(<l>).position().y, 20pt)
+
+--- ruby-spacing ---
+// Base text that is narrower than its annotation is spaced out.
+#place(hide(block(width: 100pt)[
+  #metadata(none)<start>#ruby[a][xxxxxx]#metadata(none)<end>
+]))
+#context {
+  let width = locate(<end>).position().x - locate(<start>).position().x
+  let annotation = measure(text(size: 5.5pt)[xxxxxx]).width
+  test(calc.abs((width - annotation).pt()) < 0.01, true)
+}
+
+--- ruby-overhang ---
+// The annotation overhangs adjacent kana instead of spacing out the base text
+// if allowed.
+#place(hide(block(width: 100pt)[
+  あ#metadata(none)<a>#ruby[a][xxx]#metadata(none)<b>あ
+]))
+#place(hide(block(width: 100pt)[
+  あ#metadata(none)<c>#ruby(overhang: false)[a][xxx]#metadata(none)<d>あ
+]))
+#context {
+  let overhung = locate(<b>).position().x - locate(<a>).position().x
+  let spaced = locate(<d>).position().x - locate(<c>).position().x
+  test(calc.abs((overhung - measure[a].width).pt()) < 0.01, true)
+  test(calc.abs((spaced - measure(text(size: 5.5pt)[xxx]).width).pt()) < 0.01, true)
+}
+
+--- ruby-overhang-one-side ---
+// The annotation overhangs a single adjacent kana by shifting towards it.
+#place(hide(block(width: 100pt)[
+  a#metadata(none)<a>#ruby[a][xxx]#metadata(none)<b>あ
+]))
+#place(hide(block(width: 100pt)[
+  あ#metadata(none)<c>#ruby[a][xxx]#metadata(none)<d>a
+]))
+#context {
+  let after = locate(<b>).position().x - locate(<a>).position().x
+  let before = locate(<d>).position().x - locate(<c>).position().x
+  test(calc.abs((after - measure[a].width).pt()) < 0.01, true)
+  test(calc.abs((before - measure[a].width).pt()) < 0.01, true)
+}
+
+--- ruby-jukugo-measured ---
+// Jukugo ruby falls back to group ruby if an annotation is wider than its base
+// character when laid out, regardless of how many characters it has.
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 0pt)
+#set ruby(mode: "jukugo", size: 1em)
+#context {
+  let fits = measure(block(width: 1pt, ruby[mm][i][i])).height
+  let wide = measure(block(width: 1pt, ruby[ii][m][m])).height
+  test(fits - wide, 20pt)
+}
+
+--- ruby-units-break ---
+// Lines break between the pieces of mono ruby, but never within group ruby.
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 0pt)
+#context {
+  let mono = measure(block(width: 1pt, ruby(mode: "mono")[ab][x][y])).height
+  let group = measure(block(width: 1pt, ruby[ab][xy])).height
+  test(mono - group, 20pt)
+}
+
+--- ruby-html html ---
+A #ruby[ab][xy] B.
+
+--- ruby-rendered ---
+#set page(width: 120pt)
+#set text(lang: "ja", font: "Noto Serif CJK JP")
+#ruby[東京][とう][きょう]に#ruby[行][い]く。
+#ruby[明日][あした]は晴れ。
+#ruby(position: "under")[漢字][かんじ]
+
+--- ruby-rendered-overhang ---
+#set page(width: 120pt)
+#set text(lang: "ja", font: "Noto Serif CJK JP")
+あ#ruby[承][うけたまわ]る \
+Ａ#ruby[承][うけたまわ]る \
+あ#ruby[承][うけたまわ]Ａ \
+あ#ruby(overhang: false)[承][うけたまわ]る
+
+--- ruby-rendered-jukugo ---
+#set page(width: 120pt)
+#set text(lang: "ja", font: "Noto Serif CJK JP")
+#ruby(mode: "jukugo")[境界][きょう][かい] \
+#ruby(mode: "jukugo")[承る][うけたまわ][]
+
+--- ruby-rendered-styled ---
+// The base text and annotations keep their styles.
+#set page(width: 120pt)
+#set text(lang: "ja", font: "Noto Serif CJK JP")
+#ruby[#text(red)[東]京][#text(blue)[とう]][きょう] \
+#ruby[*漢字*][_かんじ_] \
+#ruby(mode: "mono")[a#text(1.5em)[b]c][x][#text(green)[y]][z]